@@ -0,0 +1,65 @@
+//! Compares `partition_by_parity` with a branchy two-pointer partition.
+//!
+//! Run with `cargo bench --bench partition`. The branchy version is fast on predictable inputs but slows down badly
+//! once the parities are random, while the branchless one takes the same time on both.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use parity::slice::partition_by_parity;
+use parity::Parity;
+
+/// A Hoare-style partition that branches on every element.
+fn branchy_partition(values: &mut [u32]) -> usize {
+    let (mut lo, mut hi) = (0, values.len());
+    loop {
+        while lo < hi && values[lo].is_even() {
+            lo += 1;
+        }
+        while lo < hi && values[hi - 1].is_odd() {
+            hi -= 1;
+        }
+        if lo >= hi {
+            return lo;
+        }
+        values.swap(lo, hi - 1);
+    }
+}
+
+fn time(input: &[u32], partition: fn(&mut [u32]) -> usize) -> Duration {
+    let mut best = Duration::MAX;
+    for _ in 0..20 {
+        let mut values = input.to_vec();
+        let start = Instant::now();
+        black_box(partition(black_box(&mut values)));
+        best = best.min(start.elapsed());
+    }
+    best
+}
+
+fn main() {
+    const LEN: usize = 1 << 20;
+    let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+    let random: Vec<u32> = (0..LEN)
+        .map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as u32
+        })
+        .collect();
+    let alternating: Vec<u32> = (0..LEN as u32).collect();
+    let sorted: Vec<u32> = (0..LEN as u32)
+        .map(|i| (i >= LEN as u32 / 2) as u32)
+        .collect();
+
+    for (name, input) in [
+        ("random", &random),
+        ("alternating", &alternating),
+        ("sorted", &sorted),
+    ] {
+        let branchy = time(input, branchy_partition);
+        let branchless = time(input, partition_by_parity::<u32>);
+        println!("{name:>12}: branchy {branchy:>10.2?}  branchless {branchless:>10.2?}");
+    }
+}
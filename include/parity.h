@@ -0,0 +1,78 @@
+#ifndef PARITY_H
+#define PARITY_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The classification of a `double` by [parity_f64_classify].
+ */
+typedef enum ParityF64Class {
+  /**
+   * An even integer, including both zeros.
+   */
+  ParityF64Class_Even = 0,
+  /**
+   * An odd integer.
+   */
+  ParityF64Class_Odd = 1,
+  /**
+   * NaN.
+   */
+  ParityF64Class_NaN = 2,
+  /**
+   * Positive or negative infinity.
+   */
+  ParityF64Class_Infinite = 3,
+  /**
+   * A finite value with a fractional part.
+   */
+  ParityF64Class_Fractional = 4,
+} ParityF64Class;
+
+/**
+ * Returns `true` if `x` is even.
+ */
+bool parity_is_even_i64(int64_t x);
+
+/**
+ * Returns `true` if `x` is odd.
+ */
+bool parity_is_odd_i64(int64_t x);
+
+/**
+ * Returns `true` if `x` is even.
+ */
+bool parity_is_even_u64(uint64_t x);
+
+/**
+ * Returns `true` if `x` is odd.
+ */
+bool parity_is_odd_u64(uint64_t x);
+
+/**
+ * Returns `true` if `x` is an even integer.
+ */
+bool parity_is_even_f64(double x);
+
+/**
+ * Returns `true` if `x` is an odd integer.
+ */
+bool parity_is_odd_f64(double x);
+
+/**
+ * Returns the parity of `x`, or the reason it has none.
+ */
+enum ParityF64Class parity_f64_classify(double x);
+
+/**
+ * Returns `true` if `x` has an odd number of set bits.
+ */
+bool parity_bit_parity_u64(uint64_t x);
+
+#endif  /* PARITY_H */
@@ -0,0 +1,166 @@
+#![deny(missing_docs)]
+//! Derive macro for [parity](https://docs.rs/parity)'s `Parity` trait. Use it through the `derive` feature of
+//! `parity` rather than depending on this crate directly.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Error, Fields, Ident};
+
+const INTEGER_REPRS: &[&str] = &[
+    "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+];
+
+/// Implements `Parity` for a struct with exactly one field by delegating to that field, or for a fieldless enum
+/// with an integer `#[repr]` by using the discriminant of each variant.
+///
+/// ```
+/// use parity::Parity;
+///
+/// #[derive(Parity)]
+/// struct Id(u64);
+///
+/// #[derive(Parity)]
+/// #[repr(u8)]
+/// enum Opcode {
+///     Nop = 0x00,
+///     Load = 0x11,
+///     Store,
+/// }
+///
+/// assert!(Id(4).is_even());
+/// assert!(Opcode::Load.is_odd());
+/// assert!(Opcode::Store.is_even());
+/// ```
+#[proc_macro_derive(Parity)]
+pub fn derive_parity(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(mut input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data =
+        match &input.data {
+            Data::Struct(data) => data,
+            Data::Enum(data) => return expand_enum(&input, data),
+            Data::Union(_) => return Err(Error::new_spanned(
+                &input.ident,
+                "`#[derive(Parity)]` requires a struct with exactly one field or a fieldless enum",
+            )),
+        };
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`#[derive(Parity)]` requires a struct with exactly one field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => quote!(0),
+    };
+    let field_ty = field.ty.clone();
+    input
+        .generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#field_ty: ::parity::Parity));
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::parity::Parity for #ident #ty_generics #where_clause {
+            #[inline]
+            fn is_even(&self) -> bool {
+                ::parity::Parity::is_even(&self.#member)
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                ::parity::Parity::is_odd(&self.#member)
+            }
+
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                ::parity::Parity::is_parity_defined(&self.#member)
+            }
+
+            #[inline]
+            fn checked_parity(&self) -> ::core::option::Option<::parity::ParityKind> {
+                ::parity::Parity::checked_parity(&self.#member)
+            }
+
+            #[inline]
+            fn same_parity(&self, other: &Self) -> bool {
+                ::parity::Parity::same_parity(&self.#member, &other.#member)
+            }
+
+            #[inline]
+            fn opposite_parity(&self, other: &Self) -> bool {
+                ::parity::Parity::opposite_parity(&self.#member, &other.#member)
+            }
+
+            #[inline]
+            fn checked_same_parity(&self, other: &Self) -> ::core::option::Option<bool> {
+                ::parity::Parity::checked_same_parity(&self.#member, &other.#member)
+            }
+        }
+    })
+}
+
+fn integer_repr(input: &DeriveInput) -> syn::Result<Ident> {
+    let mut repr = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if let Some(ident) = meta.path.get_ident() {
+                if INTEGER_REPRS.iter().any(|name| ident == name) {
+                    repr = Some(ident.clone());
+                }
+            }
+            Ok(())
+        })?;
+    }
+    repr.ok_or_else(|| {
+        Error::new_spanned(
+            &input.ident,
+            "`#[derive(Parity)]` on an enum requires an integer `#[repr]`, such as `#[repr(u8)]`",
+        )
+    })
+}
+
+fn expand_enum(input: &DeriveInput, data: &DataEnum) -> syn::Result<proc_macro2::TokenStream> {
+    let repr = integer_repr(input)?;
+    if let Some(variant) = data.variants.iter().find(|v| !v.fields.is_empty()) {
+        return Err(Error::new_spanned(
+            &variant.ident,
+            "`#[derive(Parity)]` on an enum requires all variants to be fieldless",
+        ));
+    }
+    let variants: Vec<_> = data.variants.iter().map(|v| &v.ident).collect();
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::parity::Parity for #ident #ty_generics #where_clause {
+            #[inline]
+            fn is_even(&self) -> bool {
+                match self {
+                    #(Self::#variants => ::parity::Parity::is_even(&(Self::#variants as #repr)),)*
+                }
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                !::parity::Parity::is_even(self)
+            }
+        }
+    })
+}
@@ -0,0 +1,43 @@
+use parity::{Parity, ParityKind};
+
+#[derive(Parity)]
+struct Id(u64);
+
+#[derive(Parity)]
+struct Celsius {
+    degrees: f32,
+}
+
+#[derive(Parity)]
+struct Wrapper<T>(T);
+
+#[test]
+fn newtypes() {
+    assert!(Id(2).is_even());
+    assert!(Id(3).opposite_parity(&Id(4)));
+    assert_eq!(Id(7).checked_parity(), Some(ParityKind::Odd));
+
+    assert!(Celsius { degrees: 3.0 }.is_odd());
+    assert_eq!(Celsius { degrees: 0.5 }.checked_parity(), None);
+
+    assert!(Wrapper(-2i8).same_parity(&Wrapper(6)));
+    assert!(Wrapper(Id(5)).is_odd());
+}
+
+#[derive(Parity)]
+#[repr(i16)]
+enum Opcode {
+    Halt = -1,
+    Nop,
+    Push = 0x10,
+    Pop,
+}
+
+#[test]
+fn fieldless_enums() {
+    assert!(Opcode::Halt.is_odd());
+    assert!(Opcode::Nop.is_even());
+    assert!(Opcode::Push.same_parity(&Opcode::Nop));
+    assert!(Opcode::Pop.opposite_parity(&Opcode::Push));
+    assert_eq!(Opcode::Pop.checked_parity(), Some(ParityKind::Odd));
+}
@@ -0,0 +1,209 @@
+//! Parity-aware helpers for fixed-size arrays.
+
+use crate::{IntegerParity, Parity, ParityCount, ParityKind};
+
+struct AssertEven<const N: usize>;
+
+impl<const N: usize> AssertEven<N> {
+    const OK: () = assert!(crate::const_is_even_usize(N), "array length must be even");
+}
+
+/// Fixed-size arrays of even length.
+///
+/// Implemented for every `[T; N]`, but each method fails to compile when it is used with an odd `N`, so code
+/// written against this trait never has to handle a leftover element.
+///
+/// # Example
+/// ```
+/// use parity::array::ConstEvenLen;
+///
+/// fn sum_pairs<A: ConstEvenLen<Item = u32>>(samples: &A) -> Vec<u32> {
+///     samples.as_pairs().iter().map(|[a, b]| a + b).collect()
+/// }
+/// assert_eq!(sum_pairs(&[1, 2, 3, 4]), [3, 7]);
+/// ```
+///
+/// ```compile_fail
+/// use parity::array::ConstEvenLen;
+/// [1, 2, 3].as_pairs(); // error: array length must be even
+/// ```
+pub trait ConstEvenLen {
+    /// The element type of the array.
+    type Item;
+
+    /// The length of the array.
+    const LEN: usize;
+
+    /// Returns the array as a slice of consecutive pairs.
+    fn as_pairs(&self) -> &[[Self::Item; 2]];
+
+    /// Returns the array as a mutable slice of consecutive pairs.
+    fn as_pairs_mut(&mut self) -> &mut [[Self::Item; 2]];
+
+    /// Returns the first and second halves of the array, which always have the same length.
+    fn halves(&self) -> (&[Self::Item], &[Self::Item]);
+}
+
+impl<T, const N: usize> ConstEvenLen for [T; N] {
+    type Item = T;
+
+    const LEN: usize = N;
+
+    #[inline]
+    fn as_pairs(&self) -> &[[T; 2]] {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertEven::<N>::OK;
+        self.as_chunks().0
+    }
+
+    #[inline]
+    fn as_pairs_mut(&mut self) -> &mut [[T; 2]] {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertEven::<N>::OK;
+        self.as_chunks_mut().0
+    }
+
+    #[inline]
+    fn halves(&self) -> (&[T], &[T]) {
+        #[allow(clippy::let_unit_value)]
+        let () = AssertEven::<N>::OK;
+        self.split_at(N / 2)
+    }
+}
+
+/// Parity queries over the elements of a fixed-size array, implemented for every `[T; N]`.
+///
+/// The results are arrays or counts of fixed size, so none of the methods allocate.
+///
+/// # Example
+/// ```
+/// use parity::array::ParityArray;
+/// use parity::ParityKind::{Even, Odd};
+///
+/// let lanes = [4u32, 7, 10, 12];
+/// assert_eq!(lanes.parity_map(), [Even, Odd, Even, Even]);
+/// assert_eq!(lanes.count_parities().odd, 1);
+/// assert!(!lanes.all_even());
+/// assert!([2.0, -8.0].all_even());
+/// ```
+pub trait ParityArray<T, const N: usize> {
+    /// Returns the parity of each element, in order.
+    fn parity_map(&self) -> [ParityKind; N]
+    where
+        T: IntegerParity;
+
+    /// Returns the parity of each element, or `None` for elements without a parity, in order.
+    fn checked_parity_map(&self) -> [Option<ParityKind>; N]
+    where
+        T: Parity;
+
+    /// Counts the even and odd elements. Elements without a parity are not counted.
+    fn count_parities(&self) -> ParityCount
+    where
+        T: Parity;
+
+    /// Returns `true` if every element is even, which holds for an empty array.
+    fn all_even(&self) -> bool
+    where
+        T: Parity;
+
+    /// Returns `true` if every element is odd, which holds for an empty array.
+    fn all_odd(&self) -> bool
+    where
+        T: Parity;
+}
+
+impl<T, const N: usize> ParityArray<T, N> for [T; N] {
+    #[inline]
+    fn parity_map(&self) -> [ParityKind; N]
+    where
+        T: IntegerParity,
+    {
+        self.each_ref().map(IntegerParity::parity)
+    }
+
+    #[inline]
+    fn checked_parity_map(&self) -> [Option<ParityKind>; N]
+    where
+        T: Parity,
+    {
+        self.each_ref().map(Parity::checked_parity)
+    }
+
+    #[inline]
+    fn count_parities(&self) -> ParityCount
+    where
+        T: Parity,
+    {
+        let mut count = ParityCount::default();
+        for parity in self.iter().filter_map(Parity::checked_parity) {
+            count.record(parity);
+        }
+        count
+    }
+
+    #[inline]
+    fn all_even(&self) -> bool
+    where
+        T: Parity,
+    {
+        self.iter().all(Parity::is_even)
+    }
+
+    #[inline]
+    fn all_odd(&self) -> bool
+    where
+        T: Parity,
+    {
+        self.iter().all(Parity::is_odd)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_and_halves() {
+        let mut samples = [1, 2, 3, 4, 5, 6];
+        assert_eq!(samples.as_pairs(), [[1, 2], [3, 4], [5, 6]]);
+        for pair in samples.as_pairs_mut() {
+            pair.swap(0, 1);
+        }
+        assert_eq!(samples, [2, 1, 4, 3, 6, 5]);
+        assert_eq!(samples.halves(), (&[2, 1, 4][..], &[3, 6, 5][..]));
+        assert_eq!(<[u8; 6]>::LEN, 6);
+
+        let empty: [u8; 0] = [];
+        assert!(empty.as_pairs().is_empty());
+    }
+
+    #[test]
+    fn parity_queries() {
+        let values = [-3i8, 0, 5, 8];
+        assert_eq!(
+            values.parity_map(),
+            [
+                ParityKind::Odd,
+                ParityKind::Even,
+                ParityKind::Odd,
+                ParityKind::Even
+            ]
+        );
+        assert_eq!(values.count_parities(), ParityCount { even: 2, odd: 2 });
+        assert!(!values.all_even() && !values.all_odd());
+
+        let floats = [1.0, 0.5, f64::NAN];
+        assert_eq!(
+            floats.checked_parity_map(),
+            [Some(ParityKind::Odd), None, None]
+        );
+        assert_eq!(floats.count_parities().total(), 1);
+        assert!(!floats.all_odd());
+
+        let empty: [u64; 0] = [];
+        assert!(empty.all_even() && empty.all_odd());
+        assert_eq!(empty.parity_map(), [] as [ParityKind; 0]);
+        assert!([1u16, 3, u16::MAX].all_odd());
+    }
+}
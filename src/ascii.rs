@@ -0,0 +1,272 @@
+//! Encoding ASCII text as 7-bit characters with a parity bit, for legacy serial equipment.
+//!
+//! Each encoded byte carries a character in its low seven bits and the bit chosen by the [ParityMode] in bit 7. For
+//! streams, see the adapters in [io](crate::io) with the `std` feature.
+//!
+//! # Example
+//! ```
+//! use parity::ascii::{decode_7bit_with_parity, encode_7bit_with_parity, ParityMode};
+//!
+//! let framed = encode_7bit_with_parity("AC", ParityMode::Even)?;
+//! assert_eq!(framed, [0x41, 0xc3]);
+//! assert_eq!(decode_7bit_with_parity(&framed, ParityMode::Even)?, "AC");
+//! assert!(decode_7bit_with_parity(&framed, ParityMode::Odd).is_err());
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use core::fmt;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::serial::ParityBit;
+use crate::{BitParity, ParityKind};
+
+const PARITY_BIT: u8 = 0x80;
+
+/// How the parity bit of a 7-bit character is chosen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParityMode {
+    /// The parity bit makes the number of set bits in the byte even.
+    Even,
+    /// The parity bit makes the number of set bits in the byte odd.
+    Odd,
+    /// The parity bit is always set.
+    Mark,
+    /// The parity bit is always clear.
+    Space,
+}
+
+impl ParityMode {
+    /// Returns `data` with the parity bit of this mode in bit 7.
+    ///
+    /// # Panics
+    /// Panics if `data` does not fit in seven bits.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ascii::ParityMode;
+    ///
+    /// assert_eq!(ParityMode::Odd.frame(b'A'), 0xc1);
+    /// assert_eq!(ParityMode::Mark.frame(b'C'), 0xc3);
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub fn frame(self, data: u8) -> u8 {
+        assert!(
+            data & PARITY_BIT == 0,
+            "0x{data:02x} does not fit in 7 data bits"
+        );
+        let set = match self {
+            ParityMode::Even => data.bit_parity() == ParityKind::Odd,
+            ParityMode::Odd => data.bit_parity() == ParityKind::Even,
+            ParityMode::Mark => true,
+            ParityMode::Space => false,
+        };
+        if set {
+            data | PARITY_BIT
+        } else {
+            data
+        }
+    }
+
+    /// Returns `true` if bit 7 of `byte` is the parity bit this mode chooses for its low seven bits.
+    #[inline]
+    pub fn is_valid(self, byte: u8) -> bool {
+        self.frame(byte & !PARITY_BIT) == byte
+    }
+}
+
+impl From<ParityMode> for ParityBit {
+    #[inline]
+    fn from(mode: ParityMode) -> Self {
+        match mode {
+            ParityMode::Even => ParityBit::Even,
+            ParityMode::Odd => ParityBit::Odd,
+            ParityMode::Mark => ParityBit::Mark,
+            ParityMode::Space => ParityBit::Space,
+        }
+    }
+}
+
+impl From<ParityKind> for ParityMode {
+    /// Returns the mode that gives every byte the parity `parity` of set bits.
+    #[inline]
+    fn from(parity: ParityKind) -> Self {
+        match parity {
+            ParityKind::Even => ParityMode::Even,
+            ParityKind::Odd => ParityMode::Odd,
+        }
+    }
+}
+
+/// The error returned by [encode_7bit_with_parity] for text with a character outside ASCII.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NonAsciiError {
+    index: usize,
+    character: char,
+}
+
+impl NonAsciiError {
+    /// Returns the byte offset of the first character outside ASCII in the text.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the first character outside ASCII.
+    pub fn character(&self) -> char {
+        self.character
+    }
+}
+
+impl fmt::Display for NonAsciiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:?} at byte {} is not an ASCII character",
+            self.character, self.index
+        )
+    }
+}
+
+impl core::error::Error for NonAsciiError {}
+
+/// The error returned by [decode_7bit_with_parity] for a byte whose parity bit is wrong.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityMismatch {
+    index: usize,
+    byte: u8,
+}
+
+impl ParityMismatch {
+    /// Returns the position of the first byte with the wrong parity bit.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the first byte with the wrong parity bit, including that bit.
+    pub fn byte(&self) -> u8 {
+        self.byte
+    }
+}
+
+impl fmt::Display for ParityMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parity error at byte {} (0x{:02x})",
+            self.index, self.byte
+        )
+    }
+}
+
+impl core::error::Error for ParityMismatch {}
+
+/// Encodes every character of `text` as a byte with the parity bit chosen by `mode`.
+///
+/// # Errors
+/// Fails if `text` contains a character outside ASCII.
+///
+/// # Example
+/// ```
+/// use parity::ascii::{encode_7bit_with_parity, ParityMode};
+///
+/// assert_eq!(encode_7bit_with_parity("AT\r", ParityMode::Space), Ok(b"AT\r".to_vec()));
+/// assert_eq!(encode_7bit_with_parity("é", ParityMode::Odd).unwrap_err().character(), 'é');
+/// ```
+pub fn encode_7bit_with_parity(text: &str, mode: ParityMode) -> Result<Vec<u8>, NonAsciiError> {
+    if let Some((index, character)) = text.char_indices().find(|(_, c)| !c.is_ascii()) {
+        return Err(NonAsciiError { index, character });
+    }
+    Ok(text.bytes().map(|byte| mode.frame(byte)).collect())
+}
+
+/// Checks the parity bit of every byte in `bytes` against `mode` and returns the text in their low seven bits.
+///
+/// # Errors
+/// Fails at the first byte whose parity bit is not the one `mode` chooses.
+///
+/// # Example
+/// ```
+/// use parity::ascii::{decode_7bit_with_parity, ParityMode};
+///
+/// assert_eq!(decode_7bit_with_parity(&[0xcf, 0xcb], ParityMode::Mark).as_deref(), Ok("OK"));
+/// assert_eq!(decode_7bit_with_parity(&[0x4f, 0xcb], ParityMode::Mark).unwrap_err().index(), 0);
+/// ```
+pub fn decode_7bit_with_parity(bytes: &[u8], mode: ParityMode) -> Result<String, ParityMismatch> {
+    let mut text = String::with_capacity(bytes.len());
+    for (index, &byte) in bytes.iter().enumerate() {
+        if !mode.is_valid(byte) {
+            return Err(ParityMismatch { index, byte });
+        }
+        text.push(char::from(byte & !PARITY_BIT));
+    }
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [ParityMode; 4] = [
+        ParityMode::Even,
+        ParityMode::Odd,
+        ParityMode::Mark,
+        ParityMode::Space,
+    ];
+
+    #[test]
+    fn round_trip() {
+        let text: String = (0..0x80u8).map(char::from).collect();
+        for mode in MODES {
+            let framed = encode_7bit_with_parity(&text, mode).unwrap();
+            assert_eq!(framed.len(), text.len());
+            assert_eq!(decode_7bit_with_parity(&framed, mode).as_ref(), Ok(&text));
+        }
+        assert_eq!(encode_7bit_with_parity("", ParityMode::Odd), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn parity_bits() {
+        for data in 0..0x80u8 {
+            assert_eq!(ParityMode::Even.frame(data).bit_parity(), ParityKind::Even);
+            assert_eq!(ParityMode::Odd.frame(data).bit_parity(), ParityKind::Odd);
+            assert_eq!(ParityMode::Mark.frame(data), data | 0x80);
+            assert_eq!(ParityMode::Space.frame(data), data);
+            for mode in MODES {
+                assert!(mode.is_valid(mode.frame(data)));
+                assert!(!mode.is_valid(mode.frame(data) ^ 0x80));
+            }
+        }
+        assert_eq!(ParityMode::from(ParityKind::Odd), ParityMode::Odd);
+        for mode in MODES {
+            let bit = ParityBit::from(mode);
+            for data in 0..0x80u8 {
+                assert_eq!(u16::from(mode.frame(data)), bit.frame(data.into(), 7));
+            }
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = encode_7bit_with_parity("naïve", ParityMode::Even).unwrap_err();
+        assert_eq!((err.index(), err.character()), (2, 'ï'));
+        assert_eq!(
+            std::format!("{err}"),
+            "'ï' at byte 2 is not an ASCII character"
+        );
+
+        let err = decode_7bit_with_parity(&[0x41, 0xc3, 0x43], ParityMode::Even).unwrap_err();
+        assert_eq!((err.index(), err.byte()), (2, 0x43));
+        assert_eq!(std::format!("{err}"), "parity error at byte 2 (0x43)");
+    }
+
+    #[test]
+    #[should_panic(expected = "0x80 does not fit in 7 data bits")]
+    fn frame_rejects_eight_bit_data() {
+        ParityMode::Space.frame(0x80);
+    }
+}
@@ -0,0 +1,178 @@
+//! A parity that can be shared between threads, for lock-free alternation between two phases.
+
+use core::fmt;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::ParityKind;
+
+/// A [ParityKind] that can be read and flipped atomically.
+///
+/// It has the same in-memory representation as an [AtomicBool], which is set for [ParityKind::Odd].
+///
+/// # Example
+/// ```
+/// use core::sync::atomic::Ordering;
+/// use parity::atomic::AtomicParity;
+/// use parity::ParityKind;
+///
+/// // the buffer the producer writes next, flipped after each frame
+/// static FRONT: AtomicParity = AtomicParity::new(ParityKind::Even);
+///
+/// assert_eq!(FRONT.fetch_flip(Ordering::AcqRel), ParityKind::Even);
+/// assert_eq!(FRONT.load(Ordering::Acquire), ParityKind::Odd);
+/// ```
+#[repr(transparent)]
+pub struct AtomicParity {
+    odd: AtomicBool,
+}
+
+impl AtomicParity {
+    /// Returns a new atomic parity holding `parity`.
+    #[inline]
+    pub const fn new(parity: ParityKind) -> Self {
+        AtomicParity {
+            odd: AtomicBool::new(parity.is_odd()),
+        }
+    }
+
+    /// Loads the parity.
+    ///
+    /// # Panics
+    /// Panics if `order` is [Release](Ordering::Release) or [AcqRel](Ordering::AcqRel), like [AtomicBool::load].
+    #[inline]
+    pub fn load(&self, order: Ordering) -> ParityKind {
+        ParityKind::from_odd(self.odd.load(order))
+    }
+
+    /// Stores `parity`.
+    ///
+    /// # Panics
+    /// Panics if `order` is [Acquire](Ordering::Acquire) or [AcqRel](Ordering::AcqRel), like [AtomicBool::store].
+    #[inline]
+    pub fn store(&self, parity: ParityKind, order: Ordering) {
+        self.odd.store(parity.is_odd(), order);
+    }
+
+    /// Stores `parity` and returns the previous parity.
+    #[inline]
+    pub fn swap(&self, parity: ParityKind, order: Ordering) -> ParityKind {
+        ParityKind::from_odd(self.odd.swap(parity.is_odd(), order))
+    }
+
+    /// Flips the parity and returns the previous one.
+    ///
+    /// Of any number of threads flipping concurrently, each sees a different previous state, so two threads that
+    /// each flip once always see both parities between them.
+    #[inline]
+    pub fn fetch_flip(&self, order: Ordering) -> ParityKind {
+        ParityKind::from_odd(self.odd.fetch_xor(true, order))
+    }
+
+    /// Stores `new` if the current parity is `current`.
+    ///
+    /// Returns the previous parity, in `Ok` if it was `current` and `new` was stored and in `Err` otherwise. The
+    /// orderings are those of [AtomicBool::compare_exchange].
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: ParityKind,
+        new: ParityKind,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<ParityKind, ParityKind> {
+        self.odd
+            .compare_exchange(current.is_odd(), new.is_odd(), success, failure)
+            .map(ParityKind::from_odd)
+            .map_err(ParityKind::from_odd)
+    }
+
+    /// Returns the parity, consuming the atomic.
+    #[inline]
+    pub fn into_inner(self) -> ParityKind {
+        ParityKind::from_odd(self.odd.into_inner())
+    }
+}
+
+impl Default for AtomicParity {
+    /// Returns an atomic parity holding [ParityKind::Even].
+    #[inline]
+    fn default() -> Self {
+        Self::new(ParityKind::Even)
+    }
+}
+
+impl From<ParityKind> for AtomicParity {
+    #[inline]
+    fn from(parity: ParityKind) -> Self {
+        Self::new(parity)
+    }
+}
+
+impl fmt::Debug for AtomicParity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operations() {
+        let parity = AtomicParity::default();
+        assert_eq!(
+            parity.swap(ParityKind::Odd, Ordering::Relaxed),
+            ParityKind::Even
+        );
+        assert_eq!(
+            parity.compare_exchange(
+                ParityKind::Even,
+                ParityKind::Even,
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Err(ParityKind::Odd)
+        );
+        assert_eq!(
+            parity.compare_exchange(
+                ParityKind::Odd,
+                ParityKind::Even,
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Ok(ParityKind::Odd)
+        );
+        parity.store(ParityKind::Odd, Ordering::Relaxed);
+        assert_eq!(std::format!("{parity:?}"), "Odd");
+        assert_eq!(parity.fetch_flip(Ordering::Relaxed), ParityKind::Odd);
+        assert_eq!(parity.into_inner(), ParityKind::Even);
+        assert_eq!(
+            AtomicParity::from(ParityKind::Odd).into_inner(),
+            ParityKind::Odd
+        );
+    }
+
+    #[test]
+    fn concurrent_flips() {
+        let parity = AtomicParity::new(ParityKind::Even);
+        let odd_seen = std::thread::scope(|scope| {
+            let workers: std::vec::Vec<_> = (0..4)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..1001)
+                            .filter(|_| parity.fetch_flip(Ordering::AcqRel).is_odd())
+                            .count()
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .map(|w| w.join().unwrap())
+                .sum::<usize>()
+        });
+        // 4004 flips, alternating from even, see each parity 2002 times and end where they started
+        assert_eq!(odd_seen, 2002);
+        assert_eq!(parity.load(Ordering::Relaxed), ParityKind::Even);
+    }
+}
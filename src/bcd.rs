@@ -0,0 +1,129 @@
+//! Parity of numbers in binary-coded decimal, read from the last digit without decoding the number.
+//!
+//! Packed BCD stores two decimal digits per byte, the more significant one in the high nibble, and unpacked BCD
+//! stores one digit per byte. Both are common in meters, PLC registers and other industrial protocols. A decimal
+//! number has the parity of its last digit, so only that digit decides the parity, but every digit is checked so
+//! corrupted data is never mistaken for a number.
+//!
+//! # Example
+//! ```
+//! use parity::bcd::{packed_bcd_parity, unpacked_bcd_parity};
+//! use parity::ParityKind;
+//!
+//! // 1234567 as an odd number of packed digits, padded with a leading zero
+//! assert_eq!(packed_bcd_parity(&[0x01, 0x23, 0x45, 0x67]), Ok(ParityKind::Odd));
+//! assert_eq!(unpacked_bcd_parity(&[4, 0, 9, 6]), Ok(ParityKind::Even));
+//! assert!(packed_bcd_parity(&[0x12, 0x3a]).is_err());
+//! ```
+
+use core::fmt;
+
+use crate::{IntegerParity, ParityKind};
+
+/// The error returned for a byte that is not valid binary-coded decimal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidBcd {
+    index: usize,
+    byte: u8,
+}
+
+impl InvalidBcd {
+    /// Returns the position of the first invalid byte.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the first invalid byte.
+    pub fn byte(&self) -> u8 {
+        self.byte
+    }
+}
+
+impl fmt::Display for InvalidBcd {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid BCD byte 0x{:02x} at index {}",
+            self.byte, self.index
+        )
+    }
+}
+
+impl core::error::Error for InvalidBcd {}
+
+/// Returns `true` if both nibbles of `byte` are decimal digits.
+#[inline]
+pub const fn is_packed_bcd(byte: u8) -> bool {
+    byte >> 4 <= 9 && byte & 0x0f <= 9
+}
+
+/// Returns the parity of the number whose packed BCD digits are `bytes`, most significant byte first.
+///
+/// An empty slice has no digits and stands for zero, which is even.
+///
+/// # Errors
+/// Fails at the first byte with a nibble above 9.
+pub fn packed_bcd_parity(bytes: &[u8]) -> Result<ParityKind, InvalidBcd> {
+    check(bytes, is_packed_bcd)?;
+    Ok(bytes.last().map_or(ParityKind::Even, |last| last.parity()))
+}
+
+/// Returns the parity of the number whose unpacked BCD digits are `bytes`, one digit from 0 to 9 per byte, most
+/// significant digit first.
+///
+/// An empty slice has no digits and stands for zero, which is even.
+///
+/// # Errors
+/// Fails at the first byte above 9.
+pub fn unpacked_bcd_parity(bytes: &[u8]) -> Result<ParityKind, InvalidBcd> {
+    check(bytes, |byte| byte <= 9)?;
+    Ok(bytes.last().map_or(ParityKind::Even, |last| last.parity()))
+}
+
+fn check(bytes: &[u8], is_valid: impl Fn(u8) -> bool) -> Result<(), InvalidBcd> {
+    match bytes.iter().position(|&byte| !is_valid(byte)) {
+        Some(index) => Err(InvalidBcd {
+            index,
+            byte: bytes[index],
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packed(mut n: u64) -> std::vec::Vec<u8> {
+        let mut bytes = std::vec::Vec::new();
+        while n > 0 {
+            bytes.insert(0, (n / 10 % 10) as u8 * 16 + (n % 10) as u8);
+            n /= 100;
+        }
+        bytes
+    }
+
+    #[test]
+    fn matches_decoded_value() {
+        for n in (0..2000).chain([99_999_999_999, u64::MAX]) {
+            assert_eq!(packed_bcd_parity(&packed(n)), Ok(n.parity()));
+            let digits: std::vec::Vec<u8> = std::format!("{n}").bytes().map(|d| d - b'0').collect();
+            assert_eq!(unpacked_bcd_parity(&digits), Ok(n.parity()));
+        }
+        assert_eq!(packed_bcd_parity(&[]), Ok(ParityKind::Even));
+        assert_eq!(unpacked_bcd_parity(&[]), Ok(ParityKind::Even));
+    }
+
+    #[test]
+    fn rejects_invalid_digits() {
+        let err = packed_bcd_parity(&[0x12, 0xa4, 0x5f]).unwrap_err();
+        assert_eq!((err.index(), err.byte()), (1, 0xa4));
+        assert_eq!(std::format!("{err}"), "invalid BCD byte 0xa4 at index 1");
+        assert_eq!(unpacked_bcd_parity(&[1, 2, 0x13]).unwrap_err().index(), 2);
+        assert!(unpacked_bcd_parity(&[0x10]).is_err());
+        assert!((0..=u8::MAX)
+            .filter(|&b| is_packed_bcd(b))
+            .eq((0..100).map(|n| n / 10 * 16 + n % 10)));
+    }
+}
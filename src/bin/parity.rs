@@ -0,0 +1,265 @@
+//! Prints or filters numbers by parity.
+//!
+//! Numbers are taken from the arguments, or read from standard input separated by whitespace when there are none.
+
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use parity::label::ParityLabels;
+use parity::{BitParity, IntegerParity, Parity, ParityKind};
+
+const USAGE: &str = "\
+Usage: parity [OPTIONS] [NUMBER]...
+
+Prints the parity of each NUMBER, or of each number read from standard input if none are given.
+Integers may be written in decimal or with a 0x, 0o or 0b prefix, and anything else is read as a float.
+
+Options:
+  -b, --bit-parity  use the parity of the number of set bits instead
+  -e, --even        only print the even numbers
+  -o, --odd         only print the odd numbers
+  -t, --terse       write parities as E and O, and - when there is none
+  -h, --help        print this help";
+
+/// A number as written on the command line.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Number {
+    Integer { negative: bool, magnitude: u128 },
+    Float(f64),
+}
+
+/// The labels written by default, with a one-word label for numbers without a parity.
+const LABELS: ParityLabels = ParityLabels::new("even", "odd", "none");
+
+#[derive(Debug, Default)]
+struct Options {
+    bit_parity: bool,
+    filter: Option<ParityKind>,
+    terse: bool,
+    numbers: Vec<String>,
+}
+
+impl Options {
+    fn labels(&self) -> ParityLabels {
+        if self.terse {
+            ParityLabels::LETTERS
+        } else {
+            LABELS
+        }
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-b" | "--bit-parity" => options.bit_parity = true,
+            "-e" | "--even" => options.filter = Some(ParityKind::Even),
+            "-o" | "--odd" => options.filter = Some(ParityKind::Odd),
+            "-t" | "--terse" => options.terse = true,
+            "-h" | "--help" => return Ok(None),
+            "--" => {
+                options.numbers.extend(args);
+                break;
+            }
+            // negative numbers are not options
+            _ if arg.starts_with('-')
+                && !arg[1..].starts_with(|c: char| c.is_ascii_digit() || c == '.') =>
+            {
+                return Err(format!("unknown option '{arg}'"));
+            }
+            _ => options.numbers.push(arg),
+        }
+    }
+    Ok(Some(options))
+}
+
+fn parse_number(input: &str) -> Result<Number, String> {
+    let (negative, unsigned) = match input.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, input.strip_prefix('+').unwrap_or(input)),
+    };
+    let (radix, digits) = match unsigned.get(..2) {
+        Some("0x" | "0X") => (16, &unsigned[2..]),
+        Some("0o" | "0O") => (8, &unsigned[2..]),
+        Some("0b" | "0B") => (2, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+    // `from_str_radix` would accept a second sign
+    if !digits.starts_with(['+', '-']) {
+        if let Ok(magnitude) = u128::from_str_radix(digits, radix) {
+            return Ok(Number::Integer {
+                negative,
+                magnitude,
+            });
+        }
+    }
+    match input.parse() {
+        Ok(float) if radix == 10 => Ok(Number::Float(float)),
+        _ => Err(format!("invalid number '{input}'")),
+    }
+}
+
+/// Returns the parity of `number`, or `None` if it has none.
+fn parity_of(number: Number, bit_parity: bool) -> Result<Option<ParityKind>, String> {
+    match (number, bit_parity) {
+        (Number::Integer { magnitude, .. }, false) => Ok(Some(magnitude.parity())),
+        (Number::Float(float), false) => Ok(float.checked_parity()),
+        (
+            Number::Integer {
+                negative: false,
+                magnitude,
+            },
+            true,
+        ) => Ok(Some(magnitude.bit_parity())),
+        (Number::Integer { magnitude: 0, .. }, true) => Ok(Some(ParityKind::Even)),
+        (Number::Integer { .. }, true) => {
+            Err("the set bits of a negative number depend on its width".into())
+        }
+        (Number::Float(_), true) => Err("bit parity needs an integer".into()),
+    }
+}
+
+/// Writes the result for one input, returning `false` if it was invalid.
+fn process(input: &str, options: &Options, out: &mut impl Write) -> io::Result<bool> {
+    let parity = match parse_number(input).and_then(|number| parity_of(number, options.bit_parity))
+    {
+        Ok(parity) => parity,
+        Err(message) => {
+            eprintln!("parity: {input}: {message}");
+            return Ok(false);
+        }
+    };
+    match (options.filter, parity) {
+        (None, parity) => writeln!(out, "{input}\t{}", options.labels().get(parity))?,
+        (Some(filter), Some(parity)) if filter == parity => writeln!(out, "{input}")?,
+        (Some(_), _) => {}
+    }
+    Ok(true)
+}
+
+fn run(options: &Options) -> io::Result<bool> {
+    let mut out = BufWriter::new(io::stdout().lock());
+    let mut valid = true;
+    if options.numbers.is_empty() {
+        for line in io::stdin().lock().lines() {
+            for input in line?.split_whitespace() {
+                valid &= process(input, options, &mut out)?;
+            }
+        }
+    } else {
+        for input in &options.numbers {
+            valid &= process(input, options, &mut out)?;
+        }
+    }
+    out.flush()?;
+    Ok(valid)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("parity: {message}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match run(&options) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        // the reader of a pipeline went away, as with `parity < numbers | head`
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("parity: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integer(negative: bool, magnitude: u128) -> Number {
+        Number::Integer {
+            negative,
+            magnitude,
+        }
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_number("42"), Ok(integer(false, 42)));
+        assert_eq!(parse_number("-7"), Ok(integer(true, 7)));
+        assert_eq!(parse_number("+0x1F"), Ok(integer(false, 31)));
+        assert_eq!(parse_number("-0b101"), Ok(integer(true, 5)));
+        assert_eq!(parse_number("0o17"), Ok(integer(false, 15)));
+        assert_eq!(
+            parse_number("340282366920938463463374607431768211455"),
+            Ok(integer(false, u128::MAX))
+        );
+        assert_eq!(parse_number("2.5"), Ok(Number::Float(2.5)));
+        assert_eq!(parse_number("-1e3"), Ok(Number::Float(-1e3)));
+        assert!(matches!(parse_number("nan"), Ok(Number::Float(x)) if x.is_nan()));
+        assert!(parse_number("0x").is_err());
+        assert!(parse_number("0x1.8").is_err());
+        assert!(parse_number("--3").is_err());
+        assert!(parse_number("0x-3").is_err());
+        assert!(parse_number("seven").is_err());
+    }
+
+    #[test]
+    fn parities() {
+        let parity = |input, bit_parity| parity_of(parse_number(input).unwrap(), bit_parity);
+        assert_eq!(parity("-3", false), Ok(Some(ParityKind::Odd)));
+        assert_eq!(parity("4.0", false), Ok(Some(ParityKind::Even)));
+        assert_eq!(parity("4.5", false), Ok(None));
+        assert_eq!(parity("inf", false), Ok(None));
+        assert_eq!(parity("0b110", true), Ok(Some(ParityKind::Even)));
+        assert_eq!(parity("0x7", true), Ok(Some(ParityKind::Odd)));
+        assert_eq!(parity("-0", true), Ok(Some(ParityKind::Even)));
+        assert!(parity("-1", true).is_err());
+        assert!(parity("1.0", true).is_err());
+    }
+
+    #[test]
+    fn output() {
+        let options = parse_args(["--odd".into()]).unwrap().unwrap();
+        let mut out = Vec::new();
+        for input in ["1", "2", "0x3", "3.5", "x"] {
+            process(input, &options, &mut out).unwrap();
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), "1\n0x3\n");
+
+        let mut out = Vec::new();
+        for input in ["10", "0.5"] {
+            process(input, &Options::default(), &mut out).unwrap();
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), "10\teven\n0.5\tnone\n");
+
+        let options = parse_args(["-t".into()]).unwrap().unwrap();
+        let mut out = Vec::new();
+        for input in ["10", "7", "0.5"] {
+            process(input, &options, &mut out).unwrap();
+        }
+        assert_eq!(String::from_utf8(out).unwrap(), "10\tE\n7\tO\n0.5\t-\n");
+    }
+
+    #[test]
+    fn args() {
+        let args = |args: &[&str]| parse_args(args.iter().map(|&arg| arg.into()));
+        let options = args(&["-b", "-e", "-5", "-.5", "--", "-o"])
+            .unwrap()
+            .unwrap();
+        assert!(options.bit_parity);
+        assert_eq!(options.filter, Some(ParityKind::Even));
+        assert_eq!(options.numbers, ["-5", "-.5", "-o"]);
+        assert!(args(&["--help"]).unwrap().is_none());
+        assert!(args(&["--verbose"]).is_err());
+    }
+}
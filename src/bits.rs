@@ -0,0 +1,529 @@
+//! Parity of the set bits of an integer, and the bits and bytes at its even and odd positions.
+
+use core::ops::{Bound, RangeBounds};
+
+use crate::ParityKind;
+
+/// Provides the parity of the number of set bits in a value, as used by parity bits, CRC taps and LFSRs.
+///
+/// This is distinct from [Parity](crate::Parity), which is about the value itself: `3u8` is odd, but has an even
+/// number of set bits.
+///
+/// Implemented for all primitive integer types. Signed values are treated as their two's complement bit pattern.
+pub trait BitParity {
+    /// Returns the parity of the number of set bits in `self`.
+    fn bit_parity(&self) -> ParityKind;
+
+    /// Returns the parity of the number of set bits in `self` that are also set in `mask`.
+    fn parity_of_bits(&self, mask: Self) -> ParityKind
+    where
+        Self: Sized;
+
+    /// Returns the parity of the number of set bits in `self` at the bit positions in `range`, where bit 0 is the
+    /// least significant bit.
+    ///
+    /// # Panics
+    /// Panics if the range extends past the width of the type, or if its start is greater than its end.
+    fn parity_of_bit_range<R: RangeBounds<u32>>(&self, range: R) -> ParityKind
+    where
+        Self: Sized;
+}
+
+/// The mask and range parities of [BitParity] for a `bitvec` [BitSlice](bitvec::slice::BitSlice), which is unsized
+/// and so takes its mask by reference. The methods have their own names so they do not clash with those of
+/// [BitParity].
+///
+/// [BitVec](bitvec::vec::BitVec) dereferences to `BitSlice`, and its [BitParity] implementation forwards to these
+/// methods.
+///
+/// Requires the `bitvec` feature.
+///
+/// # Example
+/// ```
+/// use bitvec::prelude::*;
+/// use parity::bits::BitSliceParity;
+/// use parity::ParityKind;
+///
+/// let bits = bits![u8, Lsb0; 1, 0, 1, 1, 0];
+/// assert_eq!(bits.masked_bit_parity(bits![1, 1, 1]), ParityKind::Even);
+/// assert_eq!(bits.bit_range_parity(2..), ParityKind::Even);
+/// assert_eq!(bits[1..].bit_range_parity(..=1), ParityKind::Odd);
+/// ```
+#[cfg(feature = "bitvec")]
+pub trait BitSliceParity {
+    /// Returns the parity of the number of set bits in `self` that are also set in `mask`. Bits beyond the end of the
+    /// shorter of the two are unset.
+    fn masked_bit_parity<T: bitvec::store::BitStore, O: bitvec::order::BitOrder>(
+        &self,
+        mask: &bitvec::slice::BitSlice<T, O>,
+    ) -> ParityKind;
+
+    /// Returns the parity of the number of set bits in `self` at the indices in `range`.
+    ///
+    /// # Panics
+    /// Panics if the range extends past the end of `self`, or if its start is greater than its end.
+    fn bit_range_parity<R: RangeBounds<usize>>(&self, range: R) -> ParityKind;
+}
+
+fn bit_range<R: RangeBounds<u32>>(range: &R, bits: u32) -> (u32, u32) {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => Some(start),
+        Bound::Excluded(&start) => start.checked_add(1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1),
+        Bound::Excluded(&end) => Some(end),
+        Bound::Unbounded => Some(bits),
+    };
+    // a bound past `u32::MAX` is past the width of every integer
+    let (Some(start), Some(end)) = (start, end) else {
+        panic!("bit range is out of range for a {bits}-bit integer");
+    };
+    assert!(
+        start <= end,
+        "bit range starts at {start} but ends at {end}"
+    );
+    assert!(
+        end <= bits,
+        "bit range end {end} is out of range for a {bits}-bit integer"
+    );
+    (start, end)
+}
+
+macro_rules! impl_bit_parity {
+    ($($T:ty => $U:ty),*) => { $(
+        impl BitParity for $T {
+            /// Returns the parity of the number of set bits in `self`.
+            /// # Example
+            /// ```
+            /// use parity::{BitParity, ParityKind};
+            #[doc = concat!("assert_eq!(0b1011", stringify!($T), ".bit_parity(), ParityKind::Odd);")]
+            #[doc = concat!("assert_eq!(0b0011", stringify!($T), ".bit_parity(), ParityKind::Even);")]
+            /// ```
+            #[inline]
+            fn bit_parity(&self) -> ParityKind {
+                ParityKind::from_odd(self.count_ones() & 1 == 1)
+            }
+
+            /// Returns the parity of the number of set bits in `self` that are also set in `mask`.
+            /// # Example
+            /// ```
+            /// use parity::{BitParity, ParityKind};
+            #[doc = concat!("assert_eq!(0b1011", stringify!($T), ".parity_of_bits(0b0110), ParityKind::Odd);")]
+            /// ```
+            #[inline]
+            fn parity_of_bits(&self, mask: Self) -> ParityKind {
+                (*self & mask).bit_parity()
+            }
+
+            /// Returns the parity of the number of set bits in `self` at the bit positions in `range`.
+            /// # Example
+            /// ```
+            /// use parity::{BitParity, ParityKind};
+            #[doc = concat!("assert_eq!(0b1011", stringify!($T), ".parity_of_bit_range(1..4), ParityKind::Even);")]
+            /// ```
+            #[inline]
+            fn parity_of_bit_range<R: RangeBounds<u32>>(&self, range: R) -> ParityKind {
+                let (start, end) = bit_range(&range, <$T>::BITS);
+                if start == end {
+                    return ParityKind::Even;
+                }
+                let mask = (<$U>::MAX >> (<$U>::BITS - (end - start))) << start;
+                (*self as $U).parity_of_bits(mask)
+            }
+        }
+    )* };
+}
+
+impl_bit_parity![u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize];
+impl_bit_parity![i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize];
+
+/// Splits an integer into the bits at its even and odd positions, and interleaves two halves back into one.
+///
+/// Interleaving the bits of two coordinates gives their Morton code, the position of the point along a Z-order
+/// curve, with `x` in the even bits and `y` in the odd bits. Points that are close in space mostly have close codes,
+/// which keeps quadtrees and spatial indexes local.
+///
+/// Implemented for `u16`, `u32`, `u64` and `u128`, whose halves are the unsigned type of half the width.
+///
+/// # Example
+/// ```
+/// use parity::bits::BitInterleave;
+///
+/// let code = u32::interleave_bits(0b011, 0b101);
+/// assert_eq!(code, 0b10_01_11);
+/// assert_eq!((code.extract_even_bits(), code.extract_odd_bits()), (0b011, 0b101));
+/// ```
+pub trait BitInterleave: Sized {
+    /// The unsigned integer type with half as many bits.
+    type Half;
+
+    /// Returns the bits of `self` at even positions (0, 2, 4, ...), packed into the low bits of the result.
+    fn extract_even_bits(&self) -> Self::Half;
+
+    /// Returns the bits of `self` at odd positions (1, 3, 5, ...), packed into the low bits of the result.
+    fn extract_odd_bits(&self) -> Self::Half;
+
+    /// Returns an integer with the bits of `even` at its even positions and the bits of `odd` at its odd positions.
+    fn interleave_bits(even: Self::Half, odd: Self::Half) -> Self;
+}
+
+// the inverse of `extract_even_bits`, moving bit `i` of a half-width value to bit `2i`
+macro_rules! spread_bits {
+    ($T:ty, $half:expr) => {{
+        let mut x = <$T>::from($half);
+        let mut shift = <$T>::BITS / 4;
+        while shift > 0 {
+            x = (x | x << shift) & (<$T>::MAX / ((1 << shift) + 1));
+            shift /= 2;
+        }
+        x
+    }};
+}
+
+macro_rules! impl_bit_interleave {
+    ($($T:ty => $H:ty),*) => { $(
+        impl BitInterleave for $T {
+            type Half = $H;
+
+            #[inline]
+            fn extract_even_bits(&self) -> $H {
+                // gather pairs, then nibbles, then bytes and so on into the low half; `MAX / (2^k + 1)` selects the
+                // low `k` bits of every `2k`-bit group
+                let mut x = *self & (<$T>::MAX / 3);
+                let mut shift = 1;
+                while shift < <$T>::BITS / 2 {
+                    x = (x | x >> shift) & (<$T>::MAX / ((1 << (2 * shift)) + 1));
+                    shift *= 2;
+                }
+                x as $H
+            }
+
+            #[inline]
+            fn extract_odd_bits(&self) -> $H {
+                (*self >> 1).extract_even_bits()
+            }
+
+            #[inline]
+            fn interleave_bits(even: $H, odd: $H) -> $T {
+                spread_bits!($T, even) | spread_bits!($T, odd) << 1
+            }
+        }
+    )* };
+}
+
+impl_bit_interleave![u16 => u8, u32 => u16, u64 => u32, u128 => u64];
+
+/// Exchanges the bit at every even position with the odd bit above it.
+///
+/// This is the first stage of a bit reversal, which continues by swapping pairs, nibbles and so on, and the
+/// butterfly of a radix-2 network on single bits. Swapping twice gives back the original value.
+///
+/// Implemented for all primitive unsigned integer types.
+///
+/// # Example
+/// ```
+/// use parity::bits::SwapBitPairs;
+///
+/// assert_eq!(0b1101_0010u8.swap_bit_pairs(), 0b1110_0001);
+/// assert_eq!(0x5555u16.swap_bit_pairs(), 0xaaaa);
+/// ```
+pub trait SwapBitPairs {
+    /// Returns `self` with bits `2i` and `2i + 1` exchanged for every `i`.
+    fn swap_bit_pairs(self) -> Self;
+}
+
+macro_rules! impl_swap_bit_pairs {
+    ($($T:ty),*) => { $(
+        impl SwapBitPairs for $T {
+            #[inline]
+            fn swap_bit_pairs(self) -> $T {
+                const EVEN: $T = <$T>::MAX / 3;
+                (self & !EVEN) >> 1 | (self & EVEN) << 1
+            }
+        }
+    )* };
+}
+
+impl_swap_bit_pairs![u8, u16, u32, u64, u128, usize];
+
+/// Exchanges the byte at every even offset with the odd byte above it, reversing the byte order of every 16-bit
+/// word.
+///
+/// This converts a value made of 16-bit words between big- and little-endian word encoding without reordering the
+/// words themselves, as needed by formats that were written one 16-bit word at a time. See [swab16] for byte
+/// slices.
+///
+/// Implemented for the primitive unsigned integer types of at least 16 bits.
+///
+/// # Example
+/// ```
+/// use parity::bits::SwapBytePairs;
+///
+/// assert_eq!(0x1234u16.swap_byte_pairs(), 0x3412);
+/// assert_eq!(0x1122_3344u32.swap_byte_pairs(), 0x2211_4433);
+/// ```
+pub trait SwapBytePairs {
+    /// Returns `self` with bytes `2i` and `2i + 1` exchanged for every `i`.
+    fn swap_byte_pairs(self) -> Self;
+}
+
+macro_rules! impl_swap_byte_pairs {
+    ($($T:ty),*) => { $(
+        impl SwapBytePairs for $T {
+            #[inline]
+            fn swap_byte_pairs(self) -> $T {
+                const EVEN: $T = <$T>::MAX / 0x101;
+                (self & !EVEN) >> 8 | (self & EVEN) << 8
+            }
+        }
+    )* };
+}
+
+impl_swap_byte_pairs![u16, u32, u64, u128, usize];
+
+/// Exchanges every byte at an even offset of `bytes` with the byte after it, like the POSIX `swab` function.
+///
+/// A trailing byte of an odd-length slice has no partner and is left in place; its index is returned so the caller
+/// can treat it as a truncated word.
+///
+/// # Example
+/// ```
+/// use parity::bits::swab16;
+///
+/// let mut words = *b"eHll!o";
+/// assert_eq!(swab16(&mut words), None);
+/// assert_eq!(&words, b"Hello!");
+///
+/// let mut truncated = [1, 2, 3];
+/// assert_eq!(swab16(&mut truncated), Some(2));
+/// assert_eq!(truncated, [2, 1, 3]);
+/// ```
+pub fn swab16(bytes: &mut [u8]) -> Option<usize> {
+    let mut pairs = bytes.chunks_exact_mut(2);
+    for pair in &mut pairs {
+        pair.swap(0, 1);
+    }
+    (!pairs.into_remainder().is_empty()).then(|| bytes.len() - 1)
+}
+
+/// The parity of the number of set bits of every byte: entry `b` is `1` if `b` has an odd number of set bits, and
+/// `0` otherwise.
+///
+/// [BitParity::bit_parity] counts bits with `count_ones`, which compiles to a single instruction on targets with a
+/// population count. On targets without one, such as many microcontrollers, a lookup in this table is often cheaper,
+/// and [bit_parity_u8] and [bit_parity_of_bytes] use it explicitly. The table is built at compile time and takes 256
+/// bytes of read-only memory.
+///
+/// # Example
+/// ```
+/// use parity::bits::PARITY_TABLE_256;
+///
+/// assert_eq!(PARITY_TABLE_256[0b0111], 1);
+/// assert_eq!(PARITY_TABLE_256[0xff], 0);
+/// ```
+pub const PARITY_TABLE_256: [u8; 256] = {
+    let mut table = [0; 256];
+    let mut byte = 1;
+    while byte < 256 {
+        // clearing the lowest set bit removes one bit, which flips the parity
+        table[byte] = table[byte & (byte - 1)] ^ 1;
+        byte += 1;
+    }
+    table
+};
+
+/// Returns the parity of the number of set bits in `byte` by looking it up in [PARITY_TABLE_256].
+///
+/// # Example
+/// ```
+/// use parity::bits::bit_parity_u8;
+/// use parity::ParityKind;
+///
+/// const PARITY: ParityKind = bit_parity_u8(0b1011_0001);
+/// assert_eq!(PARITY, ParityKind::Even);
+/// ```
+#[inline]
+pub const fn bit_parity_u8(byte: u8) -> ParityKind {
+    ParityKind::from_odd(PARITY_TABLE_256[byte as usize] != 0)
+}
+
+/// Returns the parity of the number of set bits in all of `bytes`, by combining them with XOR and looking the result
+/// up in [PARITY_TABLE_256].
+///
+/// XOR keeps the parity of the bits at each position, so this is the parity of the total number of set bits. It is
+/// usable in const contexts, and wider integers can be passed as their `to_le_bytes`.
+///
+/// # Example
+/// ```
+/// use parity::bits::bit_parity_of_bytes;
+/// use parity::{BitParity, ParityKind};
+///
+/// assert_eq!(bit_parity_of_bytes(b"parity"), ParityKind::Odd);
+/// let word = 0xdead_beef_u32;
+/// assert_eq!(bit_parity_of_bytes(&word.to_le_bytes()), word.bit_parity());
+/// ```
+#[inline]
+pub const fn bit_parity_of_bytes(bytes: &[u8]) -> ParityKind {
+    let (mut folded, mut i) = (0, 0);
+    while i < bytes.len() {
+        folded ^= bytes[i];
+        i += 1;
+    }
+    bit_parity_u8(folded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parity_table() {
+        for byte in 0..=u8::MAX {
+            assert_eq!(bit_parity_u8(byte), byte.bit_parity());
+            assert_eq!(PARITY_TABLE_256[byte as usize], byte.count_ones() as u8 % 2);
+        }
+        assert_eq!(bit_parity_of_bytes(&[]), ParityKind::Even);
+        for value in [0u64, 1, u64::MAX, 0x0123_4567_89ab_cdef, 1 << 63] {
+            assert_eq!(
+                bit_parity_of_bytes(&value.to_be_bytes()),
+                value.bit_parity()
+            );
+        }
+    }
+
+    #[test]
+    fn bit_parity() {
+        assert_eq!(0u32.bit_parity(), ParityKind::Even);
+        assert_eq!(1u32.bit_parity(), ParityKind::Odd);
+        assert_eq!(u64::MAX.bit_parity(), ParityKind::Even);
+        assert_eq!((-1i8).bit_parity(), ParityKind::Even);
+        assert_eq!(i32::MIN.bit_parity(), ParityKind::Odd);
+    }
+
+    #[test]
+    fn masks_and_ranges() {
+        let x = 0b1101_0110u8;
+        assert_eq!(x.parity_of_bits(0), ParityKind::Even);
+        assert_eq!(x.parity_of_bits(0b1000_0000), ParityKind::Odd);
+        assert_eq!(x.parity_of_bit_range(..), x.bit_parity());
+        assert_eq!(x.parity_of_bit_range(0..0), ParityKind::Even);
+        assert_eq!(x.parity_of_bit_range(4..=7), ParityKind::Odd);
+        assert_eq!((-1i16).parity_of_bit_range(8..), ParityKind::Even);
+        assert_eq!((-1i16).parity_of_bit_range(15..), ParityKind::Odd);
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_past_width() {
+        0u8.parity_of_bit_range(0..9);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range for a 32-bit integer")]
+    fn range_end_overflow() {
+        u32::MAX.parity_of_bit_range(0..=u32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range for a 8-bit integer")]
+    fn range_start_overflow() {
+        1u8.parity_of_bit_range((Bound::Excluded(u32::MAX), Bound::Unbounded));
+    }
+
+    #[test]
+    fn interleaving() {
+        fn naive(even: u64, odd: u64) -> u128 {
+            (0..64).fold(0, |code, i| {
+                code | u128::from(even >> i & 1) << (2 * i)
+                    | u128::from(odd >> i & 1) << (2 * i + 1)
+            })
+        }
+        let samples = [
+            0u64,
+            1,
+            2,
+            0b1011,
+            0xdead_beef,
+            u64::MAX,
+            1 << 63,
+            0x0123_4567_89ab_cdef,
+        ];
+        for &even in &samples {
+            for &odd in &samples {
+                let code = naive(even, odd);
+                assert_eq!(u128::interleave_bits(even, odd), code);
+                assert_eq!(
+                    (code.extract_even_bits(), code.extract_odd_bits()),
+                    (even, odd)
+                );
+
+                let (even, odd) = (even as u32, odd as u32);
+                let code = u64::interleave_bits(even, odd);
+                assert_eq!(u128::from(code), naive(even.into(), odd.into()));
+                assert_eq!(
+                    (code.extract_even_bits(), code.extract_odd_bits()),
+                    (even, odd)
+                );
+
+                let (even, odd) = (even as u16, odd as u16);
+                let code = u32::interleave_bits(even, odd);
+                assert_eq!(
+                    (code.extract_even_bits(), code.extract_odd_bits()),
+                    (even, odd)
+                );
+
+                let (even, odd) = (even as u8, odd as u8);
+                let code = u16::interleave_bits(even, odd);
+                assert_eq!(u128::from(code), naive(even.into(), odd.into()));
+                assert_eq!(
+                    (code.extract_even_bits(), code.extract_odd_bits()),
+                    (even, odd)
+                );
+            }
+        }
+        assert_eq!(0xaaaa_u16.extract_even_bits(), 0);
+        assert_eq!(0xaaaa_u16.extract_odd_bits(), 0xff);
+    }
+
+    #[test]
+    fn bit_pair_swaps() {
+        for x in 0..=u8::MAX {
+            let swapped = x.swap_bit_pairs();
+            assert_eq!(swapped.swap_bit_pairs(), x);
+            assert_eq!(swapped.count_ones(), x.count_ones());
+            for i in 0..8 {
+                assert_eq!(swapped >> i & 1, x >> (i ^ 1) & 1);
+            }
+            assert_eq!(u64::from(x).swap_bit_pairs(), u64::from(swapped));
+        }
+        assert_eq!(u128::MAX.swap_bit_pairs(), u128::MAX);
+        assert_eq!(1u128.swap_bit_pairs(), 2);
+        assert_eq!(
+            (1usize << (usize::BITS - 1)).swap_bit_pairs(),
+            1 << (usize::BITS - 2)
+        );
+        assert_eq!(0x1234_5678u32.swap_bit_pairs(), 0x2138_a9b4);
+    }
+
+    #[test]
+    fn byte_pair_swaps() {
+        let x = 0x0011_2233_4455_6677_8899_aabb_ccdd_eeffu128;
+        assert_eq!(
+            x.swap_byte_pairs(),
+            0x1100_3322_5544_7766_9988_bbaa_ddcc_ffee
+        );
+        assert_eq!(x.swap_byte_pairs().swap_byte_pairs(), x);
+        assert_eq!((x as u64).swap_byte_pairs(), 0x9988_bbaa_ddcc_ffee);
+        assert_eq!(0xabcdu16.swap_byte_pairs(), 0xabcdu16.swap_bytes());
+
+        let mut bytes = x.to_be_bytes();
+        assert_eq!(swab16(&mut bytes), None);
+        assert_eq!(u128::from_be_bytes(bytes), x.swap_byte_pairs());
+        assert_eq!(swab16(&mut []), None);
+        let mut single = [7];
+        assert_eq!(swab16(&mut single), Some(0));
+        assert_eq!(single, [7]);
+    }
+}
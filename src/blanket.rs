@@ -0,0 +1,78 @@
+//! `Parity` for any `Copy` type convertible into `i128`, by opting in with a marker trait.
+//!
+//! A blanket implementation over every `T: Copy + Into<i128>` would overlap with the implementations for the
+//! primitive integers, which also convert into `i128`, so Rust rejects it. Implementing the empty [ParityViaI128]
+//! marker is the one line a type needs instead, and the rest of [Parity] follows from its conversion.
+//!
+//! # Example
+//! ```
+//! use parity::blanket::ParityViaI128;
+//! use parity::Parity;
+//!
+//! // a newtype as generated for a C enum or handle
+//! #[derive(Clone, Copy)]
+//! struct Handle(u32);
+//!
+//! impl From<Handle> for i128 {
+//!     fn from(handle: Handle) -> i128 {
+//!         handle.0.into()
+//!     }
+//! }
+//!
+//! impl ParityViaI128 for Handle {}
+//!
+//! assert!(Handle(7).is_odd());
+//! assert!(Handle(7).same_parity(&Handle(9)));
+//! ```
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// Opts a `Copy` type convertible into `i128` into a [Parity] implementation computed on the converted value.
+pub trait ParityViaI128: Copy + Into<i128> {}
+
+impl<T: ParityViaI128> Parity for T {
+    #[inline]
+    fn is_even(&self) -> bool {
+        (*self).into().is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        (*self).into().is_odd()
+    }
+
+    /// Returns `true`, as every integer is either even or odd.
+    #[inline]
+    fn is_parity_defined(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn checked_parity(&self) -> Option<ParityKind> {
+        Some((*self).into().parity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy)]
+    struct Code(i16);
+
+    impl From<Code> for i128 {
+        fn from(code: Code) -> i128 {
+            code.0.into()
+        }
+    }
+
+    impl ParityViaI128 for Code {}
+
+    #[test]
+    fn via_conversion() {
+        assert!(Code(-4).is_even());
+        assert!(Code(i16::MIN + 1).is_odd());
+        assert_eq!(Code(3).checked_parity(), Some(ParityKind::Odd));
+        assert!(Code(2).opposite_parity(&Code(5)));
+    }
+}
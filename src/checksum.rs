@@ -0,0 +1,222 @@
+//! Parity-based checksums over byte streams and digit strings.
+
+/// A streaming longitudinal redundancy check.
+///
+/// The check byte is the XOR of all input bytes, i.e. every bit of the result is the even parity of the
+/// corresponding bit column (ISO 1155). With a `DEPTH` greater than one the input is interleaved: byte `i` is
+/// folded into lane `i % DEPTH`, so a burst error of up to `DEPTH` consecutive bytes touches every lane at most
+/// once and cannot cancel itself out.
+///
+/// # Example
+/// ```
+/// use parity::checksum::Lrc;
+///
+/// let mut lrc = Lrc::<2>::new();
+/// lrc.update(&[0x01, 0x10]);
+/// lrc.update(&[0x02, 0x20]);
+/// assert_eq!(lrc.finish(), [0x03, 0x30]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Lrc<const DEPTH: usize = 1> {
+    lanes: [u8; DEPTH],
+    lane: usize,
+}
+
+impl<const DEPTH: usize> Lrc<DEPTH> {
+    const NONZERO_DEPTH: () = assert!(DEPTH > 0, "interleaver depth must be at least 1");
+
+    /// Creates an empty check with all lanes zero.
+    #[inline]
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::NONZERO_DEPTH;
+        Lrc {
+            lanes: [0; DEPTH],
+            lane: 0,
+        }
+    }
+
+    /// Folds `bytes` into the check, continuing the interleaving where the previous call stopped.
+    #[inline]
+    pub fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.lanes[self.lane] ^= byte;
+            self.lane += 1;
+            if self.lane == DEPTH {
+                self.lane = 0;
+            }
+        }
+    }
+
+    /// Returns the check byte of every lane.
+    #[inline]
+    pub fn finish(&self) -> [u8; DEPTH] {
+        self.lanes
+    }
+
+    /// Returns `true` if the data seen so far matches the `expected` check bytes.
+    #[inline]
+    pub fn verify(&self, expected: &[u8; DEPTH]) -> bool {
+        self.lanes == *expected
+    }
+
+    /// Clears the check so it can be reused for a new stream.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Lrc<1> {
+    /// Returns the single check byte of a non-interleaved check.
+    #[inline]
+    pub fn value(&self) -> u8 {
+        self.lanes[0]
+    }
+}
+
+impl<const DEPTH: usize> Default for Lrc<DEPTH> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the longitudinal redundancy check byte of `bytes`.
+///
+/// # Example
+/// ```
+/// use parity::checksum::lrc;
+/// assert_eq!(lrc(&[0x0f, 0xf0, 0x01]), 0xfe);
+/// ```
+#[inline]
+pub fn lrc(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0, |acc, byte| acc ^ byte)
+}
+
+/// Returns the longitudinal redundancy check of `bytes` interleaved over `DEPTH` lanes.
+///
+/// # Example
+/// ```
+/// use parity::checksum::interleaved_lrc;
+/// assert_eq!(interleaved_lrc::<3>(&[1, 2, 4, 8, 16, 32]), [9, 18, 36]);
+/// ```
+#[inline]
+pub fn interleaved_lrc<const DEPTH: usize>(bytes: &[u8]) -> [u8; DEPTH] {
+    let mut lrc = Lrc::<DEPTH>::new();
+    lrc.update(bytes);
+    lrc.finish()
+}
+
+/// Returns the check digit of the UPC/EAN (GTIN) payload `digits`, one decimal digit per element.
+///
+/// Counting from the right, digits at odd positions are weighted 3 and digits at even positions 1, and the check
+/// digit brings the weighted sum up to a multiple of ten. Counting from the right makes the same function work for
+/// EAN-8, UPC-A (12 digits) and EAN-13 payloads; for EAN-13 it is the familiar 1, 3, 1, ... weighting from the left.
+///
+/// # Panics
+/// Panics if an element of `digits` is not a decimal digit.
+///
+/// # Example
+/// ```
+/// use parity::checksum::ean_checksum;
+///
+/// assert_eq!(ean_checksum(&[4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3]), 1);
+/// assert_eq!(ean_checksum(&[0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5]), 2);
+/// ```
+#[inline]
+#[track_caller]
+pub fn ean_checksum(digits: &[u8]) -> u8 {
+    let sum = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0u32, |sum, (i, &digit)| {
+            assert!(digit <= 9, "{digit} is not a decimal digit");
+            let weight = if i.is_multiple_of(2) { 3 } else { 1 };
+            (sum + weight * u32::from(digit)) % 10
+        });
+    ((10 - sum) % 10) as u8
+}
+
+/// Returns `true` if the last element of `digits` is the [ean_checksum] of the ones before it.
+///
+/// Returns `false` for an empty slice and for slices containing anything other than decimal digits.
+///
+/// # Example
+/// ```
+/// use parity::checksum::ean_is_valid;
+///
+/// assert!(ean_is_valid(&[4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3, 1]));
+/// assert!(!ean_is_valid(&[4, 0, 0, 6, 3, 8, 1, 3, 3, 3, 9, 3, 2]));
+/// ```
+#[inline]
+pub fn ean_is_valid(digits: &[u8]) -> bool {
+    match digits.split_last() {
+        Some((&check, payload)) if digits.iter().all(|&digit| digit <= 9) => {
+            ean_checksum(payload) == check
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streaming_matches_one_shot() {
+        let data = b"The quick brown fox jumps over the lazy dog";
+        let mut lrc = Lrc::<4>::new();
+        for chunk in data.chunks(3) {
+            lrc.update(chunk);
+        }
+        assert_eq!(lrc.finish(), interleaved_lrc::<4>(data));
+
+        let mut plain = Lrc::<1>::new();
+        plain.update(data);
+        assert_eq!(plain.value(), super::lrc(data));
+    }
+
+    #[test]
+    fn interleaving_catches_bursts() {
+        let data = [0x55u8; 8];
+        let mut corrupted = data;
+        // the same bit flipped in two adjacent bytes cancels out in a plain LRC
+        corrupted[2] ^= 0x01;
+        corrupted[3] ^= 0x01;
+        assert_eq!(lrc(&data), lrc(&corrupted));
+        assert_ne!(
+            interleaved_lrc::<2>(&data),
+            interleaved_lrc::<2>(&corrupted)
+        );
+    }
+
+    #[test]
+    fn reset_and_verify() {
+        let mut lrc = Lrc::<2>::default();
+        lrc.update(&[1, 2, 3]);
+        assert!(lrc.verify(&[2, 2]));
+        lrc.reset();
+        assert!(lrc.verify(&[0, 0]));
+    }
+
+    #[test]
+    fn ean_check_digits() {
+        // EAN-8, UPC-A and EAN-13
+        assert_eq!(ean_checksum(&[9, 6, 3, 8, 5, 0, 7]), 4);
+        assert!(ean_is_valid(&[0, 3, 6, 0, 0, 0, 2, 9, 1, 4, 5, 2]));
+        assert!(ean_is_valid(&[9, 7, 8, 0, 3, 0, 6, 4, 0, 6, 1, 5, 7]));
+        // swapping adjacent digits that differ by other than five changes the weighted sum
+        assert!(!ean_is_valid(&[9, 7, 8, 0, 3, 0, 6, 4, 0, 6, 5, 1, 7]));
+        assert_eq!(ean_checksum(&[]), 0);
+        assert!(!ean_is_valid(&[]));
+        assert!(!ean_is_valid(&[1, 10, 3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "not a decimal digit")]
+    fn ean_rejects_non_digits() {
+        ean_checksum(b"123");
+    }
+}
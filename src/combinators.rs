@@ -0,0 +1,158 @@
+//! Parity constraints on [Option] and [Result].
+//!
+//! # Example
+//! ```
+//! use parity::combinators::{OptionParity, ResultParity};
+//! use parity::ParityError;
+//!
+//! let width: Option<u32> = "640".parse().ok();
+//! assert_eq!(width.filter_even(), Some(640));
+//! assert_eq!(Some(3).and_then_odd(|x: u32| x.checked_mul(3)), Some(9));
+//!
+//! let parsed: Result<u32, ParityError<u32>> = Ok(7);
+//! assert_eq!(parsed.filter_even().unwrap_err().into_value(), 7);
+//! ```
+
+use crate::{Parity, ParityError};
+
+/// Parity combinators for [Option], implemented for `Option<T>` where `T` has a parity.
+pub trait OptionParity<T: Parity> {
+    /// Returns `self` if it holds an even value, and `None` otherwise.
+    fn filter_even(self) -> Option<T>;
+
+    /// Returns `self` if it holds an odd value, and `None` otherwise.
+    fn filter_odd(self) -> Option<T>;
+
+    /// Calls `f` with the value if it is even, and returns `None` otherwise.
+    fn and_then_even<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U>;
+
+    /// Calls `f` with the value if it is odd, and returns `None` otherwise.
+    fn and_then_odd<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U>;
+}
+
+impl<T: Parity> OptionParity<T> for Option<T> {
+    #[inline]
+    fn filter_even(self) -> Option<T> {
+        self.filter(T::is_even)
+    }
+
+    #[inline]
+    fn filter_odd(self) -> Option<T> {
+        self.filter(T::is_odd)
+    }
+
+    #[inline]
+    fn and_then_even<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U> {
+        self.filter_even().and_then(f)
+    }
+
+    #[inline]
+    fn and_then_odd<U, F: FnOnce(T) -> Option<U>>(self, f: F) -> Option<U> {
+        self.filter_odd().and_then(f)
+    }
+}
+
+/// Parity combinators for [Result], implemented for `Result<T, E>` where `T` has a parity.
+///
+/// A value of the wrong parity becomes a [ParityError], converted into the error type `E`.
+pub trait ResultParity<T: Parity, E> {
+    /// Returns `self` if it holds an even value, and the rejected odd value as an error otherwise.
+    fn filter_even(self) -> Result<T, E>
+    where
+        E: From<ParityError<T>>;
+
+    /// Returns `self` if it holds an odd value, and the rejected even value as an error otherwise.
+    fn filter_odd(self) -> Result<T, E>
+    where
+        E: From<ParityError<T>>;
+
+    /// Calls `f` with the value if it is even, and returns the rejected value as an error otherwise.
+    fn and_then_even<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> Result<U, E>
+    where
+        E: From<ParityError<T>>;
+
+    /// Calls `f` with the value if it is odd, and returns the rejected value as an error otherwise.
+    fn and_then_odd<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> Result<U, E>
+    where
+        E: From<ParityError<T>>;
+}
+
+impl<T: Parity, E> ResultParity<T, E> for Result<T, E> {
+    #[inline]
+    fn filter_even(self) -> Result<T, E>
+    where
+        E: From<ParityError<T>>,
+    {
+        Ok(self?.ensure_even()?)
+    }
+
+    #[inline]
+    fn filter_odd(self) -> Result<T, E>
+    where
+        E: From<ParityError<T>>,
+    {
+        Ok(self?.ensure_odd()?)
+    }
+
+    #[inline]
+    fn and_then_even<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> Result<U, E>
+    where
+        E: From<ParityError<T>>,
+    {
+        self.filter_even().and_then(f)
+    }
+
+    #[inline]
+    fn and_then_odd<U, F: FnOnce(T) -> Result<U, E>>(self, f: F) -> Result<U, E>
+    where
+        E: From<ParityError<T>>,
+    {
+        self.filter_odd().and_then(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ParityKind;
+
+    #[test]
+    fn options() {
+        assert_eq!(Some(4).filter_even(), Some(4));
+        assert_eq!(Some(4).filter_odd(), None);
+        assert_eq!(None::<u8>.filter_even(), None);
+        assert_eq!(Some(0.5).filter_even(), None);
+        assert_eq!(Some(6u8).and_then_even(|x| x.checked_mul(50)), None);
+        assert_eq!(Some(6u8).and_then_even(|x| Some(x / 2)), Some(3));
+        assert_eq!(Some(5).and_then_odd(|x| Some(x + 1)), Some(6));
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Parse,
+        Parity(ParityKind),
+    }
+
+    impl From<ParityError<i32>> for Error {
+        fn from(err: ParityError<i32>) -> Self {
+            Error::Parity(err.expected())
+        }
+    }
+
+    #[test]
+    fn results() {
+        assert_eq!(Ok::<i32, Error>(2).filter_even(), Ok(2));
+        assert_eq!(
+            Ok::<i32, Error>(2).filter_odd(),
+            Err(Error::Parity(ParityKind::Odd))
+        );
+        assert_eq!(Err::<i32, _>(Error::Parse).filter_even(), Err(Error::Parse));
+        assert_eq!(Ok::<i32, Error>(8).and_then_even(|x| Ok(x / 2)), Ok(4));
+        assert_eq!(
+            Ok::<i32, Error>(8).and_then_odd(|x| Ok(x / 2)),
+            Err(Error::Parity(ParityKind::Odd))
+        );
+        let sum: Result<i32, Error> = Ok::<i32, Error>(3).and_then_odd(|_| Err(Error::Parse));
+        assert_eq!(sum, Err(Error::Parse));
+    }
+}
@@ -0,0 +1,133 @@
+//! Parity checks usable in const contexts.
+
+#[cfg(feature = "nightly")]
+mod nightly;
+#[cfg(feature = "nightly")]
+pub use nightly::ConstParity;
+
+macro_rules! impl_const_parity {
+    ($($T:ty => $is_even:ident, $is_odd:ident;)*) => { $(
+        #[doc = concat!("Returns `true` if the [", stringify!($T), "] `x` is even, in const contexts.")]
+        /// # Example
+        /// ```
+        #[doc = concat!("const EVEN: bool = parity::", stringify!($is_even), "(4);")]
+        /// assert!(EVEN);
+        /// ```
+        #[inline]
+        pub const fn $is_even(x: $T) -> bool {
+            x & 1 == 0
+        }
+
+        #[doc = concat!("Returns `true` if the [", stringify!($T), "] `x` is odd, in const contexts.")]
+        /// # Example
+        /// ```
+        #[doc = concat!("const ODD: bool = parity::", stringify!($is_odd), "(5);")]
+        /// assert!(ODD);
+        /// ```
+        #[inline]
+        pub const fn $is_odd(x: $T) -> bool {
+            x & 1 != 0
+        }
+    )* };
+}
+
+impl_const_parity! {
+    u8 => const_is_even_u8, const_is_odd_u8;
+    u16 => const_is_even_u16, const_is_odd_u16;
+    u32 => const_is_even_u32, const_is_odd_u32;
+    u64 => const_is_even_u64, const_is_odd_u64;
+    u128 => const_is_even_u128, const_is_odd_u128;
+    usize => const_is_even_usize, const_is_odd_usize;
+    i8 => const_is_even_i8, const_is_odd_i8;
+    i16 => const_is_even_i16, const_is_odd_i16;
+    i32 => const_is_even_i32, const_is_odd_i32;
+    i64 => const_is_even_i64, const_is_odd_i64;
+    i128 => const_is_even_i128, const_is_odd_i128;
+    isize => const_is_even_isize, const_is_odd_isize;
+}
+
+/// An even `usize` constant, checked at compile time.
+///
+/// Naming [EvenUsize::VALUE] or [EvenUsize::HALF], or constructing the type with [EvenUsize::new], fails to compile
+/// when `N` is odd, so const-generic APIs can state a parity requirement in their signature and enforce it in their
+/// body. As with every check on a const generic, the error is reported where the offending instantiation is compiled.
+///
+/// # Example
+/// ```
+/// use parity::EvenUsize;
+///
+/// /// Splits a buffer into its two halves, which requires an even length.
+/// fn halves<const N: usize>(buf: &[u8; N]) -> (&[u8], &[u8]) {
+///     buf.split_at(EvenUsize::<N>::HALF)
+/// }
+///
+/// assert_eq!(halves(&[1, 2, 3, 4]), (&[1, 2][..], &[3, 4][..]));
+/// assert_eq!(EvenUsize::<6>::new().get(), 6);
+/// ```
+///
+/// ```compile_fail
+/// # use parity::EvenUsize;
+/// let len = EvenUsize::<7>::VALUE; // error: `N` is odd
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EvenUsize<const N: usize>(());
+
+impl<const N: usize> EvenUsize<N> {
+    /// The value `N`, which is even.
+    pub const VALUE: usize = {
+        assert!(const_is_even_usize(N), "`N` is odd");
+        N
+    };
+
+    /// Half of `N`.
+    pub const HALF: usize = Self::VALUE / 2;
+
+    /// Returns the witness that `N` is even.
+    #[inline]
+    pub const fn new() -> Self {
+        let _ = Self::VALUE;
+        EvenUsize(())
+    }
+
+    /// Returns `N`.
+    #[inline]
+    pub const fn get(self) -> usize {
+        Self::VALUE
+    }
+}
+
+impl<const N: usize> Default for EvenUsize<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const _: () = assert!(const_is_even_u64(u64::MAX - 1));
+    const _: () = assert!(const_is_odd_i8(i8::MIN + 1));
+    const _: () = assert!(EvenUsize::<{ usize::MAX - 1 }>::HALF == usize::MAX / 2);
+
+    #[test]
+    fn agrees_with_parity() {
+        use crate::Parity;
+        for x in i16::MIN..=i16::MAX {
+            assert_eq!(const_is_even_i16(x), x.is_even());
+            assert_eq!(const_is_odd_i16(x), x.is_odd());
+        }
+    }
+
+    #[test]
+    fn even_usize() {
+        fn buffer<const N: usize>() -> [u8; N] {
+            let _ = EvenUsize::<N>::new();
+            [0; N]
+        }
+        assert_eq!(buffer::<0>().len(), EvenUsize::<0>::VALUE);
+        assert_eq!(EvenUsize::<8>::default().get(), 8);
+        assert_eq!(EvenUsize::<8>::HALF, 4);
+    }
+}
@@ -0,0 +1,72 @@
+/// A const-evaluable counterpart of [Parity](crate::Parity), so generic const code can bound on
+/// `T: [const] ConstParity`.
+///
+/// Requires the `nightly` feature and a nightly compiler. Implemented for all primitive integer types.
+///
+/// # Example
+/// ```
+/// #![feature(const_trait_impl)]
+/// use parity::ConstParity;
+///
+/// const fn all_even<T: [const] ConstParity>(values: &[T]) -> bool {
+///     let mut i = 0;
+///     while i < values.len() {
+///         if !values[i].const_is_even() {
+///             return false;
+///         }
+///         i += 1;
+///     }
+///     true
+/// }
+/// const EVEN: bool = all_even(&[2u8, 4, 6]);
+/// assert!(EVEN);
+/// ```
+pub const trait ConstParity {
+    /// Returns `true` if `self` is even, and false otherwise.
+    fn const_is_even(&self) -> bool;
+    /// Returns `true` if `self` is odd, and false otherwise.
+    fn const_is_odd(&self) -> bool;
+}
+
+macro_rules! impl_const_trait {
+    ($($T:ty => $is_even:ident, $is_odd:ident;)*) => { $(
+        impl const ConstParity for $T {
+            #[inline]
+            fn const_is_even(&self) -> bool {
+                super::$is_even(*self)
+            }
+
+            #[inline]
+            fn const_is_odd(&self) -> bool {
+                super::$is_odd(*self)
+            }
+        }
+    )* };
+}
+
+impl_const_trait! {
+    u8 => const_is_even_u8, const_is_odd_u8;
+    u16 => const_is_even_u16, const_is_odd_u16;
+    u32 => const_is_even_u32, const_is_odd_u32;
+    u64 => const_is_even_u64, const_is_odd_u64;
+    u128 => const_is_even_u128, const_is_odd_u128;
+    usize => const_is_even_usize, const_is_odd_usize;
+    i8 => const_is_even_i8, const_is_odd_i8;
+    i16 => const_is_even_i16, const_is_odd_i16;
+    i32 => const_is_even_i32, const_is_odd_i32;
+    i64 => const_is_even_i64, const_is_odd_i64;
+    i128 => const_is_even_i128, const_is_odd_i128;
+    isize => const_is_even_isize, const_is_odd_isize;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const fn is_even<T: [const] ConstParity>(x: &T) -> bool {
+        x.const_is_even()
+    }
+
+    const _: () = assert!(is_even(&10u32));
+    const _: () = assert!((-3i64).const_is_odd());
+}
@@ -0,0 +1,95 @@
+//! Parity of secret values without timing side channels.
+//!
+//! The usual [Parity](crate::Parity) methods return a `bool`, which invites the caller, or the optimizer, to branch
+//! on it. The methods here return `0` or `1` as a `u8` that can be combined into masks, and with the `subtle`
+//! feature as a [subtle::Choice].
+//!
+//! # Example
+//! Selecting between two values by the parity of a secret without branching:
+//! ```
+//! use parity::ct::ConstantTimeParity;
+//!
+//! let secret = 0x5eed_u64;
+//! let mask = u64::from(secret.ct_is_odd()).wrapping_neg();
+//! let (a, b) = (10u64, 20u64);
+//! assert_eq!((a & mask) | (b & !mask), 10);
+//! ```
+
+/// Constant-time parity for integer types.
+///
+/// The implementations only mask and shift, with no data-dependent branches, and pass the result through
+/// [core::hint::black_box] so the optimizer cannot see where it came from. As with any Rust constant-time code this
+/// is a best effort: the compiler makes no formal guarantee, so inspect the generated code where it matters.
+///
+/// Implemented for all primitive integer types, and for `crypto_bigint::Uint` with the `crypto-bigint` feature.
+pub trait ConstantTimeParity {
+    /// Returns `1` if `self` is odd and `0` if it is even.
+    fn ct_parity(&self) -> u8;
+
+    /// Returns `1` if `self` is even and `0` if it is odd.
+    #[inline]
+    fn ct_is_even(&self) -> u8 {
+        core::hint::black_box(self.ct_parity() ^ 1)
+    }
+
+    /// Returns `1` if `self` is odd and `0` if it is even.
+    #[inline]
+    fn ct_is_odd(&self) -> u8 {
+        self.ct_parity()
+    }
+
+    /// Returns whether `self` is even as a [subtle::Choice].
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn ct_is_even_choice(&self) -> subtle::Choice {
+        subtle::Choice::from(self.ct_is_even())
+    }
+
+    /// Returns whether `self` is odd as a [subtle::Choice].
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn ct_is_odd_choice(&self) -> subtle::Choice {
+        subtle::Choice::from(self.ct_is_odd())
+    }
+}
+
+macro_rules! impl_constant_time_parity {
+    ($($T:ty),*) => { $(
+        impl ConstantTimeParity for $T {
+            #[inline]
+            fn ct_parity(&self) -> u8 {
+                core::hint::black_box((*self & 1) as u8)
+            }
+        }
+    )* };
+}
+
+impl_constant_time_parity![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Parity;
+
+    #[test]
+    fn matches_parity() {
+        for x in [0i64, 1, -1, 2, -2, i64::MIN, i64::MAX] {
+            assert_eq!(x.ct_is_odd() == 1, x.is_odd());
+            assert_eq!(x.ct_is_even() == 1, x.is_even());
+            assert_eq!(x.ct_is_even() + x.ct_is_odd(), 1);
+        }
+        assert_eq!(u128::MAX.ct_parity(), 1);
+        assert_eq!(0x80u8.ct_parity(), 0);
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn choices() {
+        use subtle::ConditionallySelectable;
+
+        assert_eq!(7u32.ct_is_odd_choice().unwrap_u8(), 1);
+        assert_eq!(7u32.ct_is_even_choice().unwrap_u8(), 0);
+        let chosen = u32::conditional_select(&10, &20, 6i16.ct_is_even_choice());
+        assert_eq!(chosen, 20);
+    }
+}
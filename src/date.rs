@@ -0,0 +1,128 @@
+//! Parity of the calendar fields of dates from the `time` crate.
+//!
+//! Alternating schedules are usually keyed on one of these fields: odd and even days for watering restrictions,
+//! odd and even ISO weeks for fortnightly rotas, odd and even years for biennial events.
+//!
+//! # Example
+//! ```
+//! use parity::date::DateParity;
+//! use parity::ParityKind;
+//! use time::{Date, Month};
+//!
+//! let day = Date::from_calendar_date(2024, Month::February, 29)?;
+//! assert_eq!(day.day_parity(), ParityKind::Odd);
+//! assert_eq!(day.ordinal_parity(), ParityKind::Even);
+//! assert_eq!(day.iso_week_parity(), ParityKind::Odd);
+//! assert_eq!(day.year_parity(), ParityKind::Even);
+//! # Ok::<(), time::error::ComponentRange>(())
+//! ```
+
+use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+use crate::{IntegerParity, ParityKind};
+
+/// Parity of the calendar fields of a date, or of the date part of a date and time.
+pub trait DateParity {
+    /// Returns the parity of the day of the month, from 1 to 31.
+    fn day_parity(&self) -> ParityKind;
+
+    /// Returns the parity of the day of the year, from 1 to 366.
+    fn ordinal_parity(&self) -> ParityKind;
+
+    /// Returns the parity of the ISO 8601 week number, from 1 to 53.
+    ///
+    /// Years with 53 weeks end on an odd week that is followed by week 1, so a fortnightly rota keyed on this
+    /// parity repeats a parity at such a year boundary.
+    fn iso_week_parity(&self) -> ParityKind;
+
+    /// Returns the parity of the calendar year. Years before 1 AD are negative or zero in the proleptic Gregorian
+    /// calendar, so 1 BC is year 0 and even.
+    fn year_parity(&self) -> ParityKind;
+}
+
+impl DateParity for Date {
+    #[inline]
+    fn day_parity(&self) -> ParityKind {
+        self.day().parity()
+    }
+
+    #[inline]
+    fn ordinal_parity(&self) -> ParityKind {
+        self.ordinal().parity()
+    }
+
+    #[inline]
+    fn iso_week_parity(&self) -> ParityKind {
+        self.iso_week().parity()
+    }
+
+    #[inline]
+    fn year_parity(&self) -> ParityKind {
+        self.year().parity()
+    }
+}
+
+macro_rules! impl_date_parity_via_date {
+    ($($T:ty),*) => { $(
+        impl DateParity for $T {
+            #[inline]
+            fn day_parity(&self) -> ParityKind {
+                self.date().day_parity()
+            }
+
+            #[inline]
+            fn ordinal_parity(&self) -> ParityKind {
+                self.date().ordinal_parity()
+            }
+
+            #[inline]
+            fn iso_week_parity(&self) -> ParityKind {
+                self.date().iso_week_parity()
+            }
+
+            #[inline]
+            fn year_parity(&self) -> ParityKind {
+                self.date().year_parity()
+            }
+        }
+    )* };
+}
+
+impl_date_parity_via_date![OffsetDateTime, PrimitiveDateTime];
+
+#[cfg(test)]
+mod tests {
+    use time::{Month, Time, UtcOffset};
+
+    use super::*;
+
+    #[test]
+    fn fields() {
+        let date = Date::from_calendar_date(2020, Month::December, 31).unwrap();
+        assert_eq!(date.day_parity(), ParityKind::Odd);
+        assert_eq!(date.ordinal_parity(), ParityKind::Even); // day 366
+        assert_eq!(date.iso_week_parity(), ParityKind::Odd); // week 53
+        assert_eq!(date.next_day().unwrap().iso_week_parity(), ParityKind::Odd);
+        assert_eq!(
+            Date::from_calendar_date(-1, Month::January, 1)
+                .unwrap()
+                .year_parity(),
+            ParityKind::Odd
+        );
+    }
+
+    #[test]
+    fn date_times_use_their_date() {
+        let date = Date::from_calendar_date(2023, Month::March, 14).unwrap();
+        let primitive = PrimitiveDateTime::new(date, Time::MIDNIGHT);
+        let offset = primitive.assume_offset(UtcOffset::from_hms(5, 30, 0).unwrap());
+        for parities in [
+            [primitive.day_parity(), primitive.ordinal_parity()],
+            [offset.day_parity(), offset.ordinal_parity()],
+        ] {
+            assert_eq!(parities, [date.day_parity(), date.ordinal_parity()]);
+        }
+        assert_eq!(offset.iso_week_parity(), date.iso_week_parity());
+        assert_eq!(primitive.year_parity(), ParityKind::Odd);
+    }
+}
@@ -0,0 +1,86 @@
+use core::fmt;
+
+use crate::label::ParityLabels;
+use crate::ParityKind;
+
+/// The error returned by [Parity::ensure_even](crate::Parity::ensure_even) and
+/// [Parity::ensure_odd](crate::Parity::ensure_odd), carrying the rejected value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityError<T> {
+    value: T,
+    expected: ParityKind,
+}
+
+impl<T> ParityError<T> {
+    #[inline]
+    pub(crate) fn new(value: T, expected: ParityKind) -> Self {
+        ParityError { value, expected }
+    }
+
+    /// Returns a reference to the rejected value.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the rejected value.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// Returns the parity the value was required to have.
+    #[inline]
+    pub fn expected(&self) -> ParityKind {
+        self.expected
+    }
+
+    /// Formats the error with the parity written in the words of `labels`, as `expected E, found 3`.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::label::ParityLabels;
+    /// use parity::Parity;
+    ///
+    /// let err = 3.ensure_even().unwrap_err();
+    /// assert_eq!(err.display_with(ParityLabels::LETTERS).to_string(), "expected E, found 3");
+    /// ```
+    #[inline]
+    pub fn display_with(&self, labels: ParityLabels) -> LabelledParityError<'_, T> {
+        LabelledParityError {
+            error: self,
+            labels,
+        }
+    }
+}
+
+/// A [ParityError] formatted with custom [ParityLabels], returned by [ParityError::display_with].
+#[derive(Clone, Copy, Debug)]
+pub struct LabelledParityError<'a, T> {
+    error: &'a ParityError<T>,
+    labels: ParityLabels,
+}
+
+impl<T: fmt::Debug> fmt::Display for LabelledParityError<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected {}, found {:?}",
+            self.labels.kind(self.error.expected),
+            self.error.value
+        )
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for ParityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected an {} value, found {:?}",
+            self.expected, self.value
+        )
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for ParityError<T> {}
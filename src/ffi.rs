@@ -0,0 +1,104 @@
+//! C bindings for the core predicates.
+//!
+//! Every function is `extern "C"` and exported unmangled. The declarations are in `include/parity.h`, which is
+//! generated with `cbindgen --config cbindgen.toml --output include/parity.h src/ffi.rs`. To build a shared library,
+//! run `cargo rustc --release --features ffi --crate-type cdylib`.
+
+use crate::float::{FloatParity, FloatParityError};
+use crate::{BitParity, Parity, ParityKind};
+
+/// The classification of a `double` by [parity_f64_classify].
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ParityF64Class {
+    /// An even integer, including both zeros.
+    Even = 0,
+    /// An odd integer.
+    Odd = 1,
+    /// NaN.
+    NaN = 2,
+    /// Positive or negative infinity.
+    Infinite = 3,
+    /// A finite value with a fractional part.
+    Fractional = 4,
+}
+
+/// Returns `true` if `x` is even.
+#[no_mangle]
+pub extern "C" fn parity_is_even_i64(x: i64) -> bool {
+    x.is_even()
+}
+
+/// Returns `true` if `x` is odd.
+#[no_mangle]
+pub extern "C" fn parity_is_odd_i64(x: i64) -> bool {
+    x.is_odd()
+}
+
+/// Returns `true` if `x` is even.
+#[no_mangle]
+pub extern "C" fn parity_is_even_u64(x: u64) -> bool {
+    x.is_even()
+}
+
+/// Returns `true` if `x` is odd.
+#[no_mangle]
+pub extern "C" fn parity_is_odd_u64(x: u64) -> bool {
+    x.is_odd()
+}
+
+/// Returns `true` if `x` is an even integer.
+#[no_mangle]
+pub extern "C" fn parity_is_even_f64(x: f64) -> bool {
+    x.is_even()
+}
+
+/// Returns `true` if `x` is an odd integer.
+#[no_mangle]
+pub extern "C" fn parity_is_odd_f64(x: f64) -> bool {
+    x.is_odd()
+}
+
+/// Returns the parity of `x`, or the reason it has none.
+#[no_mangle]
+pub extern "C" fn parity_f64_classify(x: f64) -> ParityF64Class {
+    match x.try_parity() {
+        Ok(ParityKind::Even) => ParityF64Class::Even,
+        Ok(ParityKind::Odd) => ParityF64Class::Odd,
+        Err(FloatParityError::NaN) => ParityF64Class::NaN,
+        Err(FloatParityError::Infinite) => ParityF64Class::Infinite,
+        Err(FloatParityError::Fractional | FloatParityError::Inexact) => ParityF64Class::Fractional,
+    }
+}
+
+/// Returns `true` if `x` has an odd number of set bits.
+#[no_mangle]
+pub extern "C" fn parity_bit_parity_u64(x: u64) -> bool {
+    x.bit_parity().is_odd()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicates() {
+        assert!(parity_is_even_i64(i64::MIN) && parity_is_odd_i64(-1));
+        assert!(parity_is_even_u64(0) && parity_is_odd_u64(u64::MAX));
+        assert!(parity_is_even_f64(-0.0) && !parity_is_odd_f64(0.5));
+        assert!(parity_bit_parity_u64(0b111) && !parity_bit_parity_u64(0b11));
+    }
+
+    #[test]
+    fn classify() {
+        assert_eq!(parity_f64_classify(4.0), ParityF64Class::Even);
+        assert_eq!(parity_f64_classify(-3.0), ParityF64Class::Odd);
+        assert_eq!(parity_f64_classify(f64::NAN), ParityF64Class::NaN);
+        assert_eq!(
+            parity_f64_classify(f64::NEG_INFINITY),
+            ParityF64Class::Infinite
+        );
+        assert_eq!(parity_f64_classify(1e-300), ParityF64Class::Fractional);
+        assert_eq!(parity_f64_classify(1e300), ParityF64Class::Even);
+    }
+}
@@ -0,0 +1,367 @@
+//! Fallible parity checks for floating-point values.
+
+use core::fmt;
+
+use crate::{Parity, ParityKind};
+
+/// The reason a floating-point value has no parity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum FloatParityError {
+    /// The value is NaN.
+    NaN,
+    /// The value is positive or negative infinity.
+    Infinite,
+    /// The value is finite but has a fractional part.
+    Fractional,
+    /// The value is too large for its parity to be trusted.
+    ///
+    /// Beyond `2^53` for `f64` and `2^24` for `f32` not every integer is representable, and every representable value
+    /// is even, so the parity says more about the format than about the value that was rounded into it.
+    Inexact,
+}
+
+impl fmt::Display for FloatParityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            FloatParityError::NaN => "NaN has no parity",
+            FloatParityError::Infinite => "infinity has no parity",
+            FloatParityError::Fractional => "value with a fractional part has no parity",
+            FloatParityError::Inexact => {
+                "value is beyond the range of exactly representable integers"
+            }
+        })
+    }
+}
+
+impl core::error::Error for FloatParityError {}
+
+/// A structured account of the parity of a floating-point value, returned by [FloatParity::explain_parity].
+///
+/// Its [Display](fmt::Display) form is a sentence suitable for validation errors, such as
+/// `2.5 has no parity: its fractional part is 0.5`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityDiagnosis<T> {
+    value: T,
+    parity: Result<ParityKind, FloatParityError>,
+    fractional_part: Option<T>,
+    inexact: bool,
+}
+
+impl<T: Copy> ParityDiagnosis<T> {
+    /// Returns the value that was diagnosed.
+    #[inline]
+    pub fn value(&self) -> T {
+        self.value
+    }
+
+    /// Returns the parity of the value, or the reason it has none, as [FloatParity::try_parity] does.
+    #[inline]
+    pub fn parity(&self) -> Result<ParityKind, FloatParityError> {
+        self.parity
+    }
+
+    /// Returns the fractional part of a finite value, which has the sign of the value and is zero for integers, or
+    /// `None` for NaN and infinities.
+    #[inline]
+    pub fn fractional_part(&self) -> Option<T> {
+        self.fractional_part
+    }
+
+    /// Returns `true` if the value is an integer beyond the range of exactly representable integers, where its
+    /// parity may not be that of the value it was rounded from. [FloatParity::strict_parity] rejects such values.
+    #[inline]
+    pub fn is_inexact(&self) -> bool {
+        self.inexact
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ParityDiagnosis<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.parity, &self.fractional_part) {
+            (Ok(parity), _) if self.inexact => write!(
+                f,
+                "{} is {parity}, but it is beyond the range of exactly representable integers",
+                self.value
+            ),
+            (Ok(parity), _) => write!(f, "{} is {parity}", self.value),
+            (Err(FloatParityError::Fractional), Some(fractional)) => write!(
+                f,
+                "{} has no parity: its fractional part is {fractional}",
+                self.value
+            ),
+            (Err(FloatParityError::Infinite), _) => {
+                write!(f, "{} has no parity: it is infinite", self.value)
+            }
+            (Err(_), _) => write!(f, "{} has no parity", self.value),
+        }
+    }
+}
+
+/// Provides parity checks for floating-point types that report why a value has no parity.
+///
+/// Implemented for `f32` and `f64`. Where [Parity::is_even] and [Parity::is_odd] both return `false` for NaN,
+/// infinities and fractional values, these methods return a [FloatParityError] describing the problem.
+pub trait FloatParity: Parity {
+    /// Returns the parity of `self`, or the reason it has none.
+    fn try_parity(&self) -> Result<ParityKind, FloatParityError>;
+
+    /// Returns whether `self` is even, or the reason it has no parity.
+    #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    fn try_is_even(&self) -> Result<bool, FloatParityError> {
+        self.try_parity().map(ParityKind::is_even)
+    }
+
+    /// Returns whether `self` is odd, or the reason it has no parity.
+    #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    fn try_is_odd(&self) -> Result<bool, FloatParityError> {
+        self.try_parity().map(ParityKind::is_odd)
+    }
+
+    /// Returns the parity of `self` like [FloatParity::try_parity], but also rejects values whose magnitude exceeds
+    /// the largest integer up to which every integer is exactly representable.
+    ///
+    /// The accepted range is `±(2^53 - 1)` for `f64` and `±(2^24 - 1)` for `f32`. Larger values are reported as
+    /// [FloatParityError::Inexact].
+    fn strict_parity(&self) -> Result<ParityKind, FloatParityError>;
+
+    /// Returns a [ParityDiagnosis] describing the parity of `self`: the parity or the reason there is none, the
+    /// fractional part, and whether the value is beyond the exact-integer range.
+    fn explain_parity(&self) -> ParityDiagnosis<Self>
+    where
+        Self: Sized;
+
+    /// Returns the parity of the integer nearest to `self` if `self` is within `eps` of it, and `None` otherwise.
+    ///
+    /// This accepts measured values such as `1.9999999999` that are integers up to rounding error. A zero `eps`
+    /// behaves like [Parity::checked_parity], and a negative or NaN `eps` matches nothing.
+    ///
+    /// Requires the `std` or `libm` feature.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn parity_approx(&self, eps: Self) -> Option<ParityKind>
+    where
+        Self: Sized;
+
+    /// Returns `true` if `self` is within `eps` of an even integer.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn is_even_approx(&self, eps: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.parity_approx(eps) == Some(ParityKind::Even)
+    }
+
+    /// Returns `true` if `self` is within `eps` of an odd integer.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[inline]
+    fn is_odd_approx(&self, eps: Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.parity_approx(eps) == Some(ParityKind::Odd)
+    }
+}
+
+macro_rules! impl_float_parity {
+    ($($T:ty => $round:ident),*) => { $(
+        impl FloatParity for $T {
+            /// Returns the parity of `self`, or the reason it has none.
+            /// # Example
+            /// ```
+            /// use parity::float::{FloatParity, FloatParityError};
+            /// use parity::ParityKind;
+            #[doc = concat!("assert_eq!(3.0", stringify!($T), ".try_parity(), Ok(ParityKind::Odd));")]
+            #[doc = concat!("assert_eq!(", stringify!($T), "::NAN.try_parity(), Err(FloatParityError::NaN));")]
+            #[doc = concat!("assert_eq!(2.5", stringify!($T), ".try_is_even(), Err(FloatParityError::Fractional));")]
+            /// ```
+            #[inline]
+            #[cfg_attr(feature = "tracing", track_caller)]
+            fn try_parity(&self) -> Result<ParityKind, FloatParityError> {
+                let error = if let Some(parity) = self.checked_parity() {
+                    return Ok(parity);
+                } else if self.is_nan() {
+                    FloatParityError::NaN
+                } else if self.is_infinite() {
+                    FloatParityError::Infinite
+                } else {
+                    FloatParityError::Fractional
+                };
+                #[cfg(feature = "tracing")]
+                crate::trace::no_float_parity(f64::from(*self), error);
+                Err(error)
+            }
+
+            /// Returns the parity of `self`, rejecting values beyond the exact-integer range.
+            /// # Example
+            /// ```
+            /// use parity::float::{FloatParity, FloatParityError};
+            /// use parity::ParityKind;
+            #[doc = concat!("let digits = ", stringify!($T), "::MANTISSA_DIGITS as i32;")]
+            #[doc = concat!("let max = (2.0", stringify!($T), ").powi(digits) - 1.0;")]
+            /// assert_eq!(max.strict_parity(), Ok(ParityKind::Odd));
+            /// assert_eq!((max + 1.0).try_parity(), Ok(ParityKind::Even));
+            /// assert_eq!((max + 1.0).strict_parity(), Err(FloatParityError::Inexact));
+            /// ```
+            #[inline]
+            fn strict_parity(&self) -> Result<ParityKind, FloatParityError> {
+                const MAX_EXACT: $T = ((1u64 << <$T>::MANTISSA_DIGITS) - 1) as $T;
+                let parity = self.try_parity()?;
+                if self.abs() > MAX_EXACT {
+                    Err(FloatParityError::Inexact)
+                } else {
+                    Ok(parity)
+                }
+            }
+
+            /// Returns a structured account of the parity of `self`.
+            /// # Example
+            /// ```
+            /// use parity::float::{FloatParity, FloatParityError};
+            #[doc = concat!("let diagnosis = 2.000001", stringify!($T), ".explain_parity();")]
+            /// assert_eq!(diagnosis.parity(), Err(FloatParityError::Fractional));
+            /// assert!(diagnosis.fractional_part().unwrap() > 0.0);
+            #[doc = concat!("let fractional = 5.5", stringify!($T), ".explain_parity().to_string();")]
+            /// assert_eq!(fractional, "5.5 has no parity: its fractional part is 0.5");
+            #[doc = concat!("let infinite = ", stringify!($T), "::NEG_INFINITY.explain_parity().to_string();")]
+            /// assert_eq!(infinite, "-inf has no parity: it is infinite");
+            /// ```
+            #[inline]
+            fn explain_parity(&self) -> ParityDiagnosis<Self> {
+                const MAX_EXACT: $T = ((1u64 << <$T>::MANTISSA_DIGITS) - 1) as $T;
+                let finite = self.is_finite();
+                let parity = self.try_parity();
+                ParityDiagnosis {
+                    value: *self,
+                    parity,
+                    fractional_part: finite.then(|| self % 1.0),
+                    // checked here rather than through `strict_parity`, which would record a second tracing event
+                    inexact: parity.is_ok() && self.abs() > MAX_EXACT,
+                }
+            }
+
+            /// Returns the parity of the nearest integer if `self` is within `eps` of it.
+            /// # Example
+            /// ```
+            /// use parity::float::FloatParity;
+            /// use parity::ParityKind;
+            #[doc = concat!("assert_eq!(1.9999999", stringify!($T), ".parity_approx(1e-3), Some(ParityKind::Even));")]
+            #[doc = concat!("assert_eq!(2.5", stringify!($T), ".parity_approx(1e-3), None);")]
+            #[doc = concat!("assert!(3.001", stringify!($T), ".is_odd_approx(0.01));")]
+            /// ```
+            #[cfg(any(feature = "std", feature = "libm"))]
+            #[inline]
+            fn parity_approx(&self, eps: Self) -> Option<ParityKind> {
+                #[cfg(feature = "std")]
+                let nearest = self.round();
+                #[cfg(not(feature = "std"))]
+                let nearest = libm::$round(*self);
+                if (self - nearest).abs() <= eps {
+                    nearest.checked_parity()
+                } else {
+                    None
+                }
+            }
+        }
+    )* };
+}
+
+impl_float_parity![f32 => roundf, f64 => round];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors() {
+        assert_eq!(f64::INFINITY.try_is_even(), Err(FloatParityError::Infinite));
+        assert_eq!(
+            f32::NEG_INFINITY.try_is_odd(),
+            Err(FloatParityError::Infinite)
+        );
+        assert_eq!((-0.5f64).try_parity(), Err(FloatParityError::Fractional));
+        assert_eq!(f32::NAN.try_is_odd(), Err(FloatParityError::NaN));
+    }
+
+    #[test]
+    fn agrees_with_parity() {
+        for x in [
+            -3.0f64,
+            -2.0,
+            -0.0,
+            0.0,
+            1.0,
+            4.0,
+            1e300,
+            0.25,
+            f64::NAN,
+            f64::INFINITY,
+        ] {
+            assert_eq!(x.try_parity().ok(), x.checked_parity());
+            assert_eq!(x.try_is_even().unwrap_or(false), x.is_even());
+            assert_eq!(x.try_is_odd().unwrap_or(false), x.is_odd());
+        }
+    }
+
+    #[test]
+    fn strict_range() {
+        assert_eq!(16_777_215.0f32.strict_parity(), Ok(ParityKind::Odd));
+        assert_eq!(
+            (-16_777_216.0f32).strict_parity(),
+            Err(FloatParityError::Inexact)
+        );
+        assert_eq!(f64::MAX.try_parity(), Ok(ParityKind::Even));
+        assert_eq!(f64::MAX.strict_parity(), Err(FloatParityError::Inexact));
+        assert_eq!(f64::NAN.strict_parity(), Err(FloatParityError::NaN));
+        assert_eq!(1e300f64.strict_parity(), Err(FloatParityError::Inexact));
+        assert_eq!(0.5f64.strict_parity(), Err(FloatParityError::Fractional));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn approximate() {
+        assert_eq!(2.0f64.parity_approx(0.0), Some(ParityKind::Even));
+        assert_eq!(1.9999999999f64.parity_approx(0.0), None);
+        assert!(1.9999999999f64.is_even_approx(1e-9));
+        assert!(!1.9999999999f64.is_odd_approx(1e-9));
+        assert!((-0.9999f32).is_odd_approx(1e-3));
+        assert_eq!(0.5f64.parity_approx(0.5), Some(ParityKind::Odd));
+        assert_eq!(3.0f64.parity_approx(-1.0), None);
+        assert_eq!(3.0f64.parity_approx(f64::NAN), None);
+        assert_eq!(f64::INFINITY.parity_approx(f64::INFINITY), None);
+        assert_eq!(f64::NAN.parity_approx(1.0), None);
+    }
+
+    #[test]
+    fn diagnosis() {
+        let fractional = (-2.25f64).explain_parity();
+        assert_eq!(fractional.value(), -2.25);
+        assert_eq!(fractional.fractional_part(), Some(-0.25));
+        assert!(!fractional.is_inexact());
+        assert_eq!(
+            std::format!("{fractional}"),
+            "-2.25 has no parity: its fractional part is -0.25"
+        );
+
+        let exact = 7.0f32.explain_parity();
+        assert_eq!(exact.parity(), Ok(ParityKind::Odd));
+        assert_eq!(exact.fractional_part(), Some(0.0));
+        assert_eq!(std::format!("{exact}"), "7 is odd");
+
+        let inexact = 1e20f64.explain_parity();
+        assert_eq!(inexact.parity(), Ok(ParityKind::Even));
+        assert!(inexact.is_inexact());
+        assert_eq!(
+            std::format!("{inexact}"),
+            "100000000000000000000 is even, but it is beyond the range of exactly representable integers"
+        );
+
+        let nan = f64::NAN.explain_parity();
+        assert_eq!(nan.parity(), Err(FloatParityError::NaN));
+        assert_eq!(nan.fractional_part(), None);
+        assert_eq!(std::format!("{nan}"), "NaN has no parity");
+    }
+}
@@ -0,0 +1,185 @@
+//! Parity arguments for impartial games under the normal play convention, where the player unable to move loses.
+//!
+//! # Example
+//! ```
+//! use parity::game::{is_losing_position, nim_winning_move};
+//!
+//! let mut heaps = [3, 4, 5];
+//! assert!(!is_losing_position(&heaps));
+//! let (heap, take) = nim_winning_move(&heaps).unwrap();
+//! heaps[heap] -= take;
+//! assert!(is_losing_position(&heaps));
+//! ```
+
+/// Returns the nim-sum of `heaps`, their bitwise XOR.
+///
+/// Bit `k` of the nim-sum is the parity of the number of heaps with bit `k` set.
+///
+/// # Example
+/// ```
+/// use parity::game::nim_sum;
+/// assert_eq!(nim_sum(&[3, 4, 5]), 2);
+/// ```
+#[inline]
+pub fn nim_sum(heaps: &[u64]) -> u64 {
+    heaps.iter().fold(0, |sum, heap| sum ^ heap)
+}
+
+/// Returns `true` if the player to move in a game of Nim with `heaps` loses against perfect play.
+///
+/// By Bouton's theorem these are exactly the positions where every bit column of the heaps has even parity, so
+/// that the [nim_sum] is zero.
+///
+/// # Example
+/// ```
+/// use parity::game::is_losing_position;
+///
+/// assert!(is_losing_position(&[1, 2, 3]));
+/// assert!(!is_losing_position(&[1, 2, 4]));
+/// assert!(is_losing_position(&[]));
+/// ```
+#[inline]
+pub fn is_losing_position(heaps: &[u64]) -> bool {
+    nim_sum(heaps) == 0
+}
+
+/// Returns a move leaving a losing position for the opponent, as the index of a heap and how many to take from it.
+///
+/// Returns `None` if the position is already losing, so that every move leaves a winning position.
+///
+/// # Example
+/// ```
+/// use parity::game::nim_winning_move;
+///
+/// assert_eq!(nim_winning_move(&[3, 4, 5]), Some((0, 2)));
+/// assert_eq!(nim_winning_move(&[1, 2, 3]), None);
+/// ```
+pub fn nim_winning_move(heaps: &[u64]) -> Option<(usize, u64)> {
+    let sum = nim_sum(heaps);
+    if sum == 0 {
+        return None;
+    }
+    // a heap with the highest bit of the sum set shrinks when XORed with the sum
+    heaps
+        .iter()
+        .position(|&heap| heap ^ sum < heap)
+        .map(|index| (index, heaps[index] - (heaps[index] ^ sum)))
+}
+
+/// Returns `true` if the player to move loses the subtraction game on a single `pile` where each move takes between
+/// one and `max_take` items.
+///
+/// The losing piles are the multiples of `max_take + 1`. With `max_take == 1` players simply alternate, and the
+/// losing piles are the even ones.
+///
+/// # Panics
+/// Panics if `max_take` is zero, as no move would ever be possible.
+///
+/// # Example
+/// ```
+/// use parity::game::subtraction_game_is_losing;
+///
+/// assert!(subtraction_game_is_losing(6, 1));
+/// assert!(!subtraction_game_is_losing(7, 1));
+/// assert!(subtraction_game_is_losing(8, 3));
+/// ```
+#[inline]
+#[track_caller]
+pub fn subtraction_game_is_losing(pile: u64, max_take: u64) -> bool {
+    assert!(max_take > 0, "max_take must be nonzero");
+    match max_take.checked_add(1) {
+        Some(period) => pile.is_multiple_of(period),
+        None => pile == 0,
+    }
+}
+
+/// Returns how many items to take from `pile` to leave a losing position for the opponent in the subtraction game
+/// of [subtraction_game_is_losing], or `None` if the position is already losing.
+///
+/// # Panics
+/// Panics if `max_take` is zero.
+///
+/// # Example
+/// ```
+/// use parity::game::subtraction_game_move;
+///
+/// assert_eq!(subtraction_game_move(10, 3), Some(2));
+/// assert_eq!(subtraction_game_move(7, 1), Some(1));
+/// assert_eq!(subtraction_game_move(8, 3), None);
+/// ```
+#[inline]
+#[track_caller]
+pub fn subtraction_game_move(pile: u64, max_take: u64) -> Option<u64> {
+    if subtraction_game_is_losing(pile, max_take) {
+        return None;
+    }
+    match max_take.checked_add(1) {
+        Some(period) => Some(pile % period),
+        None => Some(pile),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decides small Nim positions by searching every move.
+    fn losing_by_search(heaps: &mut [u64]) -> bool {
+        for i in 0..heaps.len() {
+            let heap = heaps[i];
+            for smaller in 0..heap {
+                heaps[i] = smaller;
+                let opponent_loses = losing_by_search(heaps);
+                heaps[i] = heap;
+                if opponent_loses {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    #[test]
+    fn nim_matches_search() {
+        for a in 0..6 {
+            for b in 0..6 {
+                for c in 0..6 {
+                    let heaps = [a, b, c];
+                    assert_eq!(is_losing_position(&heaps), losing_by_search(&mut { heaps }));
+                    if let Some((index, take)) = nim_winning_move(&heaps) {
+                        assert!((1..=heaps[index]).contains(&take));
+                        let mut after = heaps;
+                        after[index] -= take;
+                        assert!(is_losing_position(&after));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subtraction_games() {
+        for max_take in 1..5 {
+            // a pile is losing if no move reaches a losing pile
+            let mut losing = [false; 30];
+            for pile in 0..30 {
+                losing[pile] = (1..=max_take.min(pile)).all(|take| !losing[pile - take]);
+                assert_eq!(
+                    subtraction_game_is_losing(pile as u64, max_take as u64),
+                    losing[pile]
+                );
+                if let Some(take) = subtraction_game_move(pile as u64, max_take as u64) {
+                    assert!(losing[pile - take as usize]);
+                }
+            }
+        }
+        assert!(!subtraction_game_is_losing(u64::MAX, u64::MAX));
+        assert_eq!(subtraction_game_move(5, u64::MAX), Some(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "must be nonzero")]
+    fn subtraction_game_needs_moves() {
+        subtraction_game_is_losing(3, 0);
+    }
+}
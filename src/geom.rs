@@ -0,0 +1,200 @@
+//! Geometry decided by parity.
+
+use crate::math::parity_of_sum;
+use crate::{IntegerParity, ParityKind};
+
+/// Returns the parity of the number of times a ray crosses a boundary.
+///
+/// Under the even-odd fill rule, a point is inside a shape when a ray from it crosses the outline an odd number of
+/// times.
+///
+/// # Example
+/// ```
+/// use parity::geom::crossings_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(crossings_parity(3), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn crossings_parity(count: usize) -> ParityKind {
+    count.parity()
+}
+
+/// Returns `true` if `point` is inside `polygon` under the even-odd fill rule.
+///
+/// The polygon is given by its vertices in order and is closed implicitly. It may be self-intersecting, in which
+/// case the regions covered an even number of times are outside. A horizontal ray is cast from `point` and edges
+/// are treated as half-open, so a ray through a vertex is counted once. Points exactly on an edge may be reported
+/// either way.
+///
+/// # Example
+/// A pentagram, whose center is covered twice and so is outside:
+/// ```
+/// use parity::geom::point_in_polygon_even_odd;
+///
+/// let star = [(0.0, 3.0), (1.8, -2.4), (-2.9, 0.9), (2.9, 0.9), (-1.8, -2.4)];
+/// assert!(!point_in_polygon_even_odd((0.0, 0.0), &star));
+/// assert!(point_in_polygon_even_odd((0.0, 2.0), &star));
+/// assert!(!point_in_polygon_even_odd((5.0, 0.0), &star));
+/// ```
+pub fn point_in_polygon_even_odd(point: (f64, f64), polygon: &[(f64, f64)]) -> bool {
+    let (x, y) = point;
+    let edges = polygon.iter().zip(polygon.iter().cycle().skip(1));
+    let crossings = edges
+        .filter(|&(&(x0, y0), &(x1, y1))| {
+            // the edge straddles the ray, counting its lower end but not its upper one
+            (y0 > y) != (y1 > y) && x < x0 + (y - y0) * (x1 - x0) / (y1 - y0)
+        })
+        .count();
+    crossings_parity(crossings).is_odd()
+}
+
+/// The color of a square on a checkerboard, named after the parity of the square.
+///
+/// As on a chessboard, where a1 is dark, the square at `(0, 0)` is [GridColor::Dark]: squares whose coordinates have
+/// an even sum are dark and the others are light.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum GridColor {
+    /// Squares where the sum of the coordinates is even.
+    Dark,
+    /// Squares where the sum of the coordinates is odd.
+    Light,
+}
+
+impl GridColor {
+    /// Returns the color of squares with the given coordinate sum `parity`.
+    #[inline]
+    pub const fn from_parity(parity: ParityKind) -> Self {
+        match parity {
+            ParityKind::Even => GridColor::Dark,
+            ParityKind::Odd => GridColor::Light,
+        }
+    }
+
+    /// Returns the parity of the coordinate sum of squares of this color.
+    #[inline]
+    pub const fn parity(self) -> ParityKind {
+        match self {
+            GridColor::Dark => ParityKind::Even,
+            GridColor::Light => ParityKind::Odd,
+        }
+    }
+}
+
+impl From<ParityKind> for GridColor {
+    #[inline]
+    fn from(parity: ParityKind) -> Self {
+        GridColor::from_parity(parity)
+    }
+}
+
+impl From<GridColor> for ParityKind {
+    #[inline]
+    fn from(color: GridColor) -> Self {
+        color.parity()
+    }
+}
+
+/// Returns the parity of `x + y` without computing the sum, as used for checkerboard coloring and red-black
+/// Gauss–Seidel sweeps.
+///
+/// # Example
+/// Updating the red points of a grid, then the black ones:
+/// ```
+/// use parity::geom::grid_parity;
+/// use parity::ParityKind;
+///
+/// let mut order = Vec::new();
+/// for color in [ParityKind::Even, ParityKind::Odd] {
+///     for y in 0..2 {
+///         for x in 0..2 {
+///             if grid_parity(x, y) == color {
+///                 order.push((x, y));
+///             }
+///         }
+///     }
+/// }
+/// assert_eq!(order, [(0, 0), (1, 1), (1, 0), (0, 1)]);
+/// ```
+#[inline]
+pub fn grid_parity<T: IntegerParity>(x: T, y: T) -> ParityKind {
+    parity_of_sum(x, y)
+}
+
+/// Returns the parity of `x + y + z` without computing the sum, the three-dimensional [grid_parity].
+#[inline]
+pub fn grid_parity_3d<T: IntegerParity>(x: T, y: T, z: T) -> ParityKind {
+    parity_of_sum(x, y) + z.parity()
+}
+
+/// Returns the checkerboard color of the square at `(x, y)`.
+///
+/// # Example
+/// ```
+/// use parity::geom::{grid_color, GridColor};
+///
+/// // the corners of a chessboard: a1, h1 and h8
+/// assert_eq!(grid_color(0, 0), GridColor::Dark);
+/// assert_eq!(grid_color(7, 0), GridColor::Light);
+/// assert_eq!(grid_color(7, 7), GridColor::Dark);
+/// ```
+#[inline]
+pub fn grid_color<T: IntegerParity>(x: T, y: T) -> GridColor {
+    GridColor::from_parity(grid_parity(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn squares() {
+        let square = [(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (0.0, 4.0)];
+        assert!(point_in_polygon_even_odd((1.0, 1.0), &square));
+        assert!(point_in_polygon_even_odd((3.9, 2.0), &square));
+        assert!(!point_in_polygon_even_odd((-1.0, 2.0), &square));
+        assert!(!point_in_polygon_even_odd((5.0, 2.0), &square));
+        assert!(!point_in_polygon_even_odd((2.0, 5.0), &square));
+        // the ray passes exactly through the vertices (0, 4) and (4, 4)
+        assert!(!point_in_polygon_even_odd((-1.0, 4.0), &square));
+    }
+
+    #[test]
+    fn vertex_on_ray() {
+        // a diamond whose left and right vertices lie on the ray from the center
+        let diamond = [(0.0, -1.0), (1.0, 0.0), (0.0, 1.0), (-1.0, 0.0)];
+        assert!(point_in_polygon_even_odd((0.0, 0.0), &diamond));
+        assert!(!point_in_polygon_even_odd((-2.0, 0.0), &diamond));
+    }
+
+    #[test]
+    fn degenerate_polygons() {
+        assert!(!point_in_polygon_even_odd((0.0, 0.0), &[]));
+        assert!(!point_in_polygon_even_odd((0.0, 0.0), &[(1.0, 1.0)]));
+        assert!(!point_in_polygon_even_odd(
+            (0.0, 0.5),
+            &[(1.0, 0.0), (1.0, 1.0)]
+        ));
+        assert_eq!(crossings_parity(0), ParityKind::Even);
+    }
+
+    #[test]
+    fn grids() {
+        for x in -3i32..3 {
+            for y in -3..3 {
+                assert_eq!(grid_parity(x, y), (x + y).parity());
+                for z in -3..3 {
+                    assert_eq!(grid_parity_3d(x, y, z), (x + y + z).parity());
+                }
+                // neighbours always differ
+                assert_ne!(grid_color(x, y), grid_color(x + 1, y));
+                assert_ne!(grid_color(x, y), grid_color(x, y + 1));
+            }
+        }
+        assert_eq!(grid_parity(u64::MAX, u64::MAX), ParityKind::Even);
+        for color in [GridColor::Dark, GridColor::Light] {
+            assert_eq!(GridColor::from(ParityKind::from(color)), color);
+        }
+    }
+}
@@ -0,0 +1,663 @@
+//! Linear algebra over GF(2), the field of parities.
+//!
+//! Addition in GF(2) is XOR and multiplication is AND, so [Gf2Vec] and [Gf2Matrix] pack their entries into bits and
+//! work on whole words at a time.
+//!
+//! # Example
+//! Solving for the data bits of a parity-check relation:
+//! ```
+//! use parity::gf2::{Gf2Matrix, Gf2Vec};
+//!
+//! let a = Gf2Matrix::from_rows(&[[1, 1, 0], [0, 1, 1], [1, 0, 0]]);
+//! let b: Gf2Vec = [true, false, true].into_iter().collect();
+//! let x = a.solve(&b).unwrap();
+//! assert_eq!(&a * &x, b);
+//! assert_eq!(a.rank(), 3);
+//! ```
+
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, AddAssign, Mul};
+
+use crate::{IntegerParity, ParityKind};
+
+/// Returns `true` if the bit-packed square matrix `rows` of size `n` is invertible over GF(2).
+fn packed_is_invertible(rows: &mut [Vec<u64>], n: usize) -> bool {
+    for col in 0..n {
+        let (word, bit) = (col / 64, 1u64 << (col % 64));
+        let Some(pivot) = (col..n).find(|&row| rows[row][word] & bit != 0) else {
+            return false;
+        };
+        rows.swap(col, pivot);
+        let (done, rest) = rows.split_at_mut(col + 1);
+        let pivot_row = &done[col];
+        for row in rest.iter_mut().filter(|row| row[word] & bit != 0) {
+            // columns before `word` are already zero in both rows
+            for (dst, src) in row[word..].iter_mut().zip(&pivot_row[word..]) {
+                *dst ^= src;
+            }
+        }
+    }
+    true
+}
+
+fn pack_rows<'a, T, I>(rows: I, n: usize) -> Vec<Vec<u64>>
+where
+    T: IntegerParity + 'a,
+    I: IntoIterator,
+    I::Item: IntoIterator<Item = &'a T>,
+{
+    rows.into_iter()
+        .map(|row| {
+            let mut packed = vec![0u64; n.div_ceil(64)];
+            let mut len = 0;
+            for (col, value) in row.into_iter().enumerate() {
+                assert!(col < n, "determinant of a non-square matrix");
+                packed[col / 64] |= (value.is_odd() as u64) << (col % 64);
+                len += 1;
+            }
+            assert!(len == n, "determinant of a non-square matrix");
+            packed
+        })
+        .collect()
+}
+
+/// Returns the parity of the determinant of the square integer matrix given as a slice of rows.
+///
+/// The matrix is reduced modulo two and bit-packed, and Gaussian elimination over GF(2) decides whether it is
+/// singular, so the determinant itself is never computed and cannot overflow. The determinant of the empty matrix
+/// is one, which is odd.
+///
+/// # Panics
+/// Panics if the matrix is not square.
+///
+/// # Example
+/// ```
+/// use parity::gf2::determinant_parity;
+/// use parity::ParityKind;
+///
+/// let matrix = [[2, 1, 0], [1, 3, 1], [0, 1, 5]]; // determinant 17
+/// assert_eq!(determinant_parity(&matrix), ParityKind::Odd);
+/// assert_eq!(determinant_parity(&[[1, 2], [3, 6]]), ParityKind::Even);
+/// ```
+pub fn determinant_parity<T: IntegerParity, R: AsRef<[T]>>(rows: &[R]) -> ParityKind {
+    let n = rows.len();
+    let mut packed = pack_rows(rows.iter().map(AsRef::as_ref), n);
+    ParityKind::from_odd(packed_is_invertible(&mut packed, n))
+}
+
+/// Returns the parity of the determinant of a square [ndarray] matrix. See [determinant_parity].
+///
+/// # Panics
+/// Panics if the matrix is not square.
+#[cfg(feature = "ndarray")]
+pub fn determinant_parity_of_array<T: IntegerParity>(
+    matrix: ndarray::ArrayView2<'_, T>,
+) -> ParityKind {
+    let n = matrix.nrows();
+    let mut packed = pack_rows(matrix.rows(), n);
+    ParityKind::from_odd(packed_is_invertible(&mut packed, n))
+}
+
+/// A vector over GF(2), with entries stored as bits.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Gf2Vec {
+    len: usize,
+    // bits past `len` are always zero
+    words: Vec<u64>,
+}
+
+impl Gf2Vec {
+    /// Returns the zero vector of length `len`.
+    pub fn zeros(len: usize) -> Self {
+        Self {
+            len,
+            words: vec![0; len.div_ceil(64)],
+        }
+    }
+
+    /// Returns the vector of the parities of `values`, reducing each one modulo two.
+    pub fn from_values<T: IntegerParity>(values: &[T]) -> Self {
+        values.iter().map(T::is_odd).collect()
+    }
+
+    /// Returns the number of entries.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector has no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns entry `i`.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> bool {
+        self.check_index(i);
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    /// Sets entry `i` to `value`.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn set(&mut self, i: usize, value: bool) {
+        self.check_index(i);
+        let bit = 1 << (i % 64);
+        if value {
+            self.words[i / 64] |= bit;
+        } else {
+            self.words[i / 64] &= !bit;
+        }
+    }
+
+    /// Flips entry `i`.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn flip(&mut self, i: usize) {
+        self.check_index(i);
+        self.words[i / 64] ^= 1 << (i % 64);
+    }
+
+    /// Appends an entry.
+    pub fn push(&mut self, value: bool) {
+        if self.len.is_multiple_of(64) {
+            self.words.push(0);
+        }
+        self.len += 1;
+        self.set(self.len - 1, value);
+    }
+
+    /// Returns the number of entries that are one, the Hamming weight.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if every entry is zero.
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Returns the dot product of `self` and `other`, the parity of the number of positions where both are one.
+    ///
+    /// # Panics
+    /// Panics if the vectors have different lengths.
+    pub fn dot(&self, other: &Gf2Vec) -> ParityKind {
+        self.check_len(other);
+        let odd = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .fold(0, |acc, (a, b)| acc ^ (a & b));
+        ParityKind::from_odd(odd.count_ones() % 2 == 1)
+    }
+
+    /// Returns an iterator over the entries.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    #[inline]
+    fn check_index(&self, i: usize) {
+        assert!(
+            i < self.len,
+            "index {i} is out of bounds for a vector of length {}",
+            self.len
+        );
+    }
+
+    #[inline]
+    fn check_len(&self, other: &Gf2Vec) {
+        assert!(
+            self.len == other.len,
+            "vectors of lengths {} and {} do not match",
+            self.len,
+            other.len
+        );
+    }
+}
+
+impl FromIterator<bool> for Gf2Vec {
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut vec = Gf2Vec::default();
+        for value in iter {
+            vec.push(value);
+        }
+        vec
+    }
+}
+
+impl FromIterator<ParityKind> for Gf2Vec {
+    fn from_iter<I: IntoIterator<Item = ParityKind>>(iter: I) -> Self {
+        iter.into_iter().map(ParityKind::is_odd).collect()
+    }
+}
+
+/// Adds entrywise, which is XOR.
+///
+/// # Panics
+/// Panics if the vectors have different lengths.
+impl AddAssign<&Gf2Vec> for Gf2Vec {
+    // addition in GF(2) is XOR
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn add_assign(&mut self, other: &Gf2Vec) {
+        self.check_len(other);
+        for (dst, src) in self.words.iter_mut().zip(&other.words) {
+            *dst ^= src;
+        }
+    }
+}
+
+impl Add for &Gf2Vec {
+    type Output = Gf2Vec;
+
+    fn add(self, other: &Gf2Vec) -> Gf2Vec {
+        let mut sum = self.clone();
+        sum += other;
+        sum
+    }
+}
+
+impl fmt::Display for Gf2Vec {
+    /// Writes the entries as a string of `0`s and `1`s.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.iter()
+            .try_for_each(|bit| f.write_str(if bit { "1" } else { "0" }))
+    }
+}
+
+/// A matrix over GF(2), stored as bit-packed rows.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gf2Matrix {
+    cols: usize,
+    rows: Vec<Gf2Vec>,
+}
+
+impl Gf2Matrix {
+    /// Returns the zero matrix with `rows` rows and `cols` columns.
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self {
+            cols,
+            rows: vec![Gf2Vec::zeros(cols); rows],
+        }
+    }
+
+    /// Returns the `n` by `n` identity matrix.
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::zeros(n, n);
+        for i in 0..n {
+            matrix.set(i, i, true);
+        }
+        matrix
+    }
+
+    /// Returns the matrix of the parities of the integer matrix given as a slice of rows.
+    ///
+    /// # Panics
+    /// Panics if the rows have different lengths.
+    pub fn from_rows<T: IntegerParity, R: AsRef<[T]>>(rows: &[R]) -> Self {
+        let cols = rows.first().map_or(0, |row| row.as_ref().len());
+        let rows = rows
+            .iter()
+            .map(|row| {
+                assert!(row.as_ref().len() == cols, "rows have different lengths");
+                Gf2Vec::from_values(row.as_ref())
+            })
+            .collect();
+        Self { cols, rows }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn nrows(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns row `i`.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn row(&self, i: usize) -> &Gf2Vec {
+        &self.rows[i]
+    }
+
+    /// Returns the entry in row `i` and column `j`.
+    ///
+    /// # Panics
+    /// Panics if either index is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        self.rows[i].get(j)
+    }
+
+    /// Sets the entry in row `i` and column `j` to `value`.
+    ///
+    /// # Panics
+    /// Panics if either index is out of bounds.
+    #[inline]
+    pub fn set(&mut self, i: usize, j: usize, value: bool) {
+        self.rows[i].set(j, value);
+    }
+
+    /// Returns the transpose of `self`.
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::zeros(self.cols, self.nrows());
+        for (i, row) in self.rows.iter().enumerate() {
+            for j in (0..self.cols).filter(|&j| row.get(j)) {
+                transpose.set(j, i, true);
+            }
+        }
+        transpose
+    }
+
+    /// Returns the rank of `self` over GF(2).
+    pub fn rank(&self) -> usize {
+        eliminate(&mut self.rows.clone(), self.cols).len()
+    }
+
+    /// Returns a solution `x` of `self * x = b`, or `None` if there is none.
+    ///
+    /// When the system has several solutions, the free variables are set to zero.
+    ///
+    /// # Panics
+    /// Panics if the length of `b` is not the number of rows.
+    pub fn solve(&self, b: &Gf2Vec) -> Option<Gf2Vec> {
+        assert!(
+            b.len() == self.nrows(),
+            "right-hand side of length {} for a matrix with {} rows",
+            b.len(),
+            self.nrows()
+        );
+        let mut augmented: Vec<Gf2Vec> = self
+            .rows
+            .iter()
+            .zip(b.iter())
+            .map(|(row, bit)| {
+                let mut row = row.clone();
+                row.push(bit);
+                row
+            })
+            .collect();
+        let pivots = eliminate(&mut augmented, self.cols);
+        // below the pivots the coefficients are zero, so a one on the right-hand side reads 0 = 1
+        if augmented[pivots.len()..]
+            .iter()
+            .any(|row| row.get(self.cols))
+        {
+            return None;
+        }
+        let mut x = Gf2Vec::zeros(self.cols);
+        for (row, &col) in augmented.iter().zip(&pivots) {
+            x.set(col, row.get(self.cols));
+        }
+        Some(x)
+    }
+}
+
+/// Reduces `rows` to reduced row echelon form in the first `cols` columns, returning the pivot column of each
+/// nonzero row.
+fn eliminate(rows: &mut [Gf2Vec], cols: usize) -> Vec<usize> {
+    let mut pivots = Vec::new();
+    for col in 0..cols {
+        let rank = pivots.len();
+        let Some(pivot) = (rank..rows.len()).find(|&row| rows[row].get(col)) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        let pivot_row = rows[rank].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != rank && row.get(col) {
+                *row += &pivot_row;
+            }
+        }
+        pivots.push(col);
+    }
+    pivots
+}
+
+/// Adds entrywise.
+///
+/// # Panics
+/// Panics if the matrices have different shapes.
+impl Add for &Gf2Matrix {
+    type Output = Gf2Matrix;
+
+    fn add(self, other: &Gf2Matrix) -> Gf2Matrix {
+        assert!(
+            self.nrows() == other.nrows() && self.cols == other.cols,
+            "matrices have different shapes"
+        );
+        let rows = self.rows.iter().zip(&other.rows).map(|(a, b)| a + b);
+        Gf2Matrix {
+            cols: self.cols,
+            rows: rows.collect(),
+        }
+    }
+}
+
+/// Multiplies a matrix by a column vector.
+///
+/// # Panics
+/// Panics if the length of the vector is not the number of columns.
+impl Mul<&Gf2Vec> for &Gf2Matrix {
+    type Output = Gf2Vec;
+
+    fn mul(self, x: &Gf2Vec) -> Gf2Vec {
+        self.rows.iter().map(|row| row.dot(x)).collect()
+    }
+}
+
+/// Multiplies two matrices.
+///
+/// # Panics
+/// Panics if the number of columns of the left matrix is not the number of rows of the right one.
+impl Mul for &Gf2Matrix {
+    type Output = Gf2Matrix;
+
+    fn mul(self, other: &Gf2Matrix) -> Gf2Matrix {
+        assert!(
+            self.cols == other.nrows(),
+            "cannot multiply a matrix with {} columns by one with {} rows",
+            self.cols,
+            other.nrows()
+        );
+        let rows = self.rows.iter().map(|row| {
+            let mut product = Gf2Vec::zeros(other.cols);
+            for k in (0..self.cols).filter(|&k| row.get(k)) {
+                product += &other.rows[k];
+            }
+            product
+        });
+        Gf2Matrix {
+            cols: other.cols,
+            rows: rows.collect(),
+        }
+    }
+}
+
+impl fmt::Display for Gf2Matrix {
+    /// Writes each row as a string of `0`s and `1`s on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn determinant(m: &[Vec<i64>]) -> i64 {
+        if m.is_empty() {
+            return 1;
+        }
+        (0..m.len())
+            .map(|col| {
+                let minor: Vec<Vec<i64>> = m[1..]
+                    .iter()
+                    .map(|row| [&row[..col], &row[col + 1..]].concat())
+                    .collect();
+                let sign = if col % 2 == 0 { 1 } else { -1 };
+                sign * m[0][col] * determinant(&minor)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn matches_cofactor_expansion() {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        for n in 0..6 {
+            for _ in 0..50 {
+                let m: Vec<Vec<i64>> = (0..n)
+                    .map(|_| {
+                        (0..n)
+                            .map(|_| {
+                                seed ^= seed << 13;
+                                seed ^= seed >> 7;
+                                seed ^= seed << 17;
+                                (seed % 7) as i64 - 3
+                            })
+                            .collect()
+                    })
+                    .collect();
+                assert_eq!(determinant_parity(&m), determinant(&m).parity());
+            }
+        }
+    }
+
+    #[test]
+    fn wide_matrices() {
+        let n = 130;
+        let identity: Vec<Vec<u8>> = (0..n)
+            .map(|i| (0..n).map(|j| (i == j) as u8).collect())
+            .collect();
+        assert_eq!(determinant_parity(&identity), ParityKind::Odd);
+        let mut singular = identity.clone();
+        singular[129] = singular[64].clone();
+        assert_eq!(determinant_parity(&singular), ParityKind::Even);
+    }
+
+    #[test]
+    #[should_panic]
+    fn not_square() {
+        determinant_parity(&[[1, 2]]);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn arrays() {
+        let matrix = ndarray::arr2(&[[2, 1], [1, 3]]);
+        assert_eq!(determinant_parity_of_array(matrix.view()), ParityKind::Odd);
+    }
+
+    fn random_matrix(seed: &mut u64, rows: usize, cols: usize) -> Gf2Matrix {
+        let mut matrix = Gf2Matrix::zeros(rows, cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                *seed ^= *seed << 13;
+                *seed ^= *seed >> 7;
+                *seed ^= *seed << 17;
+                matrix.set(i, j, seed.is_multiple_of(3));
+            }
+        }
+        matrix
+    }
+
+    #[test]
+    fn vectors() {
+        let mut v = Gf2Vec::from_values(&[1, 2, 3, -5]);
+        assert_eq!(std::format!("{v}"), "1011");
+        assert_eq!(v.count_ones(), 3);
+        v.flip(1);
+        v.set(0, false);
+        assert_eq!(v, [false, true, true, true].into_iter().collect());
+        let w: Gf2Vec = [
+            ParityKind::Odd,
+            ParityKind::Odd,
+            ParityKind::Even,
+            ParityKind::Odd,
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(v.dot(&w), ParityKind::Even);
+        assert!((&v + &v).is_zero());
+        assert_eq!(
+            (&v + &w).iter().collect::<Vec<_>>(),
+            [true, false, true, false]
+        );
+
+        let long: Gf2Vec = (0..200).map(|i: u32| i.is_multiple_of(3)).collect();
+        assert_eq!(long.len(), 200);
+        assert_eq!(long.count_ones(), 67);
+        assert_eq!(long.dot(&long), ParityKind::Odd);
+    }
+
+    #[test]
+    #[should_panic(expected = "do not match")]
+    fn mismatched_lengths() {
+        Gf2Vec::zeros(3).dot(&Gf2Vec::zeros(4));
+    }
+
+    #[test]
+    fn rank_and_products() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15u64;
+        for (n, m) in [(0, 0), (3, 5), (8, 8), (70, 66), (5, 130)] {
+            let a = random_matrix(&mut seed, n, m);
+            let rank = a.rank();
+            assert!(rank <= n.min(m));
+            assert_eq!(a.transpose().rank(), rank);
+            assert_eq!(&a * &Gf2Matrix::identity(m), a);
+            assert_eq!(&Gf2Matrix::identity(n) * &a, a);
+            assert_eq!((&a + &a).rank(), 0);
+
+            let b = random_matrix(&mut seed, m, 4);
+            let x: Gf2Vec = (0..4).map(|i| i != 2).collect();
+            assert_eq!(&(&a * &b) * &x, &a * &(&b * &x));
+        }
+        assert_eq!(Gf2Matrix::identity(100).rank(), 100);
+        let square = random_matrix(&mut seed, 6, 6);
+        let rows: Vec<Vec<u8>> = (0..6)
+            .map(|i| (0..6).map(|j| square.get(i, j) as u8).collect())
+            .collect();
+        assert_eq!(square.rank() == 6, determinant_parity(&rows).is_odd());
+    }
+
+    #[test]
+    fn solve() {
+        let mut seed = 0x0123_4567_89ab_cdefu64;
+        for (n, m) in [(4, 4), (6, 3), (3, 6), (65, 65)] {
+            let a = random_matrix(&mut seed, n, m);
+            // a right-hand side in the column space always has a solution
+            let x: Gf2Vec = (0..m).map(|i: usize| i.is_multiple_of(2)).collect();
+            let b = &a * &x;
+            assert_eq!(&a * &a.solve(&b).unwrap(), b);
+        }
+
+        let a = Gf2Matrix::from_rows(&[[1, 1], [1, 1]]);
+        let b: Gf2Vec = [true, false].into_iter().collect();
+        assert_eq!(a.solve(&b), None);
+        assert_eq!(a.solve(&Gf2Vec::zeros(2)), Some(Gf2Vec::zeros(2)));
+    }
+}
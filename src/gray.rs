@@ -0,0 +1,146 @@
+//! Binary-reflected Gray codes, in which consecutive values differ in exactly one bit.
+//!
+//! Gray codes are tied to parity in both directions: the [bit parity](crate::BitParity) of the Gray code of `n` is
+//! the parity of `n`, and bit `k` of the value decoded from a Gray code is the parity of the code's bits `k` and up.
+
+use crate::ParityKind;
+
+/// Converts unsigned integers to and from their binary-reflected Gray code.
+pub trait GrayCode: Sized {
+    /// Returns the Gray code of `self`.
+    fn to_gray(self) -> Self;
+
+    /// Returns the value whose Gray code is `self`.
+    // named to pair with `to_gray`, like `u32::from_be` pairs with `to_be`
+    #[allow(clippy::wrong_self_convention)]
+    fn from_gray(self) -> Self;
+}
+
+macro_rules! impl_gray_code {
+    ($($T:ty),*) => { $(
+        impl GrayCode for $T {
+            /// Returns the Gray code of `self`.
+            /// # Example
+            /// ```
+            /// use parity::gray::GrayCode;
+            #[doc = concat!("assert_eq!(5", stringify!($T), ".to_gray(), 0b111);")]
+            /// ```
+            #[inline]
+            fn to_gray(self) -> Self {
+                self ^ (self >> 1)
+            }
+
+            /// Returns the value whose Gray code is `self`.
+            /// # Example
+            /// ```
+            /// use parity::gray::GrayCode;
+            #[doc = concat!("assert_eq!(0b111", stringify!($T), ".from_gray(), 5);")]
+            /// ```
+            #[inline]
+            fn from_gray(self) -> Self {
+                // a prefix XOR from the most significant bit down, doubling the span each step
+                let mut value = self;
+                let mut shift = 1;
+                while shift < <$T>::BITS {
+                    value ^= value >> shift;
+                    shift <<= 1;
+                }
+                value
+            }
+        }
+    )* };
+}
+
+impl_gray_code![u8, u16, u32, u64, u128, usize];
+
+/// Returns the position of the bit that flips between the Gray codes of `n` and `n + 1`, where bit 0 is the least
+/// significant bit.
+///
+/// This is the number of trailing zeros of `n + 1`. The sequence wraps around, so the step from `u64::MAX` back to
+/// zero flips bit 63.
+///
+/// # Example
+/// ```
+/// use parity::gray::gray_transition_bit;
+///
+/// let flips: Vec<u32> = (0..7).map(gray_transition_bit).collect();
+/// assert_eq!(flips, [0, 1, 0, 2, 0, 1, 0]);
+/// assert_eq!(gray_transition_bit(u64::MAX), 63);
+/// ```
+#[inline]
+pub fn gray_transition_bit(n: u64) -> u32 {
+    (n.to_gray() ^ n.wrapping_add(1).to_gray()).trailing_zeros()
+}
+
+/// Returns the parity of the position of the bit that flips between the Gray codes of `n` and `n + 1`.
+///
+/// When the Gray code of `n` has an even number of set bits, which happens exactly when `n` is even, the next step
+/// flips bit 0. Otherwise it flips the bit just above the lowest set bit. In the Tower of Hanoi, where step `n`
+/// moves the disk [gray_transition_bit(n)](gray_transition_bit), this parity decides which way the disk travels.
+///
+/// # Example
+/// ```
+/// use parity::gray::gray_transition_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(gray_transition_parity(4), ParityKind::Even);
+/// assert_eq!(gray_transition_parity(5), ParityKind::Odd);
+/// assert_eq!(gray_transition_parity(7), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn gray_transition_parity(n: u64) -> ParityKind {
+    ParityKind::from_odd(gray_transition_bit(n) & 1 == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BitParity, IntegerParity};
+
+    #[test]
+    fn round_trips() {
+        for n in 0..=u8::MAX {
+            assert_eq!(n.to_gray().from_gray(), n);
+            assert_eq!(n.from_gray().to_gray(), n);
+            assert_eq!(n.to_gray().bit_parity(), n.parity());
+            assert_eq!((n.to_gray() ^ n.wrapping_add(1).to_gray()).count_ones(), 1);
+        }
+        for n in [0, 1, u128::MAX, u128::MAX / 3, 1 << 127] {
+            assert_eq!(n.to_gray().from_gray(), n);
+        }
+        assert_eq!(usize::MAX.to_gray().from_gray(), usize::MAX);
+    }
+
+    #[test]
+    fn decoding_is_a_suffix_parity() {
+        for g in 0..=u16::MAX {
+            let n = g.from_gray();
+            for k in 0..16 {
+                assert_eq!(
+                    ParityKind::from_odd(n >> k & 1 == 1),
+                    g.parity_of_bit_range(k..)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn transitions() {
+        for n in 0..1000u64 {
+            let flipped = n.to_gray() ^ (n + 1).to_gray();
+            assert_eq!(flipped, 1 << gray_transition_bit(n));
+            assert_eq!(gray_transition_bit(n), (n + 1).trailing_zeros());
+            assert_eq!(
+                gray_transition_parity(n),
+                ParityKind::from_odd((n + 1).trailing_zeros() & 1 == 1)
+            );
+            if n.to_gray().bit_parity() == ParityKind::Even {
+                assert_eq!(gray_transition_bit(n), 0);
+            } else {
+                let lowest = n.to_gray().trailing_zeros();
+                assert_eq!(gray_transition_bit(n), lowest + 1);
+            }
+        }
+        assert_eq!(gray_transition_parity(u64::MAX), ParityKind::Odd);
+    }
+}
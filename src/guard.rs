@@ -0,0 +1,176 @@
+//! A scope guard that checks a parity invariant survives a block of updates, in debug builds.
+
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+use crate::{Parity, ParityKind};
+
+/// Gives access to a value and, in debug builds, asserts when dropped that the parity read from it by a closure
+/// is the same as when the guard was created.
+///
+/// Updates go through the guard, which dereferences to the value, so code that must preserve a parity, such as
+/// swapping or moving elements in pairs, fails its tests at the end of the offending scope instead of much later.
+/// A panic that is already unwinding is left alone when `std` is enabled, so the original panic is reported.
+///
+/// In release builds the closure is never called and the guard only forwards to the value.
+///
+/// # Example
+/// ```
+/// use parity::guard::DebugParityGuard;
+///
+/// struct Buffer {
+///     words: Vec<u8>,
+/// }
+///
+/// let mut buffer = Buffer { words: vec![1, 2, 3, 4] };
+/// {
+///     let mut buffer = DebugParityGuard::new(&mut buffer, |b| b.words.len());
+///     buffer.words.extend([5, 6]);
+///     buffer.words.rotate_left(1);
+/// } // the length is still even
+/// assert_eq!(buffer.words, [2, 3, 4, 5, 6, 1]);
+/// ```
+///
+/// ```no_run
+/// # use parity::guard::DebugParityGuard;
+/// let mut len = 4u32;
+/// let mut guard = DebugParityGuard::new(&mut len, |len| *len);
+/// *guard += 1;
+/// drop(guard); // panics in debug builds: "parity invariant broken: expected even, found odd"
+/// ```
+pub struct DebugParityGuard<'a, T: ?Sized, P: Parity, F: Fn(&T) -> P> {
+    value: &'a mut T,
+    // only called in debug builds
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    read: F,
+    #[cfg(debug_assertions)]
+    expected: Option<ParityKind>,
+}
+
+impl<'a, T: ?Sized, P: Parity, F: Fn(&T) -> P> DebugParityGuard<'a, T, P, F> {
+    /// Wraps `value`, recording the parity of `read(value)` in debug builds.
+    #[inline]
+    pub fn new(value: &'a mut T, read: F) -> Self {
+        DebugParityGuard {
+            #[cfg(debug_assertions)]
+            expected: read(value).checked_parity(),
+            value,
+            read,
+        }
+    }
+
+    /// Returns the parity recorded when the guard was created, or `None` in release builds and for a value without
+    /// a parity.
+    #[inline]
+    pub fn expected(&self) -> Option<ParityKind> {
+        #[cfg(debug_assertions)]
+        return self.expected;
+        #[cfg(not(debug_assertions))]
+        return None;
+    }
+}
+
+impl<T: ?Sized, P: Parity, F: Fn(&T) -> P> Deref for DebugParityGuard<'_, T, P, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        self.value
+    }
+}
+
+impl<T: ?Sized, P: Parity, F: Fn(&T) -> P> DerefMut for DebugParityGuard<'_, T, P, F> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        self.value
+    }
+}
+
+impl<T: ?Sized, P: Parity, F: Fn(&T) -> P> Drop for DebugParityGuard<'_, T, P, F> {
+    fn drop(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            #[cfg(feature = "std")]
+            if std::thread::panicking() {
+                return;
+            }
+            let found = (self.read)(self.value).checked_parity();
+            if found != self.expected {
+                panic!(
+                    "parity invariant broken: expected {}, found {}",
+                    ParityName(self.expected),
+                    ParityName(found)
+                );
+            }
+        }
+    }
+}
+
+impl<T: ?Sized + fmt::Debug, P: Parity, F: Fn(&T) -> P> fmt::Debug
+    for DebugParityGuard<'_, T, P, F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DebugParityGuard")
+            .field("value", &self.value)
+            .field("expected", &self.expected())
+            .finish_non_exhaustive()
+    }
+}
+
+// formats an optional parity for the panic message
+#[cfg(debug_assertions)]
+struct ParityName(Option<ParityKind>);
+
+#[cfg(debug_assertions)]
+impl fmt::Display for ParityName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(parity) => write!(f, "{parity}"),
+            None => f.write_str("no parity"),
+        }
+    }
+}
+
+#[cfg(all(test, debug_assertions))]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn preserved() {
+        let mut pairs: Vec<(u8, u8)> = std::vec![(1, 2), (3, 4)];
+        {
+            let mut guard = DebugParityGuard::new(&mut pairs, |p| p.len() * 2);
+            assert_eq!(guard.expected(), Some(ParityKind::Even));
+            guard.push((5, 6));
+            guard.swap(0, 2);
+        }
+        assert_eq!(pairs[0], (5, 6));
+
+        let mut x = 1.5f64;
+        let mut guard = DebugParityGuard::new(&mut x, |x| *x);
+        assert_eq!(guard.expected(), None);
+        *guard += 0.25;
+        drop(guard);
+        assert_eq!(
+            std::format!("{:?}", DebugParityGuard::new(&mut x, |x| *x)),
+            "DebugParityGuard { value: 1.75, expected: None, .. }"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "parity invariant broken: expected odd, found even")]
+    fn broken() {
+        let mut count = 3i64;
+        let mut guard = DebugParityGuard::new(&mut count, |c| *c);
+        *guard -= 3;
+    }
+
+    #[test]
+    #[should_panic(expected = "parity invariant broken: expected even, found no parity")]
+    fn lost() {
+        let mut x = 2.0f32;
+        *DebugParityGuard::new(&mut x, |x| *x) = 2.5;
+    }
+}
@@ -0,0 +1,145 @@
+//! Conversions between parities and the serial parity settings of HAL crates.
+//!
+//! `embedded-hal` defines no serial configuration type, so every HAL crate declares its own parity enum, such as
+//! `Parity { ParityNone, ParityEven, ParityOdd }`. The orphan rule keeps both this crate and the crate using the HAL
+//! from implementing `From` between that enum and [ParityKind], so [serial_parity_conversions!] instead generates a
+//! pair of conversion functions in the crate using the HAL. Together with [expected_parity_bit], firmware and
+//! host-side test code can share one description of the serial link.
+//!
+//! # Example
+//! ```
+//! # mod hal {
+//! #     pub mod serial {
+//! #         #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+//! #         pub enum Parity { ParityNone, ParityEven, ParityOdd }
+//! #     }
+//! # }
+//! use parity::hal::expected_parity_bit;
+//! use parity::{serial_parity_conversions, ParityKind};
+//!
+//! // for a HAL that declares `hal::serial::Parity`
+//! serial_parity_conversions! {
+//!     fn from_hal_parity, fn to_hal_parity: hal::serial::Parity {
+//!         none: ParityNone,
+//!         even: ParityEven,
+//!         odd: ParityOdd,
+//!     }
+//! }
+//!
+//! let setting = to_hal_parity(Some(ParityKind::Even));
+//! assert_eq!(setting, hal::serial::Parity::ParityEven);
+//! // 'A' has two set bits, so an even parity bit is clear
+//! assert_eq!(expected_parity_bit(b'A'.into(), 8, from_hal_parity(setting)), Some(false));
+//! ```
+
+use crate::serial::ParityBit;
+use crate::ParityKind;
+
+/// Returns the parity bit sent after the `data_bits` low bits of `word` on a link with the given `parity`, or `None`
+/// if the link sends no parity bit.
+///
+/// The parity bit gives the data and parity bits together the parity `parity` of set bits, as with
+/// [ParityBit::compute_for].
+///
+/// # Panics
+/// Panics if `data_bits` is not between 1 and 15, or if `word` does not fit in `data_bits` bits.
+#[inline]
+#[track_caller]
+pub fn expected_parity_bit(word: u16, data_bits: u32, parity: Option<ParityKind>) -> Option<bool> {
+    parity
+        .map_or(ParityBit::None, ParityBit::from)
+        .compute_for(word, data_bits)
+}
+
+/// Generates functions converting between a HAL crate's serial parity enum and `Option<ParityKind>`, with `None`
+/// for no parity bit. See the [hal](crate::hal) module.
+///
+/// The enum is named by its path, followed by the names of its variants for no parity, even parity and odd parity.
+/// A visibility can be given before each `fn`. Enums with further variants, such as mark and space parity, are not
+/// supported, since those have no [ParityKind].
+///
+/// Requires the `embedded-hal` feature.
+#[macro_export]
+macro_rules! serial_parity_conversions {
+    (
+        $from_vis:vis fn $from:ident, $into_vis:vis fn $into:ident: $($hal:ident)::+ {
+            none: $none:ident,
+            even: $even:ident,
+            odd: $odd:ident $(,)?
+        }
+    ) => {
+        #[doc = ::core::concat!(
+            "Returns the parity of the set bits of each frame sent with the `",
+            ::core::stringify!($($hal)::+),
+            "` setting `setting`, or `None` if it sends no parity bit."
+        )]
+        #[inline]
+        $from_vis fn $from(setting: $($hal)::+) -> ::core::option::Option<$crate::ParityKind> {
+            match setting {
+                $($hal)::+::$none => ::core::option::Option::None,
+                $($hal)::+::$even => ::core::option::Option::Some($crate::ParityKind::Even),
+                $($hal)::+::$odd => ::core::option::Option::Some($crate::ParityKind::Odd),
+            }
+        }
+
+        #[doc = ::core::concat!(
+            "Returns the `",
+            ::core::stringify!($($hal)::+),
+            "` setting giving each frame the parity `parity` of set bits, or no parity bit for `None`."
+        )]
+        #[inline]
+        $into_vis fn $into(parity: ::core::option::Option<$crate::ParityKind>) -> $($hal)::+ {
+            match parity {
+                ::core::option::Option::None => $($hal)::+::$none,
+                ::core::option::Option::Some($crate::ParityKind::Even) => $($hal)::+::$even,
+                ::core::option::Option::Some($crate::ParityKind::Odd) => $($hal)::+::$odd,
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntegerParity;
+
+    mod uart {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum Parity {
+            None,
+            Even,
+            Odd,
+        }
+    }
+
+    serial_parity_conversions! {
+        pub(crate) fn from_uart, fn to_uart: uart::Parity { none: None, even: Even, odd: Odd }
+    }
+
+    #[test]
+    fn conversions() {
+        for setting in [uart::Parity::None, uart::Parity::Even, uart::Parity::Odd] {
+            assert_eq!(to_uart(from_uart(setting)), setting);
+        }
+        assert_eq!(from_uart(uart::Parity::Odd), Some(ParityKind::Odd));
+        assert_eq!(to_uart(None), uart::Parity::None);
+    }
+
+    #[test]
+    fn parity_bits() {
+        for word in 0..1u16 << 8 {
+            for parity in [ParityKind::Even, ParityKind::Odd] {
+                let bit = expected_parity_bit(word, 8, Some(parity)).unwrap();
+                let frame = word | u16::from(bit) << 8;
+                assert_eq!(frame.count_ones().parity(), parity);
+            }
+            assert_eq!(expected_parity_bit(word, 8, None), None);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in 7 data bits")]
+    fn word_too_wide() {
+        expected_parity_bit(0x80, 7, Some(ParityKind::Even));
+    }
+}
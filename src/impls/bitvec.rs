@@ -0,0 +1,136 @@
+use core::ops::{Bound, RangeBounds};
+
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+use bitvec::vec::BitVec;
+
+use crate::bits::BitSliceParity;
+use crate::{BitParity, Parity, ParityKind};
+
+/// The value parity of a bit slice is its bit at index 0, treating the slice as a number stored least significant
+/// bit first. An empty slice represents zero and is even.
+impl<T: BitStore, O: BitOrder> Parity for BitSlice<T, O> {
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.first().is_some_and(|bit| *bit)
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.first().is_some_and(|bit| *bit)
+    }
+}
+
+impl<T: BitStore, O: BitOrder> BitParity for BitSlice<T, O> {
+    #[inline]
+    fn bit_parity(&self) -> ParityKind {
+        ParityKind::from_odd(self.count_ones() & 1 == 1)
+    }
+}
+
+impl<T: BitStore, O: BitOrder> BitSliceParity for BitSlice<T, O> {
+    fn masked_bit_parity<T2: BitStore, O2: BitOrder>(&self, mask: &BitSlice<T2, O2>) -> ParityKind {
+        let selected = self
+            .iter()
+            .by_vals()
+            .zip(mask.iter().by_vals())
+            .filter(|&(bit, selected)| bit && selected)
+            .count();
+        ParityKind::from_odd(selected & 1 == 1)
+    }
+
+    fn bit_range_parity<R: RangeBounds<usize>>(&self, range: R) -> ParityKind {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len(),
+        };
+        self[start..end].bit_parity()
+    }
+}
+
+impl<T: BitStore, O: BitOrder> Parity for BitVec<T, O> {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.as_bitslice().is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.as_bitslice().is_odd()
+    }
+}
+
+/// Masks and ranges select bit indices; bits beyond the end of the shorter of `self` and the mask are unset.
+impl<T: BitStore, O: BitOrder> BitParity for BitVec<T, O> {
+    #[inline]
+    fn bit_parity(&self) -> ParityKind {
+        self.as_bitslice().bit_parity()
+    }
+
+    #[inline]
+    fn parity_of_bits(&self, mask: Self) -> ParityKind {
+        BitSliceParity::masked_bit_parity(self.as_bitslice(), &mask)
+    }
+
+    #[inline]
+    fn parity_of_bit_range<R: RangeBounds<u32>>(&self, range: R) -> ParityKind {
+        let index = |&index: &u32| index as usize;
+        BitSliceParity::bit_range_parity(
+            self.as_bitslice(),
+            (range.start_bound().map(index), range.end_bound().map(index)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::prelude::*;
+
+    use crate::bits::BitSliceParity;
+    use crate::{BitParity, Parity, ParityKind};
+
+    #[test]
+    fn slices() {
+        let bits = bits![u8, Lsb0; 1, 0, 1, 1];
+        assert!(bits.is_odd());
+        assert!(bits[1..].is_even());
+        assert!(BitSlice::<u8, Lsb0>::empty().is_even());
+        assert_eq!(bits.bit_parity(), ParityKind::Odd);
+        assert_eq!(bits[2..].bit_parity(), ParityKind::Even);
+
+        assert_eq!(bits.masked_bit_parity(bits![0, 1, 1, 1]), ParityKind::Even);
+        assert_eq!(bits.masked_bit_parity(bits![u16, Msb0; 1]), ParityKind::Odd);
+        assert_eq!(bits.bit_range_parity(1..3), ParityKind::Odd);
+        assert_eq!(bits.bit_range_parity(..), bits.bit_parity());
+        assert_eq!(bits.bit_range_parity(4..), ParityKind::Even);
+    }
+
+    #[test]
+    #[should_panic]
+    fn slice_range_out_of_bounds() {
+        bits![1, 0].bit_range_parity(1..3);
+    }
+
+    #[test]
+    fn vecs() {
+        let bits = bitvec![u16, Msb0; 0, 1, 1, 0, 1];
+        assert!(bits.is_even());
+        assert_eq!(bits.bit_parity(), ParityKind::Odd);
+        assert_eq!(
+            bits.parity_of_bits(bitvec![u16, Msb0; 1, 1, 0]),
+            ParityKind::Odd
+        );
+        assert_eq!(bits.parity_of_bit_range(1..=2), ParityKind::Even);
+        assert_eq!(bits.parity_of_bit_range(..), bits.bit_parity());
+        // the slice methods are reached through `Deref`
+        assert_eq!(bits.masked_bit_parity(bits![1, 1, 0]), ParityKind::Odd);
+        assert_eq!(bits.bit_range_parity(1..=2), ParityKind::Even);
+    }
+}
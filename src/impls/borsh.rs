@@ -0,0 +1,88 @@
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{Constrained, Parity, ParityKind, ParityParam};
+
+/// Serialized as a single byte: `0` for even and `1` for odd.
+impl BorshSerialize for ParityKind {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        u8::from(self.is_odd()).serialize(writer)
+    }
+}
+
+impl BorshDeserialize for ParityKind {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let value = u8::deserialize_reader(reader)?;
+        match value {
+            0 => Ok(ParityKind::Even),
+            1 => Ok(ParityKind::Odd),
+            _ => {
+                #[cfg(feature = "tracing")]
+                crate::trace::rejected_parity_encoding(value.into(), "borsh");
+                Err(Error::new(ErrorKind::InvalidData, "invalid parity byte"))
+            }
+        }
+    }
+}
+
+/// Serialized as the wrapped value.
+impl<T: BorshSerialize, const P: ParityParam> BorshSerialize for Constrained<T, P> {
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+/// Deserialized as the wrapped value, failing with [ErrorKind::InvalidData] if it has the wrong parity.
+impl<T: BorshDeserialize + Parity, const P: ParityParam> BorshDeserialize for Constrained<T, P> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let value = T::deserialize_reader(reader)?;
+        if value.checked_parity() == Some(Self::PARITY) {
+            Ok(Constrained(value))
+        } else {
+            #[cfg(feature = "tracing")]
+            crate::trace::rejected_wrong_parity::<T>(Self::PARITY, "borsh");
+            Err(Error::new(
+                ErrorKind::InvalidData,
+                match Self::PARITY {
+                    ParityKind::Even => "expected an even value",
+                    ParityKind::Odd => "expected an odd value",
+                },
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Even, Odd};
+
+    #[test]
+    fn round_trip() {
+        let even = Even::new(-6i16).unwrap();
+        let bytes = borsh::to_vec(&even).unwrap();
+        assert_eq!(bytes, borsh::to_vec(&-6i16).unwrap());
+        assert_eq!(borsh::from_slice::<Even<i16>>(&bytes).unwrap(), even);
+
+        let odd = Odd::new(3.0f64).unwrap();
+        let bytes = borsh::to_vec(&odd).unwrap();
+        assert_eq!(borsh::from_slice::<Odd<f64>>(&bytes).unwrap(), odd);
+
+        let bytes = borsh::to_vec(&ParityKind::Odd).unwrap();
+        assert_eq!(bytes, [1]);
+        assert_eq!(
+            borsh::from_slice::<ParityKind>(&bytes).unwrap(),
+            ParityKind::Odd
+        );
+    }
+
+    #[test]
+    fn validates_on_deserialize() {
+        let bytes = borsh::to_vec(&7u32).unwrap();
+        let err = borsh::from_slice::<Even<u32>>(&bytes).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+        assert!(borsh::from_slice::<Odd<u32>>(&bytes).is_ok());
+        assert!(borsh::from_slice::<Odd<f32>>(&borsh::to_vec(&0.5f32).unwrap()).is_err());
+        assert!(borsh::from_slice::<ParityKind>(&[2]).is_err());
+    }
+}
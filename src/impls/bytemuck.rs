@@ -0,0 +1,67 @@
+use bytemuck::{CheckedBitPattern, NoUninit, Zeroable};
+
+use crate::{Constrained, Even, Odd, Parity, ParityParam};
+
+// SAFETY: the wrappers are `repr(transparent)`, so they have the same layout and padding as `T`.
+unsafe impl<T: NoUninit, const P: ParityParam> NoUninit for Constrained<T, P> {}
+
+macro_rules! impl_bytemuck_wrappers {
+    ($($T:ty),*) => { $(
+        // SAFETY: the all-zero bit pattern is zero, which is even.
+        unsafe impl Zeroable for Even<$T> {}
+
+        // SAFETY: `Even<$T>` has the layout of `$T`, and every even bit pattern of it is a valid `Even<$T>`.
+        unsafe impl CheckedBitPattern for Even<$T> {
+            type Bits = $T;
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &$T) -> bool {
+                bits.is_even()
+            }
+        }
+
+        // SAFETY: `Odd<$T>` has the layout of `$T`, and every odd bit pattern of it is a valid `Odd<$T>`.
+        unsafe impl CheckedBitPattern for Odd<$T> {
+            type Bits = $T;
+
+            #[inline]
+            fn is_valid_bit_pattern(bits: &$T) -> bool {
+                bits.is_odd()
+            }
+        }
+    )* };
+}
+
+impl_bytemuck_wrappers![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64];
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::checked::{self, CheckedCastError};
+
+    use super::*;
+
+    #[test]
+    fn checked_casts() {
+        let words = [2u32, 8, 12];
+        let evens: &[Even<u32>] = checked::try_cast_slice(&words).unwrap();
+        assert_eq!(evens.iter().map(|even| *even.get()).sum::<u32>(), 22);
+        assert_eq!(bytemuck::cast_slice::<Even<u32>, u32>(evens), words);
+
+        assert_eq!(
+            checked::try_cast_slice::<u32, Odd<u32>>(&[1, 2]),
+            Err(CheckedCastError::InvalidBitPattern)
+        );
+        assert_eq!(
+            checked::try_cast::<f64, Odd<f64>>(-3.0),
+            Ok(Constrained(-3.0))
+        );
+        assert!(checked::try_cast::<f64, Even<f64>>(f64::NAN).is_err());
+
+        let bytes = 6i16.to_ne_bytes();
+        assert_eq!(
+            checked::try_from_bytes::<Even<i16>>(&bytes),
+            Ok(&Constrained(6))
+        );
+        assert_eq!(Even::<u64>::zeroed(), Even::<u64>::ZERO);
+    }
+}
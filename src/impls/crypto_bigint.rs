@@ -0,0 +1,60 @@
+use crypto_bigint::Uint;
+
+use crate::ct::ConstantTimeParity;
+use crate::Parity;
+
+/// Reads the low bit of the least significant limb through [ConstantTimeParity::ct_parity], so the time taken does
+/// not depend on the value. Only the `bool` returned can leak: callers handling secrets should use the
+/// [ConstantTimeParity] methods instead and keep the result out of branches.
+///
+/// `Uint` has an inherent `is_odd` returning a `Choice`, which method calls resolve to; generic code bounded on
+/// [Parity] and calls written as `Parity::is_odd(&x)` use this implementation.
+impl<const LIMBS: usize> Parity for Uint<LIMBS> {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.ct_is_even() == 1
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.ct_is_odd() == 1
+    }
+}
+
+/// Masks the least significant limb without branching. The limb is read whatever the value, and which limb that is
+/// depends only on `LIMBS`, so neither the memory accesses nor the instructions executed depend on the value.
+impl<const LIMBS: usize> ConstantTimeParity for Uint<LIMBS> {
+    #[inline]
+    fn ct_parity(&self) -> u8 {
+        let low = self.as_words().first().map_or(0, |&word| word & 1);
+        core::hint::black_box(low as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crypto_bigint::{U256, U64};
+
+    use crate::ct::ConstantTimeParity;
+    use crate::{Parity, ParityKind};
+
+    #[test]
+    fn matches_low_bit() {
+        assert!(U256::ZERO.is_even());
+        assert!(Parity::is_odd(&U256::ONE));
+        assert!(Parity::is_odd(&U256::MAX));
+        assert_eq!(U64::from_u64(10).checked_parity(), Some(ParityKind::Even));
+        assert_eq!(U256::from_u64(3).shl_vartime(200).ct_is_even(), 1);
+    }
+
+    #[test]
+    fn ignores_high_limbs() {
+        // values agreeing in the lowest limb share a parity, and the same code path computes it
+        let low = U256::from_u64(0x1234_5679);
+        for shift in [64, 128, 192, 255] {
+            let value = low.wrapping_add(&U256::ONE.shl_vartime(shift));
+            assert_eq!(value.ct_parity(), low.ct_parity());
+            assert_eq!(value.ct_is_even() + value.ct_is_odd(), 1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::format;
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::SmallInt;
+
+use crate::ParityKind;
+
+/// Stored as a `SMALLINT`: `0` for even and `1` for odd.
+impl<DB: Backend> ToSql<SmallInt, DB> for ParityKind
+where
+    i16: ToSql<SmallInt, DB>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let value: &'static i16 = match self {
+            ParityKind::Even => &0,
+            ParityKind::Odd => &1,
+        };
+        value.to_sql(out)
+    }
+}
+
+/// Fails for any value other than `0` and `1`.
+impl<DB: Backend> FromSql<SmallInt, DB> for ParityKind
+where
+    i16: FromSql<SmallInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = i16::from_sql(bytes)?;
+        super::parity_from_i16(value).ok_or_else(|| format!("invalid parity {value}").into())
+    }
+}
@@ -0,0 +1,58 @@
+use malachite::{Integer, Natural};
+
+use crate::Parity;
+
+/// Reads only the least significant limb, so the check takes constant time however large the number is. Zero has
+/// no limbs and is even.
+impl Parity for Natural {
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.is_odd()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.limbs().next().is_some_and(|limb| limb & 1 == 1)
+    }
+}
+
+/// The parity of an integer is the parity of its magnitude, which is stored alongside the sign.
+impl Parity for Integer {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.unsigned_abs_ref().is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.unsigned_abs_ref().is_odd()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Integer, Natural};
+
+    use crate::{Parity, ParityKind};
+
+    #[test]
+    fn naturals() {
+        assert!(Natural::from(0u8).is_even());
+        assert!(Natural::from(u64::MAX).is_odd());
+        let big = Natural::from(u64::MAX) * Natural::from(u64::MAX) + Natural::from(1u8);
+        assert!(big.is_even());
+        assert_eq!(
+            (big + Natural::from(1u8)).checked_parity(),
+            Some(ParityKind::Odd)
+        );
+    }
+
+    #[test]
+    fn integers() {
+        assert!(Integer::from(-7).is_odd());
+        assert!(Integer::from(-8).is_even());
+        assert!(Integer::from(i128::MIN).is_even());
+        assert!(Integer::from(0).same_parity(&Integer::from(-2)));
+        assert!(Integer::from(i64::MIN + 1).is_parity_defined());
+    }
+}
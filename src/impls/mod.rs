@@ -0,0 +1,54 @@
+//! Implementations for types from optional dependencies.
+
+#[cfg(feature = "bitvec")]
+mod bitvec;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "crypto-bigint")]
+mod crypto_bigint;
+#[cfg(feature = "diesel")]
+mod diesel;
+#[cfg(feature = "malachite")]
+mod malachite;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rug")]
+mod rug;
+#[cfg(feature = "sqlx")]
+mod sqlx;
+#[cfg(feature = "uuid")]
+mod uuid;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
+
+/// Decodes the `SMALLINT` representation of a parity used by the database integrations.
+#[cfg(any(feature = "diesel", feature = "sqlx"))]
+fn parity_from_i16(value: i16) -> Option<crate::ParityKind> {
+    match value {
+        0 => Some(crate::ParityKind::Even),
+        1 => Some(crate::ParityKind::Odd),
+        _ => {
+            #[cfg(feature = "tracing")]
+            crate::trace::rejected_parity_encoding(value.into(), "SMALLINT");
+            None
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "diesel", feature = "sqlx")))]
+mod tests {
+    use super::*;
+    use crate::ParityKind;
+
+    #[test]
+    fn smallint_representation() {
+        assert_eq!(parity_from_i16(0), Some(ParityKind::Even));
+        assert_eq!(parity_from_i16(1), Some(ParityKind::Odd));
+        assert_eq!(parity_from_i16(-1), None);
+        assert_eq!(parity_from_i16(2), None);
+    }
+}
@@ -0,0 +1,96 @@
+use std::boxed::Box;
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{Constrained, Even, Odd, Parity, ParityCount, ParityKind};
+
+impl Arbitrary for ParityKind {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ParityKind::from_odd(bool::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.is_odd().shrink().map(ParityKind::from_odd))
+    }
+}
+
+impl Arbitrary for ParityCount {
+    fn arbitrary(g: &mut Gen) -> Self {
+        // halved so that the total is always representable, as it is for any count of actual values
+        ParityCount {
+            even: usize::arbitrary(g) / 2,
+            odd: usize::arbitrary(g) / 2,
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(
+            (self.even, self.odd)
+                .shrink()
+                .map(|(even, odd)| ParityCount { even, odd }),
+        )
+    }
+}
+
+// Shrinking only keeps candidates that already have the right parity: adjusting a candidate could move it away from
+// zero and keep the shrinker from terminating.
+macro_rules! impl_arbitrary_wrappers {
+    ($($T:ty),*) => { $(
+        impl Arbitrary for Even<$T> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Constrained(<$T>::arbitrary(g) & !1)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.0.shrink().filter(Parity::is_even).map(Constrained))
+            }
+        }
+
+        impl Arbitrary for Odd<$T> {
+            fn arbitrary(g: &mut Gen) -> Self {
+                Constrained(<$T>::arbitrary(g) | 1)
+            }
+
+            fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+                Box::new(self.0.shrink().filter(Parity::is_odd).map(Constrained))
+            }
+        }
+    )* };
+}
+
+impl_arbitrary_wrappers![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+#[cfg(test)]
+mod tests {
+    use quickcheck::quickcheck;
+
+    use super::*;
+
+    quickcheck! {
+        fn wrappers_keep_parity(even: Even<i32>, odd: Odd<u64>) -> bool {
+            even.get().is_even() && odd.get().is_odd()
+        }
+
+        fn count_total(count: ParityCount) -> bool {
+            count.total() == count.get(ParityKind::Even) + count.get(ParityKind::Odd)
+        }
+    }
+
+    #[test]
+    fn shrinking() {
+        assert!(Even::new(10i8)
+            .unwrap()
+            .shrink()
+            .all(|even| even.0.is_even() && even.0.abs() < 10));
+        assert!(Odd::new(-7i64)
+            .unwrap()
+            .shrink()
+            .all(|odd| odd.0.is_odd() && odd.0.abs() <= 7));
+        assert_eq!(Odd::<u8>::ONE.shrink().count(), 0);
+        assert_eq!(
+            ParityKind::Odd.shrink().collect::<std::vec::Vec<_>>(),
+            [ParityKind::Even]
+        );
+        assert_eq!(ParityKind::Even.shrink().count(), 0);
+    }
+}
@@ -0,0 +1,68 @@
+use core::fmt;
+
+use rkyv::bytecheck::Verify;
+use rkyv::rancor::{Fallible, Source};
+use rkyv::rend::{
+    f32_be, f32_le, f64_be, f64_le, i128_be, i128_le, i16_be, i16_le, i32_be, i32_le, i64_be,
+    i64_le, u128_be, u128_le, u16_be, u16_le, u32_be, u32_le, u64_be, u64_le,
+};
+use rkyv::Archive;
+
+use crate::wrapper::ArchivedConstrained;
+use crate::{Constrained, Parity, ParityKind, ParityParam};
+
+macro_rules! impl_endian_parity {
+    ($($T:ty),* $(,)?) => { $(
+        /// Has the parity of the native value.
+        impl Parity for $T {
+            #[inline]
+            fn is_even(&self) -> bool {
+                self.to_native().is_even()
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                self.to_native().is_odd()
+            }
+        }
+    )* };
+}
+
+impl_endian_parity![
+    i16_le, i32_le, i64_le, i128_le, u16_le, u32_le, u64_le, u128_le, f32_le, f64_le, i16_be,
+    i32_be, i64_be, i128_be, u16_be, u32_be, u64_be, u128_be, f32_be, f64_be,
+];
+
+/// The error of validating an archived [Even](crate::Even) or [Odd](crate::Odd) holding a value of the wrong parity.
+#[derive(Debug)]
+struct WrongParity(ParityKind);
+
+impl fmt::Display for WrongParity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected an {} value", self.0)
+    }
+}
+
+impl core::error::Error for WrongParity {}
+
+/// Validation rejects an archived value of the wrong parity, so that [rkyv::access] and [rkyv::from_bytes] cannot
+/// produce a wrapper breaking its invariant.
+// SAFETY: `verify` only reads the archived value, which `CheckBytes` has already validated.
+unsafe impl<T, C, const P: ParityParam> Verify<C> for ArchivedConstrained<T, P>
+where
+    T: Archive,
+    T::Archived: Parity,
+    C: Fallible + ?Sized,
+    C::Error: Source,
+{
+    fn verify(&self, _context: &mut C) -> Result<(), C::Error> {
+        let parity = Constrained::<T, P>::PARITY;
+        if self.get().checked_parity() == Some(parity) {
+            Ok(())
+        } else {
+            #[cfg(feature = "tracing")]
+            crate::trace::rejected_wrong_parity::<T>(parity, "rkyv");
+            Err(C::Error::new(WrongParity(parity)))
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use rug::{Float, Integer};
+
+use crate::{Parity, ParityKind};
+
+/// Uses the GMP parity test, which reads only the least significant limb.
+///
+/// `Integer` has inherent `is_even` and `is_odd` methods with the same meaning, which method calls resolve to;
+/// generic code bounded on [Parity] uses this implementation.
+impl Parity for Integer {
+    #[inline]
+    fn is_even(&self) -> bool {
+        Integer::is_even(self)
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        Integer::is_odd(self)
+    }
+}
+
+/// Like the primitive floats, a `Float` has a parity only if it is finite and has no fractional part.
+impl Parity for Float {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.checked_parity() == Some(ParityKind::Even)
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.checked_parity() == Some(ParityKind::Odd)
+    }
+
+    #[inline]
+    fn is_parity_defined(&self) -> bool {
+        self.is_integer()
+    }
+
+    /// Returns the parity of `self`, or `None` if it is NaN, infinite or has a fractional part.
+    fn checked_parity(&self) -> Option<ParityKind> {
+        if !self.is_integer() {
+            return None;
+        }
+        // `self` is `significand * 2^exp`, so for a positive exponent it is a multiple of two
+        let (significand, exp) = self.to_integer_exp()?;
+        if exp > 0 {
+            return Some(ParityKind::Even);
+        }
+        // the significand is a multiple of `2^-exp`, and negating a multiple of `2^k` in two's complement leaves
+        // bit `k` unchanged, so the sign does not matter
+        Some(ParityKind::from_odd(
+            significand.get_bit(exp.unsigned_abs()),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::float::Special;
+    use rug::{Float, Integer};
+
+    use crate::{Parity, ParityKind};
+
+    #[test]
+    fn integers() {
+        assert!(Parity::is_even(&Integer::new()));
+        assert!(Parity::is_odd(&Integer::from(-7)));
+        let big = Integer::from(1) << 1000u32;
+        assert_eq!(big.checked_parity(), Some(ParityKind::Even));
+        assert_eq!((big - 1u32).checked_parity(), Some(ParityKind::Odd));
+    }
+
+    #[test]
+    fn floats() {
+        let float = |value: f64| Float::with_val(53, value);
+        for value in [
+            0.0,
+            -0.0,
+            1.0,
+            -3.0,
+            6.0,
+            2.5,
+            1e300,
+            -(2f64.powi(52) + 1.0),
+        ] {
+            assert_eq!(
+                float(value).checked_parity(),
+                value.checked_parity(),
+                "{value}"
+            );
+        }
+        assert_eq!(Float::with_val(53, Special::Nan).checked_parity(), None);
+        assert!(!Float::with_val(53, Special::Infinity).is_parity_defined());
+
+        // beyond the range of `f64`, with a precision wide enough to hold the units bit
+        let huge_odd = Float::with_val(2000, (Integer::from(1) << 1500u32) + 1u32);
+        assert!(huge_odd.is_odd());
+        assert!((huge_odd + 1u32).is_even());
+    }
+}
@@ -0,0 +1,42 @@
+use std::format;
+
+use sqlx::database::Database;
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Decode, Encode, Type};
+
+use crate::ParityKind;
+
+/// Stored as a `SMALLINT`: `0` for even and `1` for odd.
+impl<DB: Database> Type<DB> for ParityKind
+where
+    i16: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <i16 as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i16 as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for ParityKind
+where
+    i16: Encode<'q, DB>,
+{
+    fn encode_by_ref(&self, buf: &mut DB::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+        i16::from(self.is_odd()).encode_by_ref(buf)
+    }
+}
+
+/// Fails for any value other than `0` and `1`.
+impl<'r, DB: Database> Decode<'r, DB> for ParityKind
+where
+    i16: Decode<'r, DB>,
+{
+    fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = i16::decode(value)?;
+        super::parity_from_i16(value).ok_or_else(|| format!("invalid parity {value}").into())
+    }
+}
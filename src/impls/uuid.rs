@@ -0,0 +1,36 @@
+use uuid::Uuid;
+
+use crate::Parity;
+
+/// A UUID has the parity of its lowest bit, that of [`Uuid::as_u128`].
+///
+/// That bit is random in version 4 and 7 UUIDs, but in version 1 and 6 UUIDs it belongs to the node ID and is the
+/// same for every UUID generated on one host. To split UUIDs of any version, see
+/// [`uuid_shard_parity`](crate::shard::uuid_shard_parity).
+impl Parity for Uuid {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.as_u128() & 1 == 0
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.as_u128() & 1 == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{Parity, ParityKind};
+
+    #[test]
+    fn low_bit() {
+        assert!(Uuid::nil().is_even());
+        assert!(Uuid::max().is_odd());
+        let id = Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c9);
+        assert_eq!(id.checked_parity(), Some(ParityKind::Odd));
+        assert!(Uuid::from_u128(0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c8).is_even());
+    }
+}
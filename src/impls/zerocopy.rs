@@ -0,0 +1,97 @@
+use zerocopy::byteorder::{
+    ByteOrder, Isize, Order, Usize, F32, F64, I128, I16, I32, I64, U128, U16, U32, U64,
+};
+
+use crate::{Parity, ParityKind};
+
+macro_rules! impl_zerocopy_integer_parity {
+    ($($T:ident),*) => { $(
+        /// Reads only the least significant byte, wherever the byte order puts it, without converting the whole
+        /// value to native byte order.
+        impl<O: ByteOrder> Parity for $T<O> {
+            #[inline]
+            fn is_even(&self) -> bool {
+                !self.is_odd()
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                let bytes = self.to_bytes();
+                let low = match O::ORDER {
+                    Order::BigEndian => bytes[bytes.len() - 1],
+                    Order::LittleEndian => bytes[0],
+                };
+                low & 1 == 1
+            }
+        }
+    )* };
+}
+
+impl_zerocopy_integer_parity![U16, U32, U64, U128, Usize, I16, I32, I64, I128, Isize];
+
+macro_rules! impl_zerocopy_float_parity {
+    ($($T:ident),*) => { $(
+        /// Has the parity of the native float, so only finite values without a fractional part are even or odd.
+        impl<O: ByteOrder> Parity for $T<O> {
+            #[inline]
+            fn is_even(&self) -> bool {
+                self.get().is_even()
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                self.get().is_odd()
+            }
+
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                self.get().is_parity_defined()
+            }
+
+            #[inline]
+            fn checked_parity(&self) -> Option<ParityKind> {
+                self.get().checked_parity()
+            }
+        }
+    )* };
+}
+
+impl_zerocopy_float_parity![F32, F64];
+
+#[cfg(test)]
+mod tests {
+    use zerocopy::byteorder::{BigEndian, LittleEndian, NetworkEndian, F64, I32, U128, U16, U64};
+
+    use crate::{Parity, ParityKind};
+
+    #[test]
+    fn integers_in_both_orders() {
+        for value in [0u16, 1, 2, 0x0100, 0x01ff, u16::MAX] {
+            assert_eq!(U16::<BigEndian>::new(value).is_odd(), value.is_odd());
+            assert_eq!(U16::<LittleEndian>::new(value).is_even(), value.is_even());
+        }
+        assert!(I32::<NetworkEndian>::new(-3).is_odd());
+        assert!(U64::<LittleEndian>::new(1 << 40).is_even());
+        assert_eq!(
+            U128::<BigEndian>::new(u128::MAX).checked_parity(),
+            Some(ParityKind::Odd)
+        );
+    }
+
+    #[test]
+    fn wire_bytes() {
+        // a big-endian field read straight from a packet
+        let field = U16::<NetworkEndian>::from_bytes([0x12, 0x35]);
+        assert!(field.is_odd());
+        let field = U16::<LittleEndian>::from_bytes([0x12, 0x35]);
+        assert!(field.is_even());
+    }
+
+    #[test]
+    fn floats() {
+        assert!(F64::<BigEndian>::new(4.0).is_even());
+        assert!(F64::<LittleEndian>::new(-3.0).is_odd());
+        assert!(!F64::<BigEndian>::new(0.5).is_parity_defined());
+        assert_eq!(F64::<LittleEndian>::new(f64::NAN).checked_parity(), None);
+    }
+}
@@ -0,0 +1,191 @@
+//! Fields of interlaced frames: the even and the odd scanlines.
+//!
+//! A frame is a buffer of `height` rows of `stride` elements each, and a field is the rows of one parity stored
+//! contiguously. Row 0 belongs to the even field, which has one row more when the height is odd.
+//!
+//! # Example
+//! ```
+//! use parity::interlace::{extract_field, merge_fields};
+//! use parity::ParityKind;
+//!
+//! let frame = [0, 0, 1, 1, 2, 2, 3, 3, 4, 4];
+//! let (mut even, mut odd) = ([0; 6], [0; 4]);
+//! extract_field(&frame, 2, 5, ParityKind::Even, &mut even);
+//! extract_field(&frame, 2, 5, ParityKind::Odd, &mut odd);
+//! assert_eq!((even, odd), ([0, 0, 2, 2, 4, 4], [1, 1, 3, 3]));
+//!
+//! let mut woven = [0; 10];
+//! merge_fields(&even, &odd, 2, 5, &mut woven);
+//! assert_eq!(woven, frame);
+//! ```
+
+use core::iter::{Skip, StepBy, Take};
+use core::slice::{Chunks, ChunksMut};
+
+use crate::ParityKind;
+
+/// Returns the number of rows in the `field` of a frame of `height` rows.
+#[inline]
+pub const fn field_height(height: usize, field: ParityKind) -> usize {
+    match field {
+        ParityKind::Even => height.div_ceil(2),
+        ParityKind::Odd => height / 2,
+    }
+}
+
+#[track_caller]
+fn check_frame(len: usize, stride: usize, height: usize) {
+    assert!(stride > 0, "frame stride must be nonzero");
+    assert!(
+        stride.checked_mul(height).is_some_and(|size| size <= len),
+        "a frame of {height} rows of {stride} elements does not fit in a buffer of {len}"
+    );
+}
+
+/// Returns an iterator over the rows of `frame` in the given `field`.
+///
+/// # Panics
+/// Panics if `stride` is zero or `frame` is shorter than `stride * height`.
+#[inline]
+#[track_caller]
+pub fn field_rows<T>(
+    frame: &[T],
+    stride: usize,
+    height: usize,
+    field: ParityKind,
+) -> StepBy<Skip<Take<Chunks<'_, T>>>> {
+    check_frame(frame.len(), stride, height);
+    frame
+        .chunks(stride)
+        .take(height)
+        .skip(field.is_odd() as usize)
+        .step_by(2)
+}
+
+/// Returns an iterator over mutable references to the rows of `frame` in the given `field`.
+///
+/// # Panics
+/// Panics if `stride` is zero or `frame` is shorter than `stride * height`.
+#[inline]
+#[track_caller]
+pub fn field_rows_mut<T>(
+    frame: &mut [T],
+    stride: usize,
+    height: usize,
+    field: ParityKind,
+) -> StepBy<Skip<Take<ChunksMut<'_, T>>>> {
+    check_frame(frame.len(), stride, height);
+    frame
+        .chunks_mut(stride)
+        .take(height)
+        .skip(field.is_odd() as usize)
+        .step_by(2)
+}
+
+/// Copies the rows of `frame` in the given `field` into the contiguous buffer `out`.
+///
+/// # Panics
+/// Panics if `stride` is zero, `frame` is shorter than `stride * height`, or `out` does not hold exactly the
+/// [field_height] rows of the field.
+#[track_caller]
+pub fn extract_field<T: Copy>(
+    frame: &[T],
+    stride: usize,
+    height: usize,
+    field: ParityKind,
+    out: &mut [T],
+) {
+    check_field(out.len(), stride, height, field);
+    for (src, dst) in field_rows(frame, stride, height, field).zip(out.chunks_mut(stride)) {
+        dst.copy_from_slice(src);
+    }
+}
+
+/// Copies the contiguous field `rows` into the rows of `frame` in the given `field`, leaving the other field as
+/// it is.
+///
+/// # Panics
+/// Panics if `stride` is zero, `frame` is shorter than `stride * height`, or `rows` does not hold exactly the
+/// [field_height] rows of the field.
+#[track_caller]
+pub fn insert_field<T: Copy>(
+    rows: &[T],
+    stride: usize,
+    height: usize,
+    field: ParityKind,
+    frame: &mut [T],
+) {
+    check_field(rows.len(), stride, height, field);
+    for (dst, src) in field_rows_mut(frame, stride, height, field).zip(rows.chunks(stride)) {
+        dst.copy_from_slice(src);
+    }
+}
+
+/// Weaves the contiguous fields `even` and `odd` into `frame`.
+///
+/// # Panics
+/// Panics under the same conditions as [insert_field] for either field.
+#[track_caller]
+pub fn merge_fields<T: Copy>(even: &[T], odd: &[T], stride: usize, height: usize, frame: &mut [T]) {
+    insert_field(even, stride, height, ParityKind::Even, frame);
+    insert_field(odd, stride, height, ParityKind::Odd, frame);
+}
+
+#[track_caller]
+fn check_field(len: usize, stride: usize, height: usize, field: ParityKind) {
+    let rows = field_height(height, field);
+    assert!(
+        stride.checked_mul(rows) == Some(len),
+        "the {field} field of {rows} rows of {stride} elements does not match a buffer of {len}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows() {
+        // a 3-row frame with one element of padding per row past a width of 2
+        let frame = [1, 1, 0, 2, 2, 0, 3, 3, 0, 9];
+        let even: [&[u8]; 2] = [&[1, 1, 0], &[3, 3, 0]];
+        assert!(field_rows(&frame, 3, 3, ParityKind::Even).eq(even));
+        assert!(field_rows(&frame, 3, 3, ParityKind::Odd).eq([&[2u8, 2, 0][..]]));
+        assert_eq!(field_rows(&frame, 3, 0, ParityKind::Even).count(), 0);
+        assert_eq!(
+            (
+                field_height(3, ParityKind::Even),
+                field_height(3, ParityKind::Odd)
+            ),
+            (2, 1)
+        );
+
+        let mut frame = frame;
+        for row in field_rows_mut(&mut frame, 3, 3, ParityKind::Odd) {
+            row.fill(7);
+        }
+        assert_eq!(frame, [1, 1, 0, 7, 7, 7, 3, 3, 0, 9]);
+    }
+
+    #[test]
+    fn insert_keeps_other_field() {
+        let mut frame = [0u16; 8];
+        insert_field(&[5, 5, 6, 6], 2, 4, ParityKind::Odd, &mut frame);
+        assert_eq!(frame, [0, 0, 5, 5, 0, 0, 6, 6]);
+        let mut odd = [0; 4];
+        extract_field(&frame, 2, 4, ParityKind::Odd, &mut odd);
+        assert_eq!(odd, [5, 5, 6, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit")]
+    fn short_frame() {
+        let _ = field_rows(&[0u8; 5], 2, 3, ParityKind::Even);
+    }
+
+    #[test]
+    #[should_panic(expected = "the odd field of 1 rows")]
+    fn wrong_field_size() {
+        extract_field(&[0u8; 6], 2, 3, ParityKind::Odd, &mut [0; 4]);
+    }
+}
@@ -0,0 +1,140 @@
+//! A container for long-lived state that must keep a parity chosen at run time.
+
+use core::fmt;
+
+use crate::{Parity, ParityError, ParityKind};
+
+/// A value that always has the parity the container was created with.
+///
+/// Every method that stores a new value checks its parity first and rejects a value of the wrong parity, or
+/// without a parity, with a [ParityError] carrying it, leaving the stored value unchanged. Where the parity is known
+/// at compile time, [Even](crate::Even) and [Odd](crate::Odd) check it in their types instead.
+///
+/// # Example
+/// ```
+/// use parity::invariant::ParityInvariant;
+/// use parity::ParityKind;
+///
+/// // a byte cursor into a buffer of 16-bit code units
+/// let mut cursor = ParityInvariant::new(0usize, ParityKind::Even)?;
+/// cursor.update(|&at| at + 2)?;
+/// assert_eq!(*cursor.get(), 2);
+///
+/// let err = cursor.set(5).unwrap_err();
+/// assert_eq!(err.into_value(), 5);
+/// assert_eq!(*cursor.get(), 2);
+/// # Ok::<(), parity::ParityError<usize>>(())
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityInvariant<T> {
+    value: T,
+    parity: ParityKind,
+}
+
+#[inline]
+fn check<T: Parity>(value: T, parity: ParityKind) -> Result<T, ParityError<T>> {
+    match parity {
+        ParityKind::Even => value.ensure_even(),
+        ParityKind::Odd => value.ensure_odd(),
+    }
+}
+
+impl<T: Parity> ParityInvariant<T> {
+    /// Returns a container holding `value`, which must keep the parity `parity`.
+    ///
+    /// # Errors
+    /// Fails if `value` does not have the parity `parity`.
+    #[inline]
+    pub fn new(value: T, parity: ParityKind) -> Result<Self, ParityError<T>> {
+        check(value, parity).map(|value| ParityInvariant { value, parity })
+    }
+
+    /// Returns a reference to the value.
+    #[inline]
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the parity every value of this container has.
+    #[inline]
+    pub fn parity(&self) -> ParityKind {
+        self.parity
+    }
+
+    /// Stores `value`, dropping the previous one.
+    ///
+    /// # Errors
+    /// Fails if `value` does not have the parity of the container.
+    #[inline]
+    pub fn set(&mut self, value: T) -> Result<(), ParityError<T>> {
+        self.replace(value).map(drop)
+    }
+
+    /// Stores `value` and returns the previous one.
+    ///
+    /// # Errors
+    /// Fails if `value` does not have the parity of the container.
+    #[inline]
+    pub fn replace(&mut self, value: T) -> Result<T, ParityError<T>> {
+        let value = check(value, self.parity)?;
+        Ok(core::mem::replace(&mut self.value, value))
+    }
+
+    /// Stores the result of `f` applied to the current value and returns a reference to it.
+    ///
+    /// # Errors
+    /// Fails if the result does not have the parity of the container.
+    #[inline]
+    pub fn update<F: FnOnce(&T) -> T>(&mut self, f: F) -> Result<&T, ParityError<T>> {
+        self.set(f(&self.value))?;
+        Ok(&self.value)
+    }
+
+    /// Returns the value, consuming the container.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ParityInvariant<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ParityInvariant")
+            .field(&self.value)
+            .field(&self.parity)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_wrong_parity() {
+        assert_eq!(
+            ParityInvariant::new(3u16, ParityKind::Even)
+                .unwrap_err()
+                .expected(),
+            ParityKind::Even
+        );
+        let mut odd = ParityInvariant::new(-1i32, ParityKind::Odd).unwrap();
+        assert_eq!(odd.parity(), ParityKind::Odd);
+        assert_eq!(odd.replace(7), Ok(-1));
+        assert_eq!(odd.replace(8).unwrap_err().into_value(), 8);
+        assert_eq!(odd.update(|x| x * 3), Ok(&21));
+        assert!(odd.update(|x| x + 1).is_err());
+        assert_eq!(odd.into_inner(), 21);
+    }
+
+    #[test]
+    fn floats_without_parity() {
+        let mut even = ParityInvariant::new(2.0f64, ParityKind::Even).unwrap();
+        assert!(even.set(f64::NAN).is_err());
+        assert!(even.set(0.5).is_err());
+        assert_eq!(even.set(-0.0), Ok(()));
+        assert_eq!(std::format!("{even:?}"), "ParityInvariant(-0.0, Even)");
+        assert!(ParityInvariant::new(f64::INFINITY, ParityKind::Odd).is_err());
+    }
+}
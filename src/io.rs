@@ -0,0 +1,755 @@
+//! [Read] and [Write] adapters that frame bytes with a parity bit, emulating a 7E1/7O1 or 8E1/8O1 serial link.
+//!
+//! The parity bit is chosen so that each character has the configured [ParityKind] of set bits. With
+//! [DataBits::Seven], each byte on the wire carries seven data bits in its low bits and the parity bit in bit 7.
+//! With [DataBits::Eight], the nine bits of a character do not fit in a byte, so each one is sent as a little-endian
+//! `u16` holding the data byte in its low bits and the parity bit in bit 8.
+//!
+//! [EvenFrameReader] instead splits a stream into records and checks that each one is a whole number of 16-bit
+//! words.
+
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::format;
+use std::io::{self, Read, Write};
+use std::vec::Vec;
+
+use crate::{BitParity, ParityKind};
+
+const PARITY_BIT: u8 = 0x80;
+
+/// The number of data bits in each character of a framed stream.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DataBits {
+    /// Seven data bits and the parity bit in one byte, as in 7E1 and 7O1.
+    #[default]
+    Seven,
+    /// Eight data bits and the parity bit in two bytes, as in 8E1 and 8O1.
+    Eight,
+}
+
+#[inline]
+fn frame(data: u8, parity: ParityKind) -> u8 {
+    if data.bit_parity() == parity {
+        data
+    } else {
+        data | PARITY_BIT
+    }
+}
+
+#[inline]
+fn frame_is_valid(byte: u8, parity: ParityKind) -> bool {
+    byte.bit_parity() == parity
+}
+
+#[inline]
+fn wide_frame(data: u8, parity: ParityKind) -> [u8; 2] {
+    [data, u8::from(data.bit_parity() != parity)]
+}
+
+#[inline]
+fn wide_frame_is_valid(frame: u16, parity: ParityKind) -> bool {
+    frame >> 9 == 0 && frame.bit_parity() == parity
+}
+
+/// The error carried by an [io::Error] when a framed byte fails its parity check.
+///
+/// Retrieve it with [io::Error::get_ref] and [Error::downcast_ref].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParityViolation {
+    position: u64,
+    frame: u16,
+}
+
+impl ParityViolation {
+    /// Returns the zero-based offset of the offending character in the stream, that of its first byte for
+    /// [DataBits::Eight].
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the low byte of the offending character as it was read, which includes the parity bit for
+    /// [DataBits::Seven].
+    pub fn byte(&self) -> u8 {
+        self.frame as u8
+    }
+
+    /// Returns the offending character as it was read, including its parity bit.
+    pub fn frame(&self) -> u16 {
+        self.frame
+    }
+}
+
+impl fmt::Display for ParityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parity error at byte {} (0x{:02x})",
+            self.position, self.frame
+        )
+    }
+}
+
+impl Error for ParityViolation {}
+
+/// A writer that adds a parity bit to every byte before passing it on.
+///
+/// With [DataBits::Seven], only 7-bit data can be framed, and writing a byte with bit 7 set fails with
+/// [io::ErrorKind::InvalidInput]. With [DataBits::Eight], every byte can be framed, and each one takes two bytes on
+/// the wire.
+///
+/// # Example
+/// ```
+/// use std::io::Write;
+/// use parity::io::{DataBits, ParityEncodeWriter};
+/// use parity::ParityKind;
+///
+/// let mut writer = ParityEncodeWriter::new(Vec::new(), ParityKind::Even);
+/// writer.write_all(b"AC").unwrap();
+/// assert_eq!(writer.into_inner(), [0x41, 0xc3]);
+///
+/// // 8O1: 0xc3 has four set bits, so its parity bit is set
+/// let mut writer = ParityEncodeWriter::with_data_bits(Vec::new(), ParityKind::Odd, DataBits::Eight);
+/// writer.write_all(&[0xc3, 0x01]).unwrap();
+/// assert_eq!(writer.into_inner(), [0xc3, 1, 0x01, 0]);
+/// ```
+#[derive(Debug)]
+pub struct ParityEncodeWriter<W> {
+    inner: W,
+    parity: ParityKind,
+    data_bits: DataBits,
+    position: u64,
+}
+
+impl<W: Write> ParityEncodeWriter<W> {
+    /// Wraps `inner`, framing every byte as seven data bits with the given parity.
+    pub fn new(inner: W, parity: ParityKind) -> Self {
+        Self::with_data_bits(inner, parity, DataBits::Seven)
+    }
+
+    /// Wraps `inner`, framing every byte as `data_bits` data bits with the given parity.
+    pub fn with_data_bits(inner: W, parity: ParityKind, data_bits: DataBits) -> Self {
+        ParityEncodeWriter {
+            inner,
+            parity,
+            data_bits,
+            position: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps this adapter, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> Write for ParityEncodeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.data_bits == DataBits::Eight {
+            let mut framed = [0u8; 512];
+            let len = buf.len().min(framed.len() / 2);
+            for (pair, &byte) in framed.chunks_exact_mut(2).zip(&buf[..len]) {
+                pair.copy_from_slice(&wide_frame(byte, self.parity));
+            }
+            self.inner.write_all(&framed[..2 * len])?;
+            self.position += 2 * len as u64;
+            return Ok(len);
+        }
+        let mut framed = [0u8; 256];
+        let mut len = 0;
+        for &byte in buf.iter().take(framed.len()) {
+            if byte & PARITY_BIT != 0 {
+                break;
+            }
+            framed[len] = frame(byte, self.parity);
+            len += 1;
+        }
+        if len == 0 && !buf.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "byte 0x{:02x} at position {} does not fit in 7 data bits",
+                    buf[0], self.position
+                ),
+            ));
+        }
+        self.inner.write_all(&framed[..len])?;
+        self.position += len as u64;
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A reader that verifies and strips the parity bit of every character it reads.
+///
+/// A character with the wrong parity fails the read with [io::ErrorKind::InvalidData] carrying a [ParityViolation].
+/// Bytes before the offending character are still returned by the read that encountered it, and reading after the
+/// error resumes with the following character. With [DataBits::Eight], a stream that ends in the middle of a
+/// character fails with [io::ErrorKind::UnexpectedEof].
+///
+/// # Example
+/// ```
+/// use std::io::Read;
+/// use parity::io::ParityCheckReader;
+/// use parity::ParityKind;
+///
+/// let mut reader = ParityCheckReader::new(&[0x41, 0xc3][..], ParityKind::Even);
+/// let mut data = Vec::new();
+/// reader.read_to_end(&mut data).unwrap();
+/// assert_eq!(data, b"AC");
+/// ```
+#[derive(Debug)]
+pub struct ParityCheckReader<R> {
+    inner: R,
+    parity: ParityKind,
+    data_bits: DataBits,
+    position: u64,
+    pending: Option<ParityViolation>,
+    unchecked: VecDeque<u8>,
+}
+
+impl<R: Read> ParityCheckReader<R> {
+    /// Wraps `inner`, expecting every byte to carry seven data bits with the given parity.
+    pub fn new(inner: R, parity: ParityKind) -> Self {
+        Self::with_data_bits(inner, parity, DataBits::Seven)
+    }
+
+    /// Wraps `inner`, expecting every character to carry `data_bits` data bits with the given parity.
+    pub fn with_data_bits(inner: R, parity: ParityKind, data_bits: DataBits) -> Self {
+        ParityCheckReader {
+            inner,
+            parity,
+            data_bits,
+            position: 0,
+            pending: None,
+            unchecked: VecDeque::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this adapter, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Reads two-byte [DataBits::Eight] characters into `buf`.
+    fn read_wide(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut wire = [0u8; 512];
+        let want = 2 * buf.len().min(wire.len() / 2);
+        if want == 0 {
+            return Ok(0);
+        }
+        let mut len = 0;
+        while len < want {
+            match self.unchecked.pop_front() {
+                Some(byte) => wire[len] = byte,
+                None => break,
+            }
+            len += 1;
+        }
+        // a character needs two bytes, which may take several reads of `inner`
+        while len < 2 {
+            match self.inner.read(&mut wire[len..want]) {
+                Ok(0) => break,
+                Ok(read) => len += read,
+                Err(err) => {
+                    self.unchecked.extend(&wire[..len]);
+                    return Err(err);
+                }
+            }
+        }
+        if len == 1 {
+            let position = self.position;
+            self.position += 1;
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("stream ends in the middle of the character at byte {position}"),
+            ));
+        }
+        if len % 2 == 1 {
+            len -= 1;
+            self.unchecked.push_front(wire[len]);
+        }
+        for (i, pair) in wire[..len].chunks_exact(2).enumerate() {
+            let frame = u16::from_le_bytes([pair[0], pair[1]]);
+            if !wide_frame_is_valid(frame, self.parity) {
+                let violation = ParityViolation {
+                    position: self.position + 2 * i as u64,
+                    frame,
+                };
+                self.position += 2 * i as u64 + 2;
+                for &rest in wire[2 * i + 2..len].iter().rev() {
+                    self.unchecked.push_front(rest);
+                }
+                if i == 0 {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, violation));
+                }
+                self.pending = Some(violation);
+                return Ok(i);
+            }
+            buf[i] = pair[0];
+        }
+        self.position += len as u64;
+        Ok(len / 2)
+    }
+}
+
+impl<R: Read> Read for ParityCheckReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if let Some(violation) = self.pending.take() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, violation));
+        }
+        if self.data_bits == DataBits::Eight {
+            return self.read_wide(buf);
+        }
+        let read = if self.unchecked.is_empty() {
+            self.inner.read(buf)?
+        } else {
+            let len = buf.len().min(self.unchecked.len());
+            for (slot, byte) in buf.iter_mut().zip(self.unchecked.drain(..len)) {
+                *slot = byte;
+            }
+            len
+        };
+        for i in 0..read {
+            let byte = buf[i];
+            if !frame_is_valid(byte, self.parity) {
+                let violation = ParityViolation {
+                    position: self.position + i as u64,
+                    frame: byte.into(),
+                };
+                self.position += i as u64 + 1;
+                // bytes after the faulty one have been read from `inner` already, keep them for the next call
+                for &rest in buf[i + 1..read].iter().rev() {
+                    self.unchecked.push_front(rest);
+                }
+                if i == 0 {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, violation));
+                }
+                self.pending = Some(violation);
+                return Ok(i);
+            }
+            buf[i] = byte & !PARITY_BIT;
+        }
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+/// How an [EvenFrameReader] finds the end of each frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FrameFormat {
+    /// Every frame has this many bytes, except that the last one may be shorter when the stream ends.
+    Fixed(usize),
+    /// Every frame starts with its length in bytes as a `u8`.
+    LengthPrefixedU8,
+    /// Every frame starts with its length in bytes as a big-endian `u16`.
+    LengthPrefixedU16Be,
+    /// Every frame starts with its length in bytes as a little-endian `u16`.
+    LengthPrefixedU16Le,
+    /// Every frame starts with its length in bytes as a big-endian `u32`.
+    LengthPrefixedU32Be,
+    /// Every frame starts with its length in bytes as a little-endian `u32`.
+    LengthPrefixedU32Le,
+}
+
+/// The error carried by an [io::Error] when a frame read by an [EvenFrameReader] has an odd number of bytes.
+///
+/// Retrieve it with [io::Error::get_ref] and [Error::downcast_ref].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OddFrameLength {
+    frame: u64,
+    byte_count: u64,
+}
+
+impl OddFrameLength {
+    /// Returns the zero-based index of the offending frame in the stream.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Returns the number of bytes in the offending frame, not counting its length prefix.
+    pub fn byte_count(&self) -> u64 {
+        self.byte_count
+    }
+}
+
+impl fmt::Display for OddFrameLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame {} has an odd length of {} bytes",
+            self.frame, self.byte_count
+        )
+    }
+}
+
+impl Error for OddFrameLength {}
+
+/// A reader that splits a stream into frames and rejects any frame with an odd number of bytes, for protocols whose
+/// records are sequences of 16-bit words.
+///
+/// A frame with an odd length fails [read_frame](EvenFrameReader::read_frame) with [io::ErrorKind::InvalidData]
+/// carrying an [OddFrameLength]. The frame has been consumed by then, so reading after the error resumes with the
+/// next frame.
+///
+/// # Example
+/// ```
+/// use parity::io::{EvenFrameReader, FrameFormat};
+///
+/// let stream = [2, 0x12, 0x34, 3, 0xab, 0xcd, 0xef, 0, 2, 0x56, 0x78];
+/// let mut reader = EvenFrameReader::new(&stream[..], FrameFormat::LengthPrefixedU8);
+/// assert_eq!(reader.read_frame()?, Some(vec![0x12, 0x34]));
+/// assert!(reader.read_frame().is_err());
+/// assert_eq!(reader.read_frame()?, Some(vec![]));
+/// assert_eq!(reader.read_frame()?, Some(vec![0x56, 0x78]));
+/// assert_eq!(reader.read_frame()?, None);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct EvenFrameReader<R> {
+    inner: R,
+    format: FrameFormat,
+    frame: u64,
+}
+
+impl<R: Read> EvenFrameReader<R> {
+    /// Wraps `inner`, reading frames in the given format.
+    ///
+    /// # Panics
+    /// Panics if the format has a fixed frame size that is odd, since every full frame would then be rejected, or
+    /// zero.
+    #[track_caller]
+    pub fn new(inner: R, format: FrameFormat) -> Self {
+        if let FrameFormat::Fixed(size) = format {
+            assert!(
+                size != 0 && size.is_multiple_of(2),
+                "the fixed frame size {size} is not a positive even number"
+            );
+        }
+        EvenFrameReader {
+            inner,
+            format,
+            frame: 0,
+        }
+    }
+
+    /// Reads the next frame, returning `None` if the stream ends before it.
+    ///
+    /// # Errors
+    /// Fails with [io::ErrorKind::InvalidData] if the frame has an odd number of bytes, with
+    /// [io::ErrorKind::UnexpectedEof] if the stream ends in a length prefix or before the length it announced, and
+    /// with any error of the underlying reader.
+    pub fn read_frame(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let len = match self.format {
+            FrameFormat::Fixed(size) => size as u64,
+            FrameFormat::LengthPrefixedU8 => match self.read_prefix::<1>()? {
+                Some([len]) => len.into(),
+                None => return Ok(None),
+            },
+            FrameFormat::LengthPrefixedU16Be => match self.read_prefix()? {
+                Some(len) => u16::from_be_bytes(len).into(),
+                None => return Ok(None),
+            },
+            FrameFormat::LengthPrefixedU16Le => match self.read_prefix()? {
+                Some(len) => u16::from_le_bytes(len).into(),
+                None => return Ok(None),
+            },
+            FrameFormat::LengthPrefixedU32Be => match self.read_prefix()? {
+                Some(len) => u32::from_be_bytes(len).into(),
+                None => return Ok(None),
+            },
+            FrameFormat::LengthPrefixedU32Le => match self.read_prefix()? {
+                Some(len) => u32::from_le_bytes(len).into(),
+                None => return Ok(None),
+            },
+        };
+        // read through `take` instead of allocating `len` bytes up front, so a corrupt prefix cannot exhaust memory
+        let mut frame = Vec::new();
+        let read = (&mut self.inner).take(len).read_to_end(&mut frame)? as u64;
+        if read < len {
+            if let FrameFormat::Fixed(_) = self.format {
+                if read == 0 {
+                    return Ok(None);
+                }
+            } else {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    format!("frame {} ends after {read} of {len} bytes", self.frame),
+                ));
+            }
+        }
+        let index = self.frame;
+        self.frame += 1;
+        if read % 2 == 1 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                OddFrameLength {
+                    frame: index,
+                    byte_count: read,
+                },
+            ));
+        }
+        Ok(Some(frame))
+    }
+
+    /// Reads a length prefix, or returns `None` if the stream ends before its first byte.
+    fn read_prefix<const N: usize>(&mut self) -> io::Result<Option<[u8; N]>> {
+        let mut prefix = [0; N];
+        let mut filled = 0;
+        while filled < N {
+            match self.inner.read(&mut prefix[filled..]) {
+                Ok(0) if filled == 0 => return Ok(None),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        format!("the length prefix of frame {} is truncated", self.frame),
+                    ))
+                }
+                Ok(read) => filled += read,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(Some(prefix))
+    }
+
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps this adapter, returning the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for parity in [ParityKind::Even, ParityKind::Odd] {
+            let data: Vec<u8> = (0..0x80).collect();
+            let mut writer = ParityEncodeWriter::new(Vec::new(), parity);
+            writer.write_all(&data).unwrap();
+            let framed = writer.into_inner();
+            assert!(framed.iter().all(|&b| frame_is_valid(b, parity)));
+
+            let mut decoded = Vec::new();
+            ParityCheckReader::new(&framed[..], parity)
+                .read_to_end(&mut decoded)
+                .unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    #[test]
+    fn rejects_eight_bit_data() {
+        let mut writer = ParityEncodeWriter::new(Vec::new(), ParityKind::Odd);
+        let err = writer.write_all(&[0x01, 0x80]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(writer.get_ref().len(), 1);
+    }
+
+    #[test]
+    fn reports_violation_position() {
+        let framed = [0x41, 0xc3, 0x43, 0x41];
+        let mut reader = ParityCheckReader::new(&framed[..], ParityKind::Even);
+        let mut buf = [0u8; 4];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"AC");
+
+        let err = reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let violation = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ParityViolation>())
+            .unwrap();
+        assert_eq!(violation.position(), 2);
+        assert_eq!(violation.byte(), 0x43);
+
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf[0], b'A');
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    fn odd_frame(err: &io::Error) -> OddFrameLength {
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        *err.get_ref()
+            .and_then(|e| e.downcast_ref::<OddFrameLength>())
+            .unwrap()
+    }
+
+    #[test]
+    fn fixed_frames() {
+        let stream: Vec<u8> = (0..11).collect();
+        let mut reader = EvenFrameReader::new(&stream[..], FrameFormat::Fixed(4));
+        assert_eq!(reader.read_frame().unwrap(), Some(std::vec![0, 1, 2, 3]));
+        assert_eq!(reader.read_frame().unwrap(), Some(std::vec![4, 5, 6, 7]));
+        let err = reader.read_frame().unwrap_err();
+        assert_eq!(
+            odd_frame(&err),
+            OddFrameLength {
+                frame: 2,
+                byte_count: 3
+            }
+        );
+        assert_eq!(
+            std::format!("{err}"),
+            "frame 2 has an odd length of 3 bytes"
+        );
+        assert_eq!(reader.read_frame().unwrap(), None);
+
+        let mut reader = EvenFrameReader::new(&stream[..10], FrameFormat::Fixed(4));
+        assert_eq!(reader.read_frame().unwrap().map(|f| f.len()), Some(4));
+        assert_eq!(reader.read_frame().unwrap().map(|f| f.len()), Some(4));
+        assert_eq!(reader.read_frame().unwrap(), Some(std::vec![8, 9]));
+        assert_eq!(reader.read_frame().unwrap(), None);
+    }
+
+    #[test]
+    fn length_prefixed_frames() {
+        let stream = [0, 2, 0xaa, 0xbb, 0, 1, 0xcc, 0, 4, 1, 2, 3, 4];
+        let mut reader = EvenFrameReader::new(&stream[..], FrameFormat::LengthPrefixedU16Be);
+        assert_eq!(reader.read_frame().unwrap(), Some(std::vec![0xaa, 0xbb]));
+        let err = reader.read_frame().unwrap_err();
+        assert_eq!(odd_frame(&err).frame(), 1);
+        assert_eq!(odd_frame(&err).byte_count(), 1);
+        assert_eq!(reader.read_frame().unwrap(), Some(std::vec![1, 2, 3, 4]));
+        assert_eq!(reader.read_frame().unwrap(), None);
+
+        let stream = [2, 0, 0, 0, 7, 8];
+        let mut reader = EvenFrameReader::new(&stream[..], FrameFormat::LengthPrefixedU32Le);
+        assert_eq!(reader.read_frame().unwrap(), Some(std::vec![7, 8]));
+        let mut reader = EvenFrameReader::new(&stream[..], FrameFormat::LengthPrefixedU32Be);
+        let err = reader.read_frame().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+
+        let mut reader = EvenFrameReader::new(&[4, 0][..], FrameFormat::LengthPrefixedU16Le);
+        assert_eq!(
+            reader.read_frame().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+        let mut reader = EvenFrameReader::new(&[0][..], FrameFormat::LengthPrefixedU16Le);
+        assert_eq!(
+            reader.read_frame().unwrap_err().kind(),
+            io::ErrorKind::UnexpectedEof
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the fixed frame size 3 is not a positive even number")]
+    fn rejects_odd_fixed_size() {
+        EvenFrameReader::new(&[][..], FrameFormat::Fixed(3));
+    }
+
+    #[test]
+    fn eight_bit_round_trip() {
+        for parity in [ParityKind::Even, ParityKind::Odd] {
+            let data: Vec<u8> = (0..=255).chain(0..=255).collect();
+            let mut writer =
+                ParityEncodeWriter::with_data_bits(Vec::new(), parity, DataBits::Eight);
+            writer.write_all(&data).unwrap();
+            let framed = writer.into_inner();
+            assert_eq!(framed.len(), 2 * data.len());
+            assert!(framed
+                .chunks(2)
+                .all(|pair| wide_frame_is_valid(u16::from_le_bytes([pair[0], pair[1]]), parity)));
+
+            let mut decoded = Vec::new();
+            ParityCheckReader::with_data_bits(&framed[..], parity, DataBits::Eight)
+                .read_to_end(&mut decoded)
+                .unwrap();
+            assert_eq!(decoded, data);
+        }
+    }
+
+    /// A reader returning one byte per read, so that characters are split between reads.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let len = buf.len().min(self.0.len()).min(1);
+            buf[..len].copy_from_slice(&self.0[..len]);
+            self.0 = &self.0[len..];
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn eight_bit_violations() {
+        // 8E1: 0x41 is even, 0x43 is odd, and a parity byte of 2 is never valid
+        let framed = [0x41, 0, 0x43, 1, 0x43, 0, 0x41, 2, 0xff, 0];
+        let mut reader =
+            ParityCheckReader::with_data_bits(&framed[..], ParityKind::Even, DataBits::Eight);
+        let mut buf = [0u8; 8];
+        assert_eq!(reader.read(&mut buf).unwrap(), 2);
+        assert_eq!(&buf[..2], b"AC");
+
+        let err = reader.read(&mut buf).unwrap_err();
+        let violation = *err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ParityViolation>())
+            .unwrap();
+        assert_eq!((violation.position(), violation.frame()), (4, 0x043));
+        assert_eq!(std::format!("{err}"), "parity error at byte 4 (0x43)");
+
+        let err = reader.read(&mut buf).unwrap_err();
+        let violation = err
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ParityViolation>())
+            .unwrap();
+        assert_eq!((violation.position(), violation.frame()), (6, 0x241));
+        assert_eq!(violation.byte(), 0x41);
+
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf[0], 0xff);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+
+    #[test]
+    fn eight_bit_split_reads() {
+        let framed = [0x41, 0, 0x43, 1, 0x07];
+        let mut reader =
+            ParityCheckReader::with_data_bits(Trickle(&framed), ParityKind::Even, DataBits::Eight);
+        let mut buf = [0u8; 4];
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(reader.read(&mut buf).unwrap(), 1);
+        assert_eq!(buf[0], 0x43);
+        let err = reader.read(&mut buf).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+}
@@ -0,0 +1,1823 @@
+//! Extension methods for iterators over values with a parity.
+
+use core::iter::{FusedIterator, Product, Sum};
+use core::ops::{Neg, RangeBounds};
+
+use crate::range::ParityRange;
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// The number of even and odd values in a collection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug))
+)]
+pub struct ParityCount {
+    /// The number of even values.
+    pub even: usize,
+    /// The number of odd values.
+    pub odd: usize,
+}
+
+impl ParityCount {
+    /// Returns the number of values of the given `parity`.
+    #[inline]
+    pub const fn get(&self, parity: ParityKind) -> usize {
+        match parity {
+            ParityKind::Even => self.even,
+            ParityKind::Odd => self.odd,
+        }
+    }
+
+    /// Returns the number of values counted.
+    #[inline]
+    pub const fn total(&self) -> usize {
+        self.even + self.odd
+    }
+
+    /// Counts one more value of the given `parity`.
+    #[inline]
+    pub fn record(&mut self, parity: ParityKind) {
+        match parity {
+            ParityKind::Even => self.even += 1,
+            ParityKind::Odd => self.odd += 1,
+        }
+    }
+}
+
+/// Statistics of the runs of consecutive values with the same parity, returned by
+/// [ParityIterator::parity_run_stats] and [ParitySlice::parity_run_stats](crate::slice::ParitySlice::parity_run_stats).
+///
+/// A value without a parity ends the current run and is not counted, so it also separates the values before and
+/// after it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityRunStats {
+    /// The number of runs.
+    pub runs: usize,
+    /// The length of the longest run, or zero if there are no runs.
+    pub longest_run: usize,
+    /// The parity of the first of the longest runs, or `None` if there are no runs.
+    pub longest_run_parity: Option<ParityKind>,
+    /// The number of adjacent pairs of values with the same parity.
+    pub same_parity_pairs: usize,
+}
+
+impl ParityRunStats {
+    /// Computes the statistics of a sequence of parities, with `None` for values without a parity.
+    pub(crate) fn of(parities: impl Iterator<Item = Option<ParityKind>>) -> Self {
+        let mut stats = ParityRunStats::default();
+        let mut previous = None;
+        let mut current = 0;
+        for parity in parities {
+            if let Some(parity) = parity {
+                if previous == Some(parity) {
+                    current += 1;
+                    stats.same_parity_pairs += 1;
+                } else {
+                    stats.runs += 1;
+                    current = 1;
+                }
+                if current > stats.longest_run {
+                    stats.longest_run = current;
+                    stats.longest_run_parity = Some(parity);
+                }
+            }
+            previous = parity;
+        }
+        stats
+    }
+}
+
+/// Extension methods for iterators, implemented for every [Iterator].
+pub trait ParityIterator: Iterator + Sized {
+    /// Returns an iterator over the items of the given `parity`. Items without a parity are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// let odd: Vec<_> = [1.0, 2.0, 2.5, 3.0].into_iter().with_parity(ParityKind::Odd).collect();
+    /// assert_eq!(odd, [1.0, 3.0]);
+    /// ```
+    #[inline]
+    fn with_parity(self, parity: ParityKind) -> WithParity<Self>
+    where
+        Self::Item: Parity,
+    {
+        WithParity { iter: self, parity }
+    }
+
+    /// Returns an iterator over the even items.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert!((1..10).evens().eq([2, 4, 6, 8]));
+    /// ```
+    #[inline]
+    fn evens(self) -> WithParity<Self>
+    where
+        Self::Item: Parity,
+    {
+        self.with_parity(ParityKind::Even)
+    }
+
+    /// Returns an iterator over the odd items.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert!((1..10).odds().eq([1, 3, 5, 7, 9]));
+    /// ```
+    #[inline]
+    fn odds(self) -> WithParity<Self>
+    where
+        Self::Item: Parity,
+    {
+        self.with_parity(ParityKind::Odd)
+    }
+
+    /// Returns the parity of the product of all items without multiplying anything.
+    ///
+    /// Stops consuming the iterator at the first even item, since the product is then even regardless of what
+    /// follows. The empty product is one, which is odd.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// assert_eq!([3u64, 5, u64::MAX].into_iter().product_parity(), ParityKind::Odd);
+    /// // terminates even though the iterator is infinite
+    /// assert_eq!((1u32..).product_parity(), ParityKind::Even);
+    /// ```
+    #[inline]
+    fn product_parity(self) -> ParityKind
+    where
+        Self::Item: IntegerParity,
+    {
+        crate::math::parity_of_product_iter(self)
+    }
+
+    /// Returns the parity of the sum of all items without adding anything, so sums of any length cannot overflow.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// assert_eq!([u64::MAX; 3].into_iter().sum_parity(), ParityKind::Odd);
+    /// assert_eq!((1u8..=4).sum_parity(), ParityKind::Even);
+    /// ```
+    #[inline]
+    fn sum_parity(self) -> ParityKind
+    where
+        Self::Item: IntegerParity,
+    {
+        self.fold(ParityKind::Even, |acc, item| acc + item.parity())
+    }
+
+    /// Returns the parity of the sum of all items, or `None` as soon as an item without a parity is found.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// assert_eq!([1.0, 2.0, 3.0].into_iter().checked_sum_parity(), Some(ParityKind::Even));
+    /// assert_eq!([1.0, 0.5, 0.5].into_iter().checked_sum_parity(), None);
+    /// ```
+    #[inline]
+    fn checked_sum_parity(mut self) -> Option<ParityKind>
+    where
+        Self::Item: Parity,
+    {
+        self.try_fold(ParityKind::Even, |acc, item| {
+            Some(acc + item.checked_parity()?)
+        })
+    }
+
+    /// Returns `true` if this iterator and `other` have the same length and their items have the same parities,
+    /// position by position, whatever their values. Two items without a parity compare equal.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert!([1, 4, 7].into_iter().eq_by_parity([-3i64, 0, 9]));
+    /// assert!(![1, 4].into_iter().eq_by_parity([1, 4, 5]));
+    /// assert!([0.5, 2.0].into_iter().eq_by_parity([f64::NAN, 6.0]));
+    /// ```
+    #[inline]
+    fn eq_by_parity<J>(self, other: J) -> bool
+    where
+        Self::Item: Parity,
+        J: IntoIterator,
+        J::Item: Parity,
+    {
+        self.map(|item| item.checked_parity())
+            .eq(other.into_iter().map(|item| item.checked_parity()))
+    }
+
+    /// Collects the parity of each item, or `None` for items without one, into a collection such as a `Vec`.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// let signature: Vec<_> = [2, 3, 5].into_iter().parity_signature();
+    /// assert_eq!(signature, [Some(ParityKind::Even), Some(ParityKind::Odd), Some(ParityKind::Odd)]);
+    /// ```
+    #[inline]
+    fn parity_signature<B>(self) -> B
+    where
+        Self::Item: Parity,
+        B: FromIterator<Option<ParityKind>>,
+    {
+        self.map(|item| item.checked_parity()).collect()
+    }
+
+    /// Counts the even and odd items. Items without a parity are not counted.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityCount, ParityIterator};
+    ///
+    /// let count = [1, 2, 3, 5].into_iter().count_parities();
+    /// assert_eq!(count, ParityCount { even: 1, odd: 3 });
+    /// assert_eq!([0.5, 2.0].into_iter().count_parities().total(), 1);
+    /// ```
+    #[inline]
+    fn count_parities(self) -> ParityCount
+    where
+        Self::Item: Parity,
+    {
+        self.fold(ParityCount::default(), |mut count, item| {
+            if let Some(parity) = item.checked_parity() {
+                count.record(parity);
+            }
+            count
+        })
+    }
+
+    /// Moves the even items to the front of `evens` and the odd items to the front of `odds`, in order, returning
+    /// how many of each were written. This partitions without allocating, so it works without `alloc`.
+    ///
+    /// Items without a parity, and items arriving after their buffer is full, are skipped, so the whole iterator is
+    /// consumed. A count equal to the length of its buffer means that items may have been skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityCount, ParityIterator};
+    ///
+    /// let (mut evens, mut odds) = ([0; 4], [0; 4]);
+    /// let count = (1..=7).partition_parity_into(&mut evens, &mut odds);
+    /// assert_eq!(count, ParityCount { even: 3, odd: 4 });
+    /// assert_eq!(&evens[..count.even], [2, 4, 6]);
+    /// assert_eq!(odds, [1, 3, 5, 7]);
+    /// ```
+    fn partition_parity_into(self, evens: &mut [Self::Item], odds: &mut [Self::Item]) -> ParityCount
+    where
+        Self::Item: Parity,
+    {
+        let mut count = ParityCount::default();
+        for item in self {
+            let Some(parity) = item.checked_parity() else {
+                continue;
+            };
+            let (buffer, written) = match parity {
+                ParityKind::Even => (&mut *evens, count.even),
+                ParityKind::Odd => (&mut *odds, count.odd),
+            };
+            if let Some(slot) = buffer.get_mut(written) {
+                *slot = item;
+                count.record(parity);
+            }
+        }
+        count
+    }
+
+    /// Appends the even items to `evens` and the odd items to `odds`, in order, returning how many of each were
+    /// appended.
+    ///
+    /// Like [partition_parity_into](ParityIterator::partition_parity_into), items without a parity and items
+    /// arriving after their vector is full are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use heapless::Vec;
+    /// use parity::ParityIterator;
+    ///
+    /// let (mut evens, mut odds) = (Vec::<u8, 8>::new(), Vec::<u8, 2>::new());
+    /// let count = (0..10).partition_parity_heapless(&mut evens, &mut odds);
+    /// assert_eq!((count.even, count.odd), (5, 2));
+    /// assert_eq!(evens, [0, 2, 4, 6, 8]);
+    /// assert_eq!(odds, [1, 3]);
+    /// ```
+    #[cfg(feature = "heapless")]
+    fn partition_parity_heapless<const E: usize, const O: usize>(
+        self,
+        evens: &mut heapless::Vec<Self::Item, E>,
+        odds: &mut heapless::Vec<Self::Item, O>,
+    ) -> ParityCount
+    where
+        Self::Item: Parity,
+    {
+        let mut count = ParityCount::default();
+        for item in self {
+            let pushed = match item.checked_parity() {
+                Some(ParityKind::Even) => evens.push(item).is_ok().then_some(ParityKind::Even),
+                Some(ParityKind::Odd) => odds.push(item).is_ok().then_some(ParityKind::Odd),
+                None => None,
+            };
+            if let Some(parity) = pushed {
+                count.record(parity);
+            }
+        }
+        count
+    }
+
+    /// Returns an iterator over every second item, starting with the first item for an [Even](ParityKind::Even)
+    /// `phase` and with the second for an [Odd](ParityKind::Odd) one.
+    ///
+    /// The items kept are those whose index has the parity `phase`, which downsamples a stream by a factor of two.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// assert!("abcde".chars().decimate(ParityKind::Even).eq(['a', 'c', 'e']));
+    /// assert!("abcde".chars().decimate(ParityKind::Odd).eq(['b', 'd']));
+    /// ```
+    #[inline]
+    fn decimate(self, phase: ParityKind) -> Decimate<Self> {
+        Decimate {
+            iter: self,
+            skip: phase.is_odd() as usize,
+        }
+    }
+
+    /// Returns an iterator that negates every second item, multiplying the item at index `i` by `(-1)^i`, or by
+    /// `(-1)^(i + 1)` if `start` is [Odd](ParityKind::Odd).
+    ///
+    /// An [Even](ParityKind::Even) `start` keeps the sign of the first item, which gives the terms of an
+    /// alternating series, and an odd one negates it.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// assert!((1..=4).alternate_signs(ParityKind::Even).eq([1, -2, 3, -4]));
+    /// assert!((1..=4).alternate_signs(ParityKind::Odd).eq([-1, 2, -3, 4]));
+    ///
+    /// // the Leibniz series for pi / 4
+    /// let quarter_pi: f64 = (0..1000).map(|k| 1.0 / f64::from(2 * k + 1)).alternate_signs(ParityKind::Even).sum();
+    /// assert!((quarter_pi - core::f64::consts::FRAC_PI_4).abs() < 1e-3);
+    /// ```
+    #[inline]
+    fn alternate_signs(self, start: ParityKind) -> AlternateSigns<Self>
+    where
+        Self::Item: Neg<Output = Self::Item>,
+    {
+        AlternateSigns {
+            iter: self,
+            negate: start.is_odd(),
+        }
+    }
+
+    /// Returns statistics of the runs of consecutive items with the same parity.
+    ///
+    /// For a sequence of independent fair coin flips, `n` items form about `(n + 1) / 2` runs and the longest run
+    /// is about `log2(n)` items long, so large deviations from these hint at a biased or correlated source.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::iter::ParityRunStats;
+    /// use parity::{ParityIterator, ParityKind};
+    ///
+    /// let stats = [1, 3, 5, 2, 4, 7].into_iter().parity_run_stats();
+    /// assert_eq!(
+    ///     stats,
+    ///     ParityRunStats {
+    ///         runs: 3,
+    ///         longest_run: 3,
+    ///         longest_run_parity: Some(ParityKind::Odd),
+    ///         same_parity_pairs: 3,
+    ///     }
+    /// );
+    /// ```
+    #[inline]
+    fn parity_run_stats(self) -> ParityRunStats
+    where
+        Self::Item: Parity,
+    {
+        ParityRunStats::of(self.map(|item| item.checked_parity()))
+    }
+
+    /// Returns an iterator over the indices of the items whose parity differs from that of the item before them,
+    /// marking the start of every run after the first.
+    ///
+    /// Items without a parity never differ from their neighbours, so no index next to them is yielded.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// let samples = [1, 3, 4, 6, 8, 5];
+    /// assert!(samples.into_iter().parity_transitions().eq([2, 5]));
+    /// ```
+    #[inline]
+    fn parity_transitions(self) -> ParityTransitions<Self>
+    where
+        Self::Item: Parity,
+    {
+        ParityTransitions {
+            iter: self,
+            index: 0,
+            previous: None,
+        }
+    }
+
+    /// Returns the sum of the even items. Items without a parity are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!((1..=10).sum_even::<i32>(), 30);
+    /// assert_eq!([2.0, 2.5, 3.0, 4.0].into_iter().sum_even::<f64>(), 6.0);
+    /// ```
+    #[inline]
+    fn sum_even<S: Sum<Self::Item>>(self) -> S
+    where
+        Self::Item: Parity,
+    {
+        self.evens().sum()
+    }
+
+    /// Returns the sum of the odd items. Items without a parity are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!((1..=10).sum_odd::<i32>(), 25);
+    /// ```
+    #[inline]
+    fn sum_odd<S: Sum<Self::Item>>(self) -> S
+    where
+        Self::Item: Parity,
+    {
+        self.odds().sum()
+    }
+
+    /// Returns the product of the odd items, which is odd. Items without a parity are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!((1..=10).product_odd::<u64>(), 945);
+    /// ```
+    #[inline]
+    fn product_odd<P: Product<Self::Item>>(self) -> P
+    where
+        Self::Item: Parity,
+    {
+        self.odds().product()
+    }
+
+    /// Returns the smallest even item, or `None` if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!([7, -4, 3, 10].into_iter().min_even(), Some(-4));
+    /// assert_eq!([1, 3].into_iter().min_even(), None);
+    /// ```
+    #[inline]
+    fn min_even(self) -> Option<Self::Item>
+    where
+        Self::Item: Parity + Ord,
+    {
+        self.evens().min()
+    }
+
+    /// Returns the largest even item, or `None` if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!([7, -4, 3, 10].into_iter().max_even(), Some(10));
+    /// ```
+    #[inline]
+    fn max_even(self) -> Option<Self::Item>
+    where
+        Self::Item: Parity + Ord,
+    {
+        self.evens().max()
+    }
+
+    /// Returns the smallest odd item, or `None` if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!([7, -4, 3, 10].into_iter().min_odd(), Some(3));
+    /// ```
+    #[inline]
+    fn min_odd(self) -> Option<Self::Item>
+    where
+        Self::Item: Parity + Ord,
+    {
+        self.odds().min()
+    }
+
+    /// Returns the largest odd item, or `None` if there is none.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!([7, -4, 3, 10].into_iter().max_odd(), Some(7));
+    /// assert_eq!([2u8, 4].into_iter().max_odd(), None);
+    /// ```
+    #[inline]
+    fn max_odd(self) -> Option<Self::Item>
+    where
+        Self::Item: Parity + Ord,
+    {
+        self.odds().max()
+    }
+
+    /// Returns the sum of the even items, or `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!([100u8, 101, 102].into_iter().checked_sum_even(), Some(202));
+    /// assert_eq!([100u8, 101, 102, 54].into_iter().checked_sum_even(), None);
+    /// ```
+    #[inline]
+    fn checked_sum_even(self) -> Option<Self::Item>
+    where
+        Self::Item: CheckedArithmetic,
+    {
+        self.evens()
+            .try_fold(Self::Item::ZERO, CheckedArithmetic::checked_add)
+    }
+
+    /// Returns the sum of the odd items, or `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!([-3i8, 4, -125].into_iter().checked_sum_odd(), Some(-128));
+    /// assert_eq!([-3i8, 4, -127].into_iter().checked_sum_odd(), None);
+    /// ```
+    #[inline]
+    fn checked_sum_odd(self) -> Option<Self::Item>
+    where
+        Self::Item: CheckedArithmetic,
+    {
+        self.odds()
+            .try_fold(Self::Item::ZERO, CheckedArithmetic::checked_add)
+    }
+
+    /// Returns the product of the odd items, or `None` if it overflows.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityIterator;
+    ///
+    /// assert_eq!((1u16..=11).checked_product_odd(), Some(10395));
+    /// assert_eq!((1u16..=13).checked_product_odd(), None);
+    /// ```
+    #[inline]
+    fn checked_product_odd(self) -> Option<Self::Item>
+    where
+        Self::Item: CheckedArithmetic,
+    {
+        self.odds()
+            .try_fold(Self::Item::ONE, CheckedArithmetic::checked_mul)
+    }
+}
+
+/// Integers with checked addition and multiplication, for the checked sums and products of [ParityIterator].
+///
+/// Implemented for all primitive integer types.
+pub trait CheckedArithmetic: IntegerParity + Sized {
+    /// The additive identity.
+    const ZERO: Self;
+    /// The multiplicative identity.
+    const ONE: Self;
+
+    /// Returns `self + rhs`, or `None` if it overflows.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Returns `self * rhs`, or `None` if it overflows.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arithmetic {
+    ($($T:ty),*) => { $(
+        impl CheckedArithmetic for $T {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$T>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$T>::checked_mul(self, rhs)
+            }
+        }
+    )* };
+}
+
+impl_checked_arithmetic![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+impl<I: Iterator> ParityIterator for I {}
+
+/// An iterator over the indices where the parity of the items of another iterator changes, created by
+/// [ParityIterator::parity_transitions].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParityTransitions<I> {
+    iter: I,
+    // index of the next item of `iter`
+    index: usize,
+    previous: Option<ParityKind>,
+}
+
+impl<I: Iterator> Iterator for ParityTransitions<I>
+where
+    I::Item: Parity,
+{
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        loop {
+            let parity = self.iter.next()?.checked_parity();
+            let index = self.index;
+            self.index += 1;
+            let previous = core::mem::replace(&mut self.previous, parity);
+            if let (Some(previous), Some(parity)) = (previous, parity) {
+                if previous != parity {
+                    return Some(index);
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I: FusedIterator> FusedIterator for ParityTransitions<I> where I::Item: Parity {}
+
+/// An iterator over the items of another iterator that have a given parity.
+///
+/// Created by [ParityIterator::with_parity], [ParityIterator::evens] and [ParityIterator::odds]. Any item of the
+/// underlying iterator may be skipped, so the lower bound of [Iterator::size_hint] is zero; to enumerate the values
+/// of one parity in a range with an exact length, use [ParityValues] instead.
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithParity<I> {
+    iter: I,
+    parity: ParityKind,
+}
+
+impl<I> WithParity<I> {
+    /// Returns the parity of the items this iterator yields.
+    #[inline]
+    pub fn parity(&self) -> ParityKind {
+        self.parity
+    }
+
+    /// Returns the underlying iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I> Iterator for WithParity<I>
+where
+    I: Iterator,
+    I::Item: Parity,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let parity = self.parity;
+        self.iter.find(|item| item.checked_parity() == Some(parity))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        let parity = self.parity;
+        self.iter
+            .filter(|item| item.checked_parity() == Some(parity))
+            .count()
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item) -> B,
+    {
+        let parity = self.parity;
+        self.iter.fold(init, |acc, item| {
+            if item.checked_parity() == Some(parity) {
+                f(acc, item)
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl<I> DoubleEndedIterator for WithParity<I>
+where
+    I: DoubleEndedIterator,
+    I::Item: Parity,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        let parity = self.parity;
+        self.iter
+            .rfind(|item| item.checked_parity() == Some(parity))
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, I::Item) -> B,
+    {
+        let parity = self.parity;
+        self.iter.rfold(init, |acc, item| {
+            if item.checked_parity() == Some(parity) {
+                f(acc, item)
+            } else {
+                acc
+            }
+        })
+    }
+}
+
+impl<I> FusedIterator for WithParity<I>
+where
+    I: FusedIterator,
+    I::Item: Parity,
+{
+}
+
+/// An iterator over every second item of another iterator.
+///
+/// Created by [ParityIterator::decimate].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Decimate<I> {
+    iter: I,
+    /// The number of items to drop before the next one kept, either zero or one.
+    skip: usize,
+}
+
+impl<I> Decimate<I> {
+    /// Returns the underlying iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+
+    /// Returns the number of items kept out of `remaining` items of the underlying iterator.
+    #[inline]
+    fn kept(&self, remaining: usize) -> usize {
+        if self.skip == 0 {
+            remaining.div_ceil(2)
+        } else {
+            remaining / 2
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for Decimate<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.nth(self.skip);
+        self.skip = 1;
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (self.kept(lower), upper.map(|upper| self.kept(upper)))
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for Decimate<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        let last = self.iter.len().checked_sub(1)?;
+        if last % 2 != self.skip {
+            self.iter.next_back();
+        }
+        self.iter.next_back()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for Decimate<I> {}
+
+impl<I: FusedIterator> FusedIterator for Decimate<I> {}
+
+/// An iterator negating every second item of another iterator.
+///
+/// Created by [ParityIterator::alternate_signs].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AlternateSigns<I> {
+    iter: I,
+    // whether the next item from the front is negated
+    negate: bool,
+}
+
+impl<I> AlternateSigns<I> {
+    /// Returns the underlying iterator.
+    #[inline]
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for AlternateSigns<I>
+where
+    I::Item: Neg<Output = I::Item>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        let negate = self.negate;
+        self.negate = !negate;
+        Some(if negate { -item } else { item })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for AlternateSigns<I>
+where
+    I::Item: Neg<Output = I::Item>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        // the last item is `len - 1` items after the next one from the front
+        let negate = self.negate != (self.iter.len().checked_sub(1)? % 2 == 1);
+        let item = self.iter.next_back()?;
+        Some(if negate { -item } else { item })
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for AlternateSigns<I> where
+    I::Item: Neg<Output = I::Item>
+{
+}
+
+impl<I: FusedIterator> FusedIterator for AlternateSigns<I> where I::Item: Neg<Output = I::Item> {}
+
+/// Returns the parity of `value`, for use as the key function of grouping and sorting APIs.
+///
+/// The function takes its argument by reference, so it can be passed by name wherever a `FnMut(&T) -> K` key is
+/// expected, such as [slice::sort_by_key] or the `chunk_by` and `into_group_map_by` adapters of `itertools`.
+/// Use [checked_parity_key] for values that may have no parity.
+///
+/// # Example
+/// ```
+/// use parity::iter::parity_key;
+/// use parity::ParityKind;
+///
+/// let mut values = [3, 8, 5, 2];
+/// values.sort_by_key(parity_key);
+/// assert_eq!(values, [8, 2, 3, 5]);
+/// assert_eq!(parity_key(&-7i64), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn parity_key<T: IntegerParity + ?Sized>(value: &T) -> ParityKind {
+    value.parity()
+}
+
+/// Returns the parity of `value`, or `None` if it has none, for use as a key function like [parity_key].
+///
+/// # Example
+/// ```
+/// use parity::iter::checked_parity_key;
+///
+/// let mut values = [0.5, 3.0, 2.0];
+/// values.sort_by_key(checked_parity_key);
+/// assert_eq!(values, [0.5, 2.0, 3.0]);
+/// ```
+#[inline]
+pub fn checked_parity_key<T: Parity + ?Sized>(value: &T) -> Option<ParityKind> {
+    value.checked_parity()
+}
+
+/// How the parity signatures of two sequences differ, returned by [diff_parity_profiles].
+///
+/// Positions past the end of the shorter sequence count as mismatches, as do positions where only one of the two
+/// items has a parity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityProfileDiff {
+    /// The index of the first position where the signatures differ, or `None` if they are identical.
+    pub first_mismatch: Option<usize>,
+    /// The number of positions where the signatures differ.
+    pub mismatches: usize,
+    /// The length of the first sequence.
+    pub a_len: usize,
+    /// The length of the second sequence.
+    pub b_len: usize,
+    /// The number of even and odd items in the first sequence.
+    pub a: ParityCount,
+    /// The number of even and odd items in the second sequence.
+    pub b: ParityCount,
+}
+
+impl ParityProfileDiff {
+    /// Returns `true` if the two sequences have the same parity signature.
+    #[inline]
+    pub const fn is_identical(&self) -> bool {
+        self.first_mismatch.is_none()
+    }
+
+    /// Returns how many more items of the given `parity` the second sequence has than the first, which is negative
+    /// if it has fewer.
+    #[inline]
+    pub const fn delta(&self, parity: ParityKind) -> isize {
+        self.b.get(parity) as isize - self.a.get(parity) as isize
+    }
+
+    /// Returns [delta](Self::delta) for even items.
+    #[inline]
+    pub const fn even_delta(&self) -> isize {
+        self.delta(ParityKind::Even)
+    }
+
+    /// Returns [delta](Self::delta) for odd items.
+    #[inline]
+    pub const fn odd_delta(&self) -> isize {
+        self.delta(ParityKind::Odd)
+    }
+}
+
+/// Compares the [parity signatures](ParityIterator::parity_signature) of `a` and `b` position by position.
+///
+/// This localizes where the even/odd structure of two runs of a numeric pipeline diverged, where
+/// [eq_by_parity](ParityIterator::eq_by_parity) only tells whether it did.
+///
+/// # Example
+/// ```
+/// use parity::iter::diff_parity_profiles;
+///
+/// let expected = [2, 4, 5, 7, 8];
+/// let actual = [0, 4, 6, 7];
+/// let diff = diff_parity_profiles(expected, actual);
+/// assert_eq!(diff.first_mismatch, Some(2));
+/// // position 2 has the wrong parity, and position 4 is missing
+/// assert_eq!(diff.mismatches, 2);
+/// assert_eq!((diff.even_delta(), diff.odd_delta()), (0, -1));
+/// assert!(diff_parity_profiles([1, 2], [3, 0]).is_identical());
+/// ```
+pub fn diff_parity_profiles<A, B>(a: A, b: B) -> ParityProfileDiff
+where
+    A: IntoIterator,
+    A::Item: Parity,
+    B: IntoIterator,
+    B::Item: Parity,
+{
+    let mut diff = ParityProfileDiff::default();
+    let mut a = a.into_iter().fuse();
+    let mut b = b.into_iter().fuse();
+    loop {
+        let (x, y) = match (a.next(), b.next()) {
+            (None, None) => break,
+            (x, y) => (x.map(|x| x.checked_parity()), y.map(|y| y.checked_parity())),
+        };
+        let index = diff.a_len.max(diff.b_len);
+        if let Some(parity) = x {
+            diff.a_len += 1;
+            if let Some(parity) = parity {
+                diff.a.record(parity);
+            }
+        }
+        if let Some(parity) = y {
+            diff.b_len += 1;
+            if let Some(parity) = parity {
+                diff.b.record(parity);
+            }
+        }
+        if x != y {
+            diff.first_mismatch.get_or_insert(index);
+            diff.mismatches += 1;
+        }
+    }
+    diff
+}
+
+/// Returns an iterator placing the items of `evens` at the even positions and the items of `odds` at the odd
+/// positions, the inverse of [decimating](ParityIterator::decimate) a stream into its two phases.
+///
+/// The iterator stops as soon as the source for the next position is exhausted, even if the other source has items
+/// left, so that no item ever lands at a position of the wrong parity. The leftover items stay in the sources,
+/// which [InterleaveParity::into_inner] gives back.
+///
+/// # Example
+/// ```
+/// use parity::iter::interleave_parity;
+///
+/// let woven: String = interleave_parity("ace".chars(), "bd".chars()).collect();
+/// assert_eq!(woven, "abcde");
+///
+/// // the third even position has no item, so the last odd item is never used
+/// assert!(interleave_parity([0, 2], [1, 3, 5]).eq([0, 1, 2, 3]));
+/// ```
+#[inline]
+pub fn interleave_parity<A, B>(evens: A, odds: B) -> InterleaveParity<A::IntoIter, B::IntoIter>
+where
+    A: IntoIterator,
+    B: IntoIterator<Item = A::Item>,
+{
+    InterleaveParity {
+        evens: evens.into_iter(),
+        odds: odds.into_iter(),
+        next: ParityKind::Even,
+    }
+}
+
+/// An iterator alternating between the items of two iterators, created by [interleave_parity].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InterleaveParity<A, B> {
+    evens: A,
+    odds: B,
+    next: ParityKind,
+}
+
+impl<A, B> InterleaveParity<A, B> {
+    /// Returns the parity of the position of the next item.
+    #[inline]
+    pub fn next_parity(&self) -> ParityKind {
+        self.next
+    }
+
+    /// Returns the sources of the even and odd positions, with the items not yet yielded.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.evens, self.odds)
+    }
+}
+
+/// Returns how many items alternate when the source of the next position has `next` items and the other has
+/// `other`, with `None` standing for an unbounded or overflowing count.
+#[inline]
+fn alternating_len(next: Option<usize>, other: Option<usize>) -> Option<usize> {
+    match (next, other) {
+        (Some(next), Some(other)) if next <= other => next.checked_mul(2),
+        (_, Some(other)) => other.checked_mul(2)?.checked_add(1),
+        (Some(next), None) => next.checked_mul(2),
+        (None, None) => None,
+    }
+}
+
+impl<A, B> Iterator for InterleaveParity<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<A::Item> {
+        let item = match self.next {
+            ParityKind::Even => self.evens.next()?,
+            ParityKind::Odd => self.odds.next()?,
+        };
+        self.next = self.next + ParityKind::Odd;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (evens, odds) = (self.evens.size_hint(), self.odds.size_hint());
+        let (lower, upper) = match self.next {
+            ParityKind::Even => (
+                alternating_len(Some(evens.0), Some(odds.0)),
+                alternating_len(evens.1, odds.1),
+            ),
+            ParityKind::Odd => (
+                alternating_len(Some(odds.0), Some(evens.0)),
+                alternating_len(odds.1, evens.1),
+            ),
+        };
+        (lower.unwrap_or(usize::MAX), upper)
+    }
+}
+
+impl<A, B> FusedIterator for InterleaveParity<A, B>
+where
+    A: FusedIterator,
+    B: FusedIterator<Item = A::Item>,
+{
+}
+
+/// An iterator over the integers of one parity in a range, stepping by two.
+///
+/// Unlike filtering the range with [ParityIterator::with_parity], this never looks at the values of the other
+/// parity and knows its exact length.
+///
+/// # Example
+/// ```
+/// use parity::iter::ParityValues;
+/// use parity::ParityKind;
+///
+/// let evens = ParityValues::<u8>::new(3..=10, ParityKind::Even);
+/// assert_eq!(evens.len(), 4);
+/// assert!(evens.eq([4, 6, 8, 10]));
+/// assert_eq!(ParityValues::<i128>::new(.., ParityKind::Odd).size_hint(), (usize::MAX, None));
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParityValues<T> {
+    // the remaining values are `front, front + 2, ..., back`, unless the iterator is exhausted
+    front: T,
+    back: T,
+    exhausted: bool,
+}
+
+macro_rules! impl_parity_values {
+    ($($T:ty),*) => { $(
+        impl ParityValues<$T> {
+            /// Returns an iterator over the values of the given `parity` in `range`, in increasing order.
+            pub fn new<B: RangeBounds<$T>>(range: B, parity: ParityKind) -> Self {
+                Self::in_range(&range, parity)
+            }
+
+            fn in_range<B: RangeBounds<$T>>(range: &B, parity: ParityKind) -> Self {
+                match <$T>::parity_range(range, parity) {
+                    Some((first, steps)) => Self {
+                        front: first,
+                        back: <$T>::nth_step(first, steps),
+                        exhausted: false,
+                    },
+                    None => Self { front: 0, back: 0, exhausted: true },
+                }
+            }
+
+            /// Returns iterators over the even and the odd values in `range`, which together cover it without
+            /// overlapping.
+            ///
+            /// This splits an index space between two workers, as in red-black or odd-even ordering, and each
+            /// half knows its exact length for scheduling.
+            ///
+            /// # Example
+            /// ```
+            /// use parity::iter::ParityValues;
+            ///
+            #[doc = concat!("let (evens, odds) = ParityValues::<", stringify!($T), ">::split(1..=6);")]
+            /// assert_eq!((evens.size_hint(), odds.size_hint()), ((3, Some(3)), (3, Some(3))));
+            /// assert!(evens.eq([2, 4, 6]));
+            /// assert!(odds.eq([1, 3, 5]));
+            /// ```
+            pub fn split<B: RangeBounds<$T>>(range: B) -> (Self, Self) {
+                (Self::in_range(&range, ParityKind::Even), Self::in_range(&range, ParityKind::Odd))
+            }
+        }
+
+        impl Iterator for ParityValues<$T> {
+            type Item = $T;
+
+            #[inline]
+            fn next(&mut self) -> Option<$T> {
+                if self.exhausted {
+                    return None;
+                }
+                let value = self.front;
+                if value == self.back {
+                    self.exhausted = true;
+                } else {
+                    self.front += 2;
+                }
+                Some(value)
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                if self.exhausted {
+                    return (0, Some(0));
+                }
+                let len = usize::try_from(self.back.abs_diff(self.front) / 2)
+                    .ok()
+                    .and_then(|steps| steps.checked_add(1));
+                (len.unwrap_or(usize::MAX), len)
+            }
+        }
+
+        impl DoubleEndedIterator for ParityValues<$T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$T> {
+                if self.exhausted {
+                    return None;
+                }
+                let value = self.back;
+                if value == self.front {
+                    self.exhausted = true;
+                } else {
+                    self.back -= 2;
+                }
+                Some(value)
+            }
+        }
+
+        impl FusedIterator for ParityValues<$T> {}
+    )* };
+}
+
+impl_parity_values![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+macro_rules! impl_exact_size {
+    ($($T:ty),*) => { $(
+        impl ExactSizeIterator for ParityValues<$T> {}
+    )* };
+}
+
+// only the types whose ranges of one parity have at most 2^31 values, which fits in a 32-bit `usize`
+impl_exact_size![u8, u16, u32, i8, i16, i32];
+
+/// An endless alternation between the two parities, for behavior that switches on every use, such as ping-pong
+/// buffers or striped rows.
+///
+/// Each call to [next](Iterator::next) returns the current parity and then flips it, so the first item is the
+/// parity the toggle was created with.
+///
+/// # Example
+/// ```
+/// use parity::iter::Toggle;
+/// use parity::ParityKind;
+///
+/// let mut buffers = [Vec::new(), Vec::new()];
+/// let mut target = Toggle::new(ParityKind::Even);
+/// for frame in 0..5 {
+///     let parity = target.next().unwrap();
+///     buffers[usize::from(parity.is_odd())].push(frame);
+/// }
+/// assert_eq!(buffers, [vec![0, 2, 4], vec![1, 3]]);
+/// assert_eq!(target.current(), ParityKind::Odd);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Toggle {
+    current: ParityKind,
+}
+
+impl Toggle {
+    /// Returns a toggle whose first item is `start`.
+    #[inline]
+    pub const fn new(start: ParityKind) -> Self {
+        Self { current: start }
+    }
+
+    /// Returns the parity the next call to [next](Iterator::next) returns, without flipping it.
+    #[inline]
+    pub const fn current(&self) -> ParityKind {
+        self.current
+    }
+
+    /// Flips the current parity without returning it.
+    #[inline]
+    pub fn flip(&mut self) {
+        self.current = self.current.other();
+    }
+
+    /// Sets the parity the next call to [next](Iterator::next) returns.
+    #[inline]
+    pub fn set(&mut self, parity: ParityKind) {
+        self.current = parity;
+    }
+}
+
+impl Default for Toggle {
+    /// Returns a toggle starting at [ParityKind::Even].
+    #[inline]
+    fn default() -> Self {
+        Self::new(ParityKind::Even)
+    }
+}
+
+impl Iterator for Toggle {
+    type Item = ParityKind;
+
+    #[inline]
+    fn next(&mut self) -> Option<ParityKind> {
+        let parity = self.current;
+        self.flip();
+        Some(parity)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<ParityKind> {
+        self.current = self.current + ParityKind::from_odd(!n.is_multiple_of(2));
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for Toggle {}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn product_parity_short_circuits() {
+        let mut seen = 0;
+        let parity = [1u8, 3, 4, 5, 7]
+            .into_iter()
+            .inspect(|_| seen += 1)
+            .product_parity();
+        assert_eq!(parity, ParityKind::Even);
+        assert_eq!(seen, 3);
+        assert_eq!(core::iter::empty::<i32>().product_parity(), ParityKind::Odd);
+    }
+
+    #[test]
+    fn sum_parity() {
+        for n in 0..20u32 {
+            assert_eq!((0..n).sum_parity(), (0..n).sum::<u32>().parity());
+            assert_eq!(
+                (0..n).map(f64::from).checked_sum_parity(),
+                Some((0..n).sum_parity())
+            );
+        }
+        assert_eq!([2.0, f64::NAN].into_iter().checked_sum_parity(), None);
+    }
+
+    #[test]
+    fn parity_comparison() {
+        assert!((0..5u8).eq_by_parity(10..15i128));
+        assert!(!(0..5u8).eq_by_parity(1..6u8));
+        assert!(core::iter::empty::<u8>().eq_by_parity(core::iter::empty::<f32>()));
+        assert!(![1.0, 2.0].into_iter().eq_by_parity([1.0, 2.5]));
+
+        let signature: std::vec::Vec<_> = [1.5, -2.0].into_iter().parity_signature();
+        assert_eq!(signature, [None, Some(ParityKind::Even)]);
+        let signature: Option<std::vec::Vec<ParityKind>> = (3..6).parity_signature();
+        assert_eq!(
+            signature,
+            Some(std::vec![
+                ParityKind::Odd,
+                ParityKind::Even,
+                ParityKind::Odd
+            ])
+        );
+    }
+
+    #[test]
+    fn count_parities() {
+        let count = (-5i32..5).count_parities();
+        assert_eq!(count, ParityCount { even: 5, odd: 5 });
+        assert_eq!(count.get(ParityKind::Odd), 5);
+        let count = [f64::NAN, 1.0, 3.0, f64::INFINITY]
+            .into_iter()
+            .count_parities();
+        assert_eq!((count.even, count.odd, count.total()), (0, 2, 2));
+        assert_eq!(
+            core::iter::empty::<u8>().count_parities(),
+            ParityCount::default()
+        );
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv_round_trip() {
+        use rkyv::rancor::Error;
+
+        let count = ParityCount { even: 3, odd: 9 };
+        let bytes = rkyv::to_bytes::<Error>(&count).unwrap();
+        let archived = rkyv::access::<ArchivedParityCount, Error>(&bytes).unwrap();
+        assert_eq!(
+            (archived.even.to_native(), archived.odd.to_native()),
+            (3, 9)
+        );
+        assert_eq!(
+            rkyv::deserialize::<ParityCount, Error>(archived).unwrap(),
+            count
+        );
+
+        let bytes = rkyv::to_bytes::<Error>(&ParityKind::Odd).unwrap();
+        let archived = rkyv::access::<crate::ArchivedParityKind, Error>(&bytes).unwrap();
+        assert_eq!(*archived, ParityKind::Odd);
+    }
+
+    #[test]
+    fn parity_adapters() {
+        let evens = (0..10).evens();
+        assert_eq!(evens.size_hint(), (0, Some(10)));
+        assert_eq!(evens.parity(), ParityKind::Even);
+        assert_eq!(evens.clone().count(), 5);
+        assert_eq!(evens.clone().sum::<i32>(), 20);
+        assert!(evens.rev().eq([8, 6, 4, 2, 0]));
+        assert_eq!((0..10).odds().rfold(0, |acc, x| acc * 10 + x), 97531);
+        assert_eq!((0u64..).evens().size_hint(), (0, None));
+
+        // fused like the underlying range
+        let mut odds = (0..3).odds();
+        assert_eq!(
+            (odds.next(), odds.next(), odds.next()),
+            (Some(1), None, None)
+        );
+        let floats = [0.5, 1.0, f64::NAN, 4.0];
+        assert_eq!(floats.into_iter().with_parity(ParityKind::Even).count(), 1);
+    }
+
+    #[test]
+    fn parity_values() {
+        for start in -6i8..6 {
+            for end in -6i8..6 {
+                for parity in [ParityKind::Even, ParityKind::Odd] {
+                    let values = ParityValues::<i8>::new(start..end, parity);
+                    let expected: std::vec::Vec<i8> = (start..end).with_parity(parity).collect();
+                    assert_eq!(values.len(), expected.len());
+                    assert!(values.clone().eq(expected.iter().copied()));
+                    assert!(values.rev().eq(expected.iter().rev().copied()));
+                }
+            }
+        }
+        assert!(ParityValues::<u8>::new(250.., ParityKind::Odd).eq([251, 253, 255]));
+        assert!(ParityValues::<i16>::new(..=i16::MIN + 2, ParityKind::Even)
+            .eq([i16::MIN, i16::MIN + 2]));
+        assert_eq!(
+            ParityValues::<u32>::new(.., ParityKind::Even).len(),
+            1 << 31
+        );
+
+        let mut values = ParityValues::<u64>::new(1..=5, ParityKind::Odd);
+        assert_eq!((values.next(), values.next_back()), (Some(1), Some(5)));
+        assert_eq!(values.size_hint(), (1, Some(1)));
+        assert_eq!(
+            (values.next_back(), values.next(), values.next_back()),
+            (Some(3), None, None)
+        );
+    }
+
+    #[test]
+    fn split_range() {
+        for start in 0..6u16 {
+            for end in 0..8u16 {
+                let (evens, odds) = ParityValues::<u16>::split(start..end);
+                assert_eq!(evens.len() + odds.len(), (start..end).len());
+                let mut merged: std::vec::Vec<u16> = evens.chain(odds).collect();
+                merged.sort_unstable();
+                assert!(merged.into_iter().eq(start..end));
+            }
+        }
+        let (evens, odds) = ParityValues::<i128>::split(..);
+        assert_eq!(
+            (evens.size_hint(), odds.size_hint()),
+            ((usize::MAX, None), (usize::MAX, None))
+        );
+        let (evens, odds) = ParityValues::<u8>::split(7..=7);
+        assert_eq!((evens.len(), odds.len()), (0, 1));
+    }
+
+    #[test]
+    fn alternate_signs() {
+        for len in 0..7i32 {
+            for start in [ParityKind::Even, ParityKind::Odd] {
+                let expected: Vec<i32> = (0..len)
+                    .map(|i| {
+                        if (i % 2 == 1) != start.is_odd() {
+                            -i
+                        } else {
+                            i
+                        }
+                    })
+                    .collect();
+                let iter = (0..len).alternate_signs(start);
+                assert_eq!(iter.len(), expected.len());
+                assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+                assert!(iter.clone().rev().eq(expected.iter().rev().copied()));
+                // alternate ends
+                let mut iter = iter;
+                let mut want = expected.iter().copied();
+                for step in 0..len {
+                    if step % 2 == 0 {
+                        assert_eq!(iter.next(), want.next());
+                    } else {
+                        assert_eq!(iter.next_back(), want.next_back());
+                    }
+                }
+                assert_eq!(iter.next(), None);
+            }
+        }
+        let floats: Vec<f32> = [1.0, 2.0, -3.0]
+            .into_iter()
+            .alternate_signs(ParityKind::Odd)
+            .collect();
+        assert_eq!(floats, [-1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn decimate() {
+        for len in 0..8 {
+            for phase in [ParityKind::Even, ParityKind::Odd] {
+                let expected: Vec<usize> = (0..len).with_parity(phase).collect();
+                let mut iter = (0..len).decimate(phase);
+                assert_eq!(iter.len(), expected.len());
+                assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+                assert!(iter.clone().rev().eq(expected.iter().rev().copied()));
+                // alternate ends, checking the length after every step
+                let mut front = expected.iter().copied();
+                for step in 0..len {
+                    let item = if step % 2 == 0 {
+                        iter.next()
+                    } else {
+                        iter.next_back()
+                    };
+                    let want = if step % 2 == 0 {
+                        front.next()
+                    } else {
+                        front.next_back()
+                    };
+                    assert_eq!(item, want);
+                    assert_eq!(iter.len(), front.len());
+                }
+            }
+        }
+        let filtered = (0..10).filter(|x| x % 3 != 0).decimate(ParityKind::Odd);
+        assert_eq!(filtered.size_hint(), (0, Some(5)));
+        assert_eq!(
+            (0u64..).decimate(ParityKind::Even).size_hint(),
+            (usize::MAX.div_ceil(2), None)
+        );
+    }
+
+    #[test]
+    fn interleave_parity_inverts_decimate() {
+        for len in 0..8 {
+            let evens = (0..len).decimate(ParityKind::Even);
+            let odds = (0..len).decimate(ParityKind::Odd);
+            let woven = interleave_parity(evens, odds);
+            assert_eq!(woven.size_hint(), (len, Some(len)));
+            assert!(woven.eq(0..len));
+        }
+    }
+
+    #[test]
+    fn interleave_parity_uneven_sources() {
+        let mut woven = interleave_parity([0, 2, 4, 6], [1]);
+        assert_eq!(woven.size_hint(), (3, Some(3)));
+        assert_eq!(woven.by_ref().collect::<Vec<_>>(), [0, 1, 2]);
+        assert_eq!(woven.next_parity(), ParityKind::Odd);
+        let (evens, mut odds) = woven.into_inner();
+        assert!(evens.eq([4, 6]) && odds.next().is_none());
+
+        assert_eq!(interleave_parity(0.., [1, 3]).size_hint(), (5, Some(5)));
+        assert_eq!(interleave_parity([0], 1..).size_hint(), (2, Some(2)));
+        assert_eq!(interleave_parity(0.., 1..).size_hint(), (usize::MAX, None));
+        let filtered = interleave_parity((0..6).filter(|x| x % 2 == 0), [1, 3]);
+        assert_eq!(filtered.size_hint(), (0, Some(5)));
+        assert!(filtered.eq([0, 1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn partition_parity_into() {
+        let (mut evens, mut odds) = ([0.0; 3], [0.0; 1]);
+        let values = [1.0, 2.0, 0.5, 3.0, 4.0, f64::NAN, 6.0, 8.0];
+        let count = values
+            .into_iter()
+            .partition_parity_into(&mut evens, &mut odds);
+        assert_eq!(count, ParityCount { even: 3, odd: 1 });
+        assert_eq!((evens, odds), ([2.0, 4.0, 6.0], [1.0]));
+
+        let count = core::iter::empty::<u8>().partition_parity_into(&mut [], &mut []);
+        assert_eq!(count.total(), 0);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn partition_parity_heapless() {
+        let mut evens = heapless::Vec::<i32, 4>::from_slice(&[100]).unwrap();
+        let mut odds = heapless::Vec::<i32, 4>::new();
+        let count = (-4..4).partition_parity_heapless(&mut evens, &mut odds);
+        assert_eq!(count, ParityCount { even: 3, odd: 4 });
+        assert_eq!(evens, [100, -4, -2, 0]);
+        assert_eq!(odds, [-3, -1, 1, 3]);
+    }
+
+    #[test]
+    fn toggle() {
+        let mut toggle = Toggle::new(ParityKind::Odd);
+        let first: Vec<ParityKind> = toggle.by_ref().take(3).collect();
+        assert_eq!(first, [ParityKind::Odd, ParityKind::Even, ParityKind::Odd]);
+        assert_eq!(toggle.current(), ParityKind::Even);
+
+        toggle.flip();
+        assert_eq!(toggle.current(), ParityKind::Odd);
+        toggle.set(ParityKind::Even);
+        assert_eq!(toggle.nth(4), Some(ParityKind::Even));
+        assert_eq!(toggle.nth(1), Some(ParityKind::Even));
+        assert_eq!(toggle.next(), Some(ParityKind::Odd));
+
+        // skipping with `nth` agrees with stepping one item at a time
+        for n in 0..6 {
+            let mut stepped = Toggle::default();
+            for _ in 0..n {
+                stepped.next();
+            }
+            let mut skipped = Toggle::default();
+            assert_eq!(skipped.nth(n), stepped.next());
+            assert_eq!(skipped, stepped);
+        }
+    }
+
+    #[test]
+    fn parity_sums_and_products() {
+        let values = [-7i64, -4, 0, 3, 8, 11, 12];
+        assert_eq!(values.into_iter().sum_even::<i64>(), 16);
+        assert_eq!(values.iter().copied().sum_odd::<i64>(), 7);
+        assert_eq!(values.into_iter().product_odd::<i64>(), -231);
+        assert_eq!(values.into_iter().checked_sum_even(), Some(16));
+        assert_eq!(values.into_iter().checked_sum_odd(), Some(7));
+        assert_eq!(values.into_iter().checked_product_odd(), Some(-231));
+
+        assert_eq!(core::iter::empty::<u8>().checked_product_odd(), Some(1));
+        assert_eq!(core::iter::empty::<u8>().checked_sum_even(), Some(0));
+        // an overflowing item of the other parity does not matter
+        assert_eq!(
+            [u8::MAX, 2, u8::MAX].into_iter().checked_sum_even(),
+            Some(2)
+        );
+        assert_eq!([u128::MAX, 3].into_iter().checked_product_odd(), None);
+        assert_eq!([0.5, 1.0, 3.0].into_iter().product_odd::<f64>(), 3.0);
+    }
+
+    #[test]
+    fn parity_extrema() {
+        use crate::Even;
+
+        let values = [5u32, 12, 9, 0, 31, 8, 12];
+        assert_eq!(values.into_iter().min_even(), Some(0));
+        assert_eq!(values.into_iter().max_even(), Some(12));
+        assert_eq!(values.into_iter().min_odd(), Some(5));
+        assert_eq!(values.into_iter().max_odd(), Some(31));
+        assert_eq!(core::iter::empty::<i8>().min_odd(), None);
+
+        // the wrappers order like their values
+        let wrapped = [4i16, -6, 2].map(|v| Even::new(v).unwrap());
+        assert_eq!(wrapped.into_iter().max_even(), Some(wrapped[0]));
+        assert_eq!(wrapped.into_iter().min_even(), Some(wrapped[1]));
+        assert_eq!(wrapped.into_iter().min_odd(), None);
+    }
+
+    #[test]
+    fn parity_run_stats() {
+        assert_eq!(
+            core::iter::empty::<u8>().parity_run_stats(),
+            ParityRunStats::default()
+        );
+        let stats = [7u8].into_iter().parity_run_stats();
+        assert_eq!(
+            (stats.runs, stats.longest_run, stats.same_parity_pairs),
+            (1, 1, 0)
+        );
+        assert_eq!(stats.longest_run_parity, Some(ParityKind::Odd));
+
+        // the first of equally long runs decides the parity
+        let stats = [2, 4, 1, 3, 6].into_iter().parity_run_stats();
+        assert_eq!((stats.runs, stats.longest_run), (3, 2));
+        assert_eq!(stats.longest_run_parity, Some(ParityKind::Even));
+
+        // values without a parity separate runs of the same parity
+        let stats = [1.0, 3.0, 0.5, 5.0, f64::NAN, f64::NAN, 2.0]
+            .into_iter()
+            .parity_run_stats();
+        assert_eq!(
+            stats,
+            ParityRunStats {
+                runs: 3,
+                longest_run: 2,
+                longest_run_parity: Some(ParityKind::Odd),
+                same_parity_pairs: 1,
+            }
+        );
+
+        let values: Vec<u32> = (0..500u32)
+            .map(|i| i.wrapping_mul(2_654_435_761) >> 9)
+            .collect();
+        let stats = values.iter().copied().parity_run_stats();
+        assert_eq!(stats.runs + stats.same_parity_pairs, values.len());
+        let changes = values
+            .windows(2)
+            .filter(|pair| pair[0].opposite_parity(&pair[1]))
+            .count();
+        assert_eq!(stats.runs, changes + 1);
+    }
+
+    #[test]
+    fn parity_transitions() {
+        assert!(core::iter::empty::<u8>()
+            .parity_transitions()
+            .next()
+            .is_none());
+        assert!([5u8].into_iter().parity_transitions().next().is_none());
+        assert!((0..6).parity_transitions().eq(1..6));
+        assert!([2.0, 1.0, 0.5, 3.0, 4.0, f64::NAN, 5.0]
+            .into_iter()
+            .parity_transitions()
+            .eq([1, 4]));
+
+        let values: Vec<u32> = (0..300u32)
+            .map(|i| i.wrapping_mul(2_654_435_761) >> 9)
+            .collect();
+        let expected: Vec<usize> = (1..values.len())
+            .filter(|&i| values[i - 1].opposite_parity(&values[i]))
+            .collect();
+        assert!(values
+            .iter()
+            .copied()
+            .parity_transitions()
+            .eq(expected.iter().copied()));
+        assert_eq!(
+            values.iter().copied().parity_transitions().count() + 1,
+            values.iter().copied().parity_run_stats().runs
+        );
+        assert_eq!(
+            values.into_iter().parity_transitions().size_hint(),
+            (0, Some(300))
+        );
+    }
+
+    #[test]
+    fn parity_profile_diff() {
+        let diff = diff_parity_profiles([1, 2, 3], [1, 2, 3]);
+        assert!(diff.is_identical());
+        assert_eq!(diff.mismatches, 0);
+        assert_eq!((diff.a_len, diff.b_len), (3, 3));
+        assert_eq!(diff.a, ParityCount { even: 1, odd: 2 });
+        assert_eq!(diff.even_delta(), 0);
+
+        // values without a parity match each other, but not a value with one
+        let diff = diff_parity_profiles([0.5, 1.0, 2.0], [f64::NAN, 2.0, 2.5]);
+        assert_eq!(diff.first_mismatch, Some(1));
+        assert_eq!(diff.mismatches, 2);
+        assert_eq!(diff.a, ParityCount { even: 1, odd: 1 });
+        assert_eq!(diff.b, ParityCount { even: 1, odd: 0 });
+        assert_eq!(diff.delta(ParityKind::Odd), -1);
+
+        // the longer sequence's tail is all mismatches
+        let diff = diff_parity_profiles(0..2, 0..5);
+        assert_eq!(diff.first_mismatch, Some(2));
+        assert_eq!(diff.mismatches, 3);
+        assert_eq!((diff.a_len, diff.b_len), (2, 5));
+        assert_eq!((diff.even_delta(), diff.odd_delta()), (2, 1));
+
+        assert!(diff_parity_profiles(core::iter::empty::<u8>(), [0u8; 0]).is_identical());
+        assert_eq!(
+            diff_parity_profiles([0u8; 0], [7u8]).first_mismatch,
+            Some(0)
+        );
+    }
+}
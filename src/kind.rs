@@ -0,0 +1,148 @@
+use core::fmt;
+use core::ops::{Add, Mul};
+
+/// The parity of a value: either [ParityKind::Even] or [ParityKind::Odd].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "wasm", wasm_bindgen::prelude::wasm_bindgen(js_name = Parity))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::SmallInt)
+)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug), compare(PartialEq))
+)]
+pub enum ParityKind {
+    /// Divisible by two.
+    Even,
+    /// Not divisible by two.
+    Odd,
+}
+
+impl ParityKind {
+    /// Both parities, even first.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{IntegerParity, ParityKind};
+    ///
+    /// // one bucket per parity, indexed in the order of `ALL`
+    /// let mut buckets: [Vec<u32>; 2] = Default::default();
+    /// for x in [3, 8, 5, 2] {
+    ///     buckets[x.parity() as usize].push(x);
+    /// }
+    /// for (parity, bucket) in ParityKind::ALL.iter().zip(&buckets) {
+    ///     assert!(bucket.iter().all(|x| x.parity() == *parity));
+    /// }
+    /// ```
+    pub const ALL: [ParityKind; 2] = [ParityKind::Even, ParityKind::Odd];
+
+    /// Returns an iterator over both parities, even first.
+    #[inline]
+    pub fn iter() -> core::array::IntoIter<ParityKind, 2> {
+        Self::ALL.into_iter()
+    }
+
+    /// Returns the other parity.
+    ///
+    /// This is the same as adding [ParityKind::Odd].
+    ///
+    /// # Example
+    /// ```
+    /// use parity::ParityKind;
+    ///
+    /// assert_eq!(ParityKind::Even.other(), ParityKind::Odd);
+    /// assert_eq!(ParityKind::Odd.other(), ParityKind::Even);
+    /// ```
+    #[inline]
+    pub const fn other(self) -> Self {
+        ParityKind::from_odd(self.is_even())
+    }
+
+    /// Returns [ParityKind::Odd] if `odd` is `true`, and [ParityKind::Even] otherwise.
+    #[inline]
+    pub const fn from_odd(odd: bool) -> Self {
+        if odd {
+            ParityKind::Odd
+        } else {
+            ParityKind::Even
+        }
+    }
+
+    /// Returns `true` for [ParityKind::Even].
+    #[inline]
+    pub const fn is_even(self) -> bool {
+        matches!(self, ParityKind::Even)
+    }
+
+    /// Returns `true` for [ParityKind::Odd].
+    #[inline]
+    pub const fn is_odd(self) -> bool {
+        matches!(self, ParityKind::Odd)
+    }
+}
+
+/// A finer classification of integers by how even they are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum EvenClass {
+    /// Not divisible by two.
+    Odd,
+    /// Divisible by two but not by four, i.e. `2 (mod 4)`.
+    SinglyEven,
+    /// Divisible by four, including zero.
+    DoublyEven,
+}
+
+impl EvenClass {
+    /// Returns the parity of the values in this class.
+    #[inline]
+    pub const fn parity(self) -> ParityKind {
+        ParityKind::from_odd(matches!(self, EvenClass::Odd))
+    }
+}
+
+/// How to round the result of halving an odd integer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero, truncating like integer division.
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+}
+
+/// Addition in GF(2): the parity of a sum is the sum of the parities of its terms.
+impl Add for ParityKind {
+    type Output = ParityKind;
+
+    #[inline]
+    fn add(self, rhs: ParityKind) -> ParityKind {
+        ParityKind::from_odd(self != rhs)
+    }
+}
+
+/// Multiplication in GF(2): a product is odd only if all of its factors are odd.
+impl Mul for ParityKind {
+    type Output = ParityKind;
+
+    #[inline]
+    fn mul(self, rhs: ParityKind) -> ParityKind {
+        ParityKind::from_odd(self.is_odd() && rhs.is_odd())
+    }
+}
+
+impl fmt::Display for ParityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ParityKind::Even => "even",
+            ParityKind::Odd => "odd",
+        })
+    }
+}
@@ -0,0 +1,306 @@
+//! Describing the parity of values in text, and labelling alternating rows.
+
+use core::fmt;
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// How a [ParityLabel] is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LabelStyle {
+    /// The value followed by its parity in parentheses, such as `42 (even)`.
+    #[default]
+    WithValue,
+    /// Only the parity, such as `even`.
+    ParityOnly,
+}
+
+/// The words used to write a parity, such as translations or single letters.
+///
+/// The strings are `'static` so that label sets can be kept in constants, as compiled-in translations usually are.
+///
+/// # Example
+/// ```
+/// use parity::label::{ParityLabel, ParityLabels};
+///
+/// const FRENCH: ParityLabels = ParityLabels::new("pair", "impair", "sans parité");
+///
+/// assert_eq!(ParityLabel::new(42).with_labels(FRENCH).to_string(), "42 (pair)");
+/// assert_eq!(ParityLabel::new(7).with_labels(ParityLabels::LETTERS).to_string(), "7 (O)");
+/// assert_eq!(FRENCH.get(None), "sans parité");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParityLabels {
+    even: &'static str,
+    odd: &'static str,
+    none: &'static str,
+}
+
+impl ParityLabels {
+    /// `even`, `odd` and `no parity`, as written by [ParityKind]'s `Display` implementation.
+    pub const ENGLISH: Self = Self::new("even", "odd", "no parity");
+
+    /// `E`, `O` and `-`, for terse output.
+    pub const LETTERS: Self = Self::new("E", "O", "-");
+
+    /// Creates a label set from the words for even values, odd values and values without a parity.
+    #[inline]
+    pub const fn new(even: &'static str, odd: &'static str, none: &'static str) -> Self {
+        Self { even, odd, none }
+    }
+
+    /// Returns the word for `parity`, or for a missing parity if it is `None`.
+    #[inline]
+    pub const fn get(&self, parity: Option<ParityKind>) -> &'static str {
+        match parity {
+            Some(parity) => self.kind(parity),
+            None => self.none,
+        }
+    }
+
+    /// Returns the word for `parity`.
+    #[inline]
+    pub const fn kind(&self, parity: ParityKind) -> &'static str {
+        match parity {
+            ParityKind::Even => self.even,
+            ParityKind::Odd => self.odd,
+        }
+    }
+
+    /// Returns the word for even values.
+    #[inline]
+    pub const fn even(&self) -> &'static str {
+        self.even
+    }
+
+    /// Returns the word for odd values.
+    #[inline]
+    pub const fn odd(&self) -> &'static str {
+        self.odd
+    }
+
+    /// Returns the word for values without a parity.
+    #[inline]
+    pub const fn none(&self) -> &'static str {
+        self.none
+    }
+}
+
+impl Default for ParityLabels {
+    #[inline]
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+/// Formats a value together with its parity.
+///
+/// Values without a parity, such as fractional floats, are labelled `no parity`. Other words can be chosen with
+/// [ParityLabel::with_labels].
+///
+/// # Example
+/// ```
+/// use parity::label::ParityLabel;
+///
+/// assert_eq!(ParityLabel::new(42).to_string(), "42 (even)");
+/// assert_eq!(ParityLabel::new(-7).parity_only().to_string(), "odd");
+/// assert_eq!(format!("got {}", ParityLabel::new(2.5)), "got 2.5 (no parity)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParityLabel<T> {
+    value: T,
+    style: LabelStyle,
+    labels: ParityLabels,
+}
+
+impl<T> ParityLabel<T> {
+    /// Labels `value` in the [LabelStyle::WithValue] style.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Self::with_style(value, LabelStyle::WithValue)
+    }
+
+    /// Labels `value` in the given `style`.
+    #[inline]
+    pub const fn with_style(value: T, style: LabelStyle) -> Self {
+        Self {
+            value,
+            style,
+            labels: ParityLabels::ENGLISH,
+        }
+    }
+
+    /// Writes the parity with the words in `labels`.
+    #[inline]
+    pub fn with_labels(mut self, labels: ParityLabels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Switches to the [LabelStyle::ParityOnly] style.
+    #[inline]
+    pub fn parity_only(mut self) -> Self {
+        self.style = LabelStyle::ParityOnly;
+        self
+    }
+
+    /// Returns the style of the label.
+    #[inline]
+    pub const fn style(&self) -> LabelStyle {
+        self.style
+    }
+
+    /// Returns the words the parity is written with.
+    #[inline]
+    pub const fn labels(&self) -> ParityLabels {
+        self.labels
+    }
+
+    /// Returns the labelled value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Parity + fmt::Display> fmt::Display for ParityLabel<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let with_value = self.style == LabelStyle::WithValue;
+        if with_value {
+            write!(f, "{} (", self.value)?;
+        }
+        f.write_str(self.labels.get(self.value.checked_parity()))?;
+        if with_value {
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the stripe of the row at the 0-based `index`: the first row is even.
+///
+/// # Example
+/// ```
+/// use parity::label::stripe;
+/// use parity::ParityKind;
+///
+/// assert_eq!(stripe(0), ParityKind::Even);
+/// assert_eq!(stripe(1), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn stripe(index: usize) -> ParityKind {
+    index.parity()
+}
+
+/// Returns the stripe of the row at the 1-based `index`: the first row is odd, as with the CSS selector
+/// `:nth-child(odd)`.
+///
+/// # Panics
+/// Panics if `index` is zero.
+#[inline]
+#[track_caller]
+pub fn stripe_one_based(index: usize) -> ParityKind {
+    assert!(index > 0, "1-based row index must be nonzero");
+    index.parity()
+}
+
+/// Returns `even_label` or `odd_label` for the row at the 0-based `index`. See [stripe].
+///
+/// # Example
+/// ```
+/// use parity::label::stripe_class;
+///
+/// let rows: Vec<_> = ["apples", "pears", "plums"]
+///     .iter()
+///     .enumerate()
+///     .map(|(i, row)| format!("<tr class=\"{}\">{row}</tr>", stripe_class(i, "even", "odd")))
+///     .collect();
+/// assert_eq!(rows[1], "<tr class=\"odd\">pears</tr>");
+/// ```
+#[inline]
+pub fn stripe_class<T>(index: usize, even_label: T, odd_label: T) -> T {
+    match stripe(index) {
+        ParityKind::Even => even_label,
+        ParityKind::Odd => odd_label,
+    }
+}
+
+/// Returns `even_label` or `odd_label` for the row at the 1-based `index`. See [stripe_one_based].
+///
+/// # Panics
+/// Panics if `index` is zero.
+#[inline]
+#[track_caller]
+pub fn stripe_class_one_based<T>(index: usize, even_label: T, odd_label: T) -> T {
+    match stripe_one_based(index) {
+        ParityKind::Even => even_label,
+        ParityKind::Odd => odd_label,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::format;
+
+    use super::*;
+
+    #[test]
+    fn labels() {
+        assert_eq!(format!("{}", ParityLabel::new(0u8)), "0 (even)");
+        assert_eq!(
+            format!("{}", ParityLabel::new(u64::MAX)),
+            "18446744073709551615 (odd)"
+        );
+        assert_eq!(
+            format!("{}", ParityLabel::new(f64::NAN).parity_only()),
+            "no parity"
+        );
+        let label = ParityLabel::with_style(3.0, LabelStyle::ParityOnly);
+        assert_eq!(format!("{label}"), "odd");
+        assert_eq!(label.style(), LabelStyle::ParityOnly);
+        assert_eq!(label.into_inner(), 3.0);
+        // the value's own formatting options are not applied
+        assert_eq!(format!("{:>4}", ParityLabel::new(1)), "1 (odd)");
+    }
+
+    #[test]
+    fn stripes() {
+        // the same row under both conventions has opposite stripes
+        for row in 0..10 {
+            assert_ne!(stripe(row), stripe_one_based(row + 1));
+        }
+        assert_eq!(stripe_class(4, "light", "dark"), "light");
+        assert_eq!(stripe_class_one_based(1, 'e', 'o'), 'o');
+    }
+
+    #[test]
+    #[should_panic(expected = "must be nonzero")]
+    fn one_based_zero() {
+        stripe_one_based(0);
+    }
+
+    #[test]
+    fn custom_labels() {
+        let label = ParityLabel::new(-4).with_labels(ParityLabels::LETTERS);
+        assert_eq!(format!("{label}"), "-4 (E)");
+        assert_eq!(format!("{}", label.parity_only()), "E");
+        assert_eq!(label.labels(), ParityLabels::LETTERS);
+        assert_eq!(
+            format!(
+                "{}",
+                ParityLabel::new(0.5).with_labels(ParityLabels::LETTERS)
+            ),
+            "0.5 (-)"
+        );
+        assert_eq!(ParityLabel::new(1).labels(), ParityLabels::default());
+
+        let german = ParityLabels::new("gerade", "ungerade", "keine Parität");
+        assert_eq!(german.kind(ParityKind::Odd), "ungerade");
+        assert_eq!(german.get(Some(ParityKind::Even)), german.even());
+        assert_eq!(german.get(None), german.none());
+        assert_eq!(german.odd(), "ungerade");
+        // the English labels agree with ParityKind's own formatting
+        for parity in [ParityKind::Even, ParityKind::Odd] {
+            assert_eq!(ParityLabels::ENGLISH.kind(parity), format!("{parity}"));
+        }
+    }
+}
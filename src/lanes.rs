@@ -0,0 +1,185 @@
+//! Lane-wise parity of small integer vectors: the portable SIMD vectors of `wide`, usable on stable Rust, and the
+//! integer vectors of `glam`.
+//!
+//! The lane-wise checks return the mask type of the vector's own comparisons. For `wide` that is the vector type
+//! itself, with every bit of a lane set where the check holds and clear where it does not, ready for `blend` or
+//! `to_bitmask`. For `glam` it is the `BVec` of the same dimension.
+//!
+//! # Example
+//! ```
+//! # #[cfg(feature = "wide")] {
+//! use parity::lanes::LaneParity;
+//! use wide::i32x4;
+//!
+//! let v = i32x4::new([1, 2, -3, -4]);
+//! assert_eq!(v.is_even_lanes().to_array(), [0, -1, 0, -1]);
+//! assert_eq!(v.is_odd_lanes().to_bitmask(), 0b0101);
+//! assert!(!v.all_even());
+//! assert!((v * i32x4::splat(2)).all_even());
+//! # }
+//! ```
+//!
+//! Tiles of a checkerboard where both coordinates are even:
+//! ```
+//! # #[cfg(feature = "glam")] {
+//! use glam::{BVec2, IVec2};
+//! use parity::lanes::LaneParity;
+//!
+//! let tile = IVec2::new(4, -3);
+//! assert_eq!(tile.is_even_lanes(), BVec2::new(true, false));
+//! assert!(!tile.all_even());
+//! assert!((tile * 2).all_even());
+//! # }
+//! ```
+
+/// Lane-wise parity checks for vectors of integers.
+pub trait LaneParity {
+    /// The mask with one lane per lane of `Self` that the checks return.
+    type Mask;
+
+    /// Returns a mask selecting the lanes of `self` that are even.
+    fn is_even_lanes(&self) -> Self::Mask;
+
+    /// Returns a mask selecting the lanes of `self` that are odd.
+    fn is_odd_lanes(&self) -> Self::Mask;
+
+    /// Returns `true` if every lane of `self` is even.
+    fn all_even(&self) -> bool;
+
+    /// Returns `true` if every lane of `self` is odd.
+    fn all_odd(&self) -> bool;
+}
+
+#[cfg(feature = "wide")]
+macro_rules! impl_wide_lane_parity {
+    ($($V:ty),*) => { $(
+        impl LaneParity for $V {
+            type Mask = $V;
+
+            #[inline]
+            fn is_even_lanes(&self) -> $V {
+                (*self & <$V>::splat(1)).simd_eq(<$V>::splat(0))
+            }
+
+            #[inline]
+            fn is_odd_lanes(&self) -> $V {
+                (*self & <$V>::splat(1)).simd_eq(<$V>::splat(1))
+            }
+
+            #[inline]
+            fn all_even(&self) -> bool {
+                self.is_even_lanes().all()
+            }
+
+            #[inline]
+            fn all_odd(&self) -> bool {
+                self.is_odd_lanes().all()
+            }
+        }
+    )* };
+}
+
+#[cfg(feature = "wide")]
+mod wide_vectors {
+    use wide::*;
+
+    use super::LaneParity;
+
+    impl_wide_lane_parity![
+        i8x16, i8x32, i8x64, i16x8, i16x16, i16x32, i32x4, i32x8, i32x16, i64x2, i64x4, i64x8
+    ];
+    impl_wide_lane_parity![
+        u8x16, u8x32, u8x64, u16x8, u16x16, u16x32, u32x4, u32x8, u32x16, u64x2, u64x4, u64x8
+    ];
+}
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_lane_parity {
+    ($($V:ty => $Mask:ty),*) => { $(
+        impl LaneParity for $V {
+            type Mask = $Mask;
+
+            #[inline]
+            fn is_even_lanes(&self) -> $Mask {
+                (*self & <$V>::ONE).cmpeq(<$V>::ZERO)
+            }
+
+            #[inline]
+            fn is_odd_lanes(&self) -> $Mask {
+                (*self & <$V>::ONE).cmpeq(<$V>::ONE)
+            }
+
+            #[inline]
+            fn all_even(&self) -> bool {
+                self.is_even_lanes().all()
+            }
+
+            #[inline]
+            fn all_odd(&self) -> bool {
+                self.is_odd_lanes().all()
+            }
+        }
+    )* };
+}
+
+#[cfg(feature = "glam")]
+mod glam_vectors {
+    use glam::*;
+
+    use super::LaneParity;
+
+    impl_glam_lane_parity![I8Vec2 => BVec2, I8Vec3 => BVec3, I8Vec4 => BVec4];
+    impl_glam_lane_parity![U8Vec2 => BVec2, U8Vec3 => BVec3, U8Vec4 => BVec4];
+    impl_glam_lane_parity![I16Vec2 => BVec2, I16Vec3 => BVec3, I16Vec4 => BVec4];
+    impl_glam_lane_parity![U16Vec2 => BVec2, U16Vec3 => BVec3, U16Vec4 => BVec4];
+    impl_glam_lane_parity![IVec2 => BVec2, IVec3 => BVec3, IVec4 => BVec4];
+    impl_glam_lane_parity![UVec2 => BVec2, UVec3 => BVec3, UVec4 => BVec4];
+    impl_glam_lane_parity![I64Vec2 => BVec2, I64Vec3 => BVec3, I64Vec4 => BVec4];
+    impl_glam_lane_parity![U64Vec2 => BVec2, U64Vec3 => BVec3, U64Vec4 => BVec4];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn wide_matches_scalar_parity() {
+        use crate::Parity;
+        use wide::*;
+
+        macro_rules! check_lanes {
+            ($($V:ty => $T:ty),*) => { $({
+                let v = <$V>::new(core::array::from_fn(|i| (i as $T).wrapping_mul(37).wrapping_sub(5)));
+                let values = v.to_array();
+                let (even, odd) = (v.is_even_lanes().to_array(), v.is_odd_lanes().to_array());
+                for (i, value) in values.iter().enumerate() {
+                    assert_eq!(even[i] != 0, value.is_even());
+                    assert_eq!(odd[i] != 0, value.is_odd());
+                    assert!(even[i] == 0 || even[i] == !0);
+                }
+                assert!((v + v).all_even());
+                assert!((v + v + <$V>::splat(1)).all_odd());
+                assert!(!v.all_even() && !v.all_odd());
+            })* };
+        }
+
+        check_lanes![i8x16 => i8, i8x64 => i8, i16x8 => i16, i32x4 => i32, i32x16 => i32, i64x2 => i64, i64x8 => i64];
+        check_lanes![u8x32 => u8, u16x16 => u16, u16x32 => u16, u32x8 => u32, u64x4 => u64];
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_matches_scalar_parity() {
+        use glam::*;
+
+        let v = IVec4::new(-3, -2, 0, i32::MAX);
+        assert_eq!(v.is_even_lanes(), BVec4::new(false, true, true, false));
+        assert_eq!(v.is_odd_lanes(), BVec4::new(true, false, false, true));
+        assert!(!v.all_even() && !v.all_odd());
+        assert!(U64Vec3::new(2, 0, u64::MAX - 1).all_even());
+        assert!(I8Vec2::new(i8::MIN + 1, -1).all_odd());
+        assert_eq!(UVec2::new(7, 8).is_odd_lanes().bitmask(), 0b01);
+        assert!(!I16Vec3::ZERO.all_odd());
+    }
+}
@@ -0,0 +1,116 @@
+//! Parity of memory layouts, for formats and interfaces that require even-sized or even-aligned records.
+//!
+//! # Example
+//! ```
+//! use core::alloc::Layout;
+//! use parity::layout::LayoutParity;
+//!
+//! let record = Layout::new::<[u8; 5]>();
+//! assert!(!record.is_even_sized());
+//! assert_eq!(record.pad_to_even_size().unwrap().size(), 6);
+//! ```
+
+use core::alloc::{Layout, LayoutError};
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// Parity helpers for [Layout].
+pub trait LayoutParity: Sized {
+    /// Returns the parity of the size.
+    fn size_parity(&self) -> ParityKind;
+
+    /// Returns `true` if the size is even.
+    #[inline]
+    fn is_even_sized(&self) -> bool {
+        self.size_parity().is_even()
+    }
+
+    /// Returns `true` if the alignment is even, that is at least two, so every allocation has an even address.
+    fn has_even_align(&self) -> bool;
+
+    /// Returns the layout with its size rounded up to the next even number, keeping the alignment.
+    ///
+    /// Fails if the rounded size overflows the limits of [Layout].
+    fn pad_to_even_size(&self) -> Result<Self, LayoutError>;
+}
+
+impl LayoutParity for Layout {
+    #[inline]
+    fn size_parity(&self) -> ParityKind {
+        self.size().parity()
+    }
+
+    #[inline]
+    fn has_even_align(&self) -> bool {
+        // alignments are powers of two, so only an alignment of one is odd
+        self.align() > 1
+    }
+
+    #[inline]
+    fn pad_to_even_size(&self) -> Result<Self, LayoutError> {
+        if self.is_even_sized() {
+            return Ok(*self);
+        }
+        // an alignment of at least two already makes the size even, so here the alignment is one
+        Layout::from_size_align(self.size().saturating_add(1), self.align())
+    }
+}
+
+/// Returns `true` if a record of `size` bytes aligned to `align` is even-sized and even-aligned.
+///
+/// # Example
+/// ```
+/// use parity::layout::is_even_size_align;
+///
+/// assert!(is_even_size_align(12, 4));
+/// assert!(!is_even_size_align(12, 1));
+/// assert!(!is_even_size_align(3, 2));
+/// ```
+#[inline]
+pub fn is_even_size_align(size: usize, align: usize) -> bool {
+    size.is_even() && align.is_even()
+}
+
+/// Returns `size` rounded up to the next even number, or `None` on overflow.
+///
+/// # Example
+/// ```
+/// use parity::layout::pad_size_to_even;
+///
+/// assert_eq!(pad_size_to_even(7), Some(8));
+/// assert_eq!(pad_size_to_even(8), Some(8));
+/// assert_eq!(pad_size_to_even(usize::MAX), None);
+/// ```
+#[inline]
+pub fn pad_size_to_even(size: usize) -> Option<usize> {
+    size.checked_add(size & 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layouts() {
+        let byte = Layout::new::<u8>();
+        assert_eq!(byte.size_parity(), ParityKind::Odd);
+        assert!(!byte.has_even_align());
+        let padded = byte.pad_to_even_size().unwrap();
+        assert_eq!((padded.size(), padded.align()), (2, 1));
+
+        let word = Layout::new::<u32>();
+        assert!(word.is_even_sized() && word.has_even_align());
+        assert_eq!(word.pad_to_even_size(), Ok(word));
+        assert!(Layout::new::<()>().is_even_sized());
+
+        let largest = Layout::from_size_align(isize::MAX as usize, 1).unwrap();
+        assert!(largest.pad_to_even_size().is_err());
+    }
+
+    #[test]
+    fn raw_sizes() {
+        assert!(is_even_size_align(0, 2));
+        assert_eq!(pad_size_to_even(0), Some(0));
+        assert_eq!(pad_size_to_even(usize::MAX - 1), Some(usize::MAX - 1));
+    }
+}
@@ -0,0 +1,121 @@
+//! Linear-feedback shift registers driven by tap parity.
+//!
+//! # Example
+//! A PRBS7 generator (x⁷ + x⁶ + 1):
+//! ```
+//! use parity::lfsr::{lfsr_step, LfsrKind};
+//!
+//! let mut state = 0x01u8;
+//! let mut period = 0;
+//! loop {
+//!     (state, _) = lfsr_step(state, 0x60, LfsrKind::Fibonacci);
+//!     period += 1;
+//!     if state == 0x01 {
+//!         break;
+//!     }
+//! }
+//! assert_eq!(period, 127);
+//! ```
+
+use crate::BitParity;
+
+/// The feedback arrangement of a shift register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LfsrKind {
+    /// The register shifts left and the parity of the tapped bits is fed back into bit 0.
+    ///
+    /// The width of the register is given by the highest set tap, so x⁷ + x⁶ + 1 is written as taps `0x60` and
+    /// keeps the state within 7 bits. The returned bit is the feedback bit.
+    Fibonacci,
+    /// The register shifts right, and when the bit shifted out is set the taps are XORed into the state.
+    ///
+    /// The returned bit is the bit shifted out of position 0.
+    Galois,
+}
+
+/// An unsigned integer usable as shift register state.
+pub trait LfsrRegister: BitParity + Copy {
+    /// Advances a Fibonacci register by one step. See [LfsrKind::Fibonacci].
+    fn fibonacci_step(self, taps: Self) -> (Self, bool);
+    /// Advances a Galois register by one step. See [LfsrKind::Galois].
+    fn galois_step(self, taps: Self) -> (Self, bool);
+}
+
+macro_rules! impl_lfsr_register {
+    ($($T:ty),*) => { $(
+        impl LfsrRegister for $T {
+            #[inline]
+            fn fibonacci_step(self, taps: Self) -> (Self, bool) {
+                let width = <$T>::BITS - taps.leading_zeros();
+                let mask = <$T>::MAX.checked_shr(<$T>::BITS - width).unwrap_or(0);
+                let feedback = self.parity_of_bits(taps).is_odd();
+                (((self << 1) | feedback as $T) & mask, feedback)
+            }
+
+            #[inline]
+            fn galois_step(self, taps: Self) -> (Self, bool) {
+                let out = self & 1;
+                ((self >> 1) ^ (taps & out.wrapping_neg()), out == 1)
+            }
+        }
+    )* };
+}
+
+impl_lfsr_register![u8, u16, u32, u64, u128, usize];
+
+/// Advances the shift register `state` with the given `taps` by one step, returning the new state and the output
+/// bit.
+#[inline]
+pub fn lfsr_step<T: LfsrRegister>(state: T, taps: T, kind: LfsrKind) -> (T, bool) {
+    match kind {
+        LfsrKind::Fibonacci => state.fibonacci_step(taps),
+        LfsrKind::Galois => state.galois_step(taps),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period<T: LfsrRegister + PartialEq>(seed: T, taps: T, kind: LfsrKind) -> u64 {
+        let mut state = seed;
+        let mut steps = 0;
+        loop {
+            state = lfsr_step(state, taps, kind).0;
+            steps += 1;
+            if state == seed {
+                return steps;
+            }
+        }
+    }
+
+    #[test]
+    fn maximal_length() {
+        assert_eq!(period(0x01u8, 0x60, LfsrKind::Fibonacci), 127);
+        assert_eq!(period(0xace1u16, 0xb400, LfsrKind::Galois), 65535);
+        assert_eq!(period(0x1u32, 0xb400, LfsrKind::Fibonacci), 65535);
+    }
+
+    #[test]
+    fn output_bits() {
+        // from an all-ones seed PRBS7 feeds back six zeros before the first one
+        let mut state = 0x7fu8;
+        let mut bits = 0u8;
+        for _ in 0..8 {
+            let (next, bit) = lfsr_step(state, 0x60, LfsrKind::Fibonacci);
+            state = next;
+            bits = (bits << 1) | bit as u8;
+        }
+        assert_eq!(bits, 0b0000_0010);
+
+        let (state, bit) = lfsr_step(0b1001u8, 0b1100, LfsrKind::Galois);
+        assert_eq!((state, bit), (0b1000, true));
+        assert_eq!(lfsr_step(0u64, 0b1100, LfsrKind::Galois), (0, false));
+    }
+
+    #[test]
+    fn full_width_register() {
+        let (state, bit) = lfsr_step(u8::MAX, 0x80, LfsrKind::Fibonacci);
+        assert_eq!((state, bit), (u8::MAX, true));
+    }
+}
@@ -1,16 +1,471 @@
+#![no_std]
 #![deny(missing_docs)]
+#![cfg_attr(
+    feature = "nightly",
+    feature(const_trait_impl, fn_traits, unboxed_closures)
+)]
 //! [Parity] is a trait for indicating whether a number is odd or even.
+//!
+//! The crate is `no_std`. Integer and float parity only depend on `core`, while allocating APIs require the `alloc`
+//! feature and approximate float parity requires `std` or `libm`.
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+pub mod array;
+#[cfg(feature = "alloc")]
+pub mod ascii;
+#[cfg(target_has_atomic = "8")]
+pub mod atomic;
+pub mod bcd;
+pub mod bits;
+#[cfg(feature = "into-i128")]
+pub mod blanket;
+pub mod checksum;
+pub mod combinators;
+mod consts;
+pub mod ct;
+#[cfg(feature = "time")]
+pub mod date;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod float;
+pub mod game;
+pub mod geom;
+#[cfg(feature = "alloc")]
+pub mod gf2;
+pub mod gray;
+pub mod guard;
+#[cfg(feature = "embedded-hal")]
+pub mod hal;
+mod impls;
+pub mod interlace;
+pub mod invariant;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod iter;
+mod kind;
+pub mod label;
+#[cfg(any(feature = "glam", feature = "wide"))]
+pub mod lanes;
+pub mod layout;
+pub mod lfsr;
+mod macros;
+pub mod math;
+#[cfg(feature = "net")]
+pub mod net;
+#[cfg(feature = "rayon")]
+pub mod par;
+#[cfg(feature = "alloc")]
+pub mod pattern;
+#[cfg(feature = "alloc")]
+pub mod perm;
+pub mod phase;
+pub mod predicate;
+pub mod prelude;
+pub mod ptr;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "rand")]
+pub mod random;
+mod range;
+pub mod select;
+pub mod seq;
+pub mod serial;
+pub mod shard;
+pub mod signal;
+#[cfg(feature = "alloc")]
+pub mod simd;
+pub mod slice;
+#[cfg(feature = "stats")]
+pub mod stats;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+pub mod testing;
+#[cfg(feature = "tracing")]
+mod trace;
+#[cfg(feature = "typenum")]
+pub mod type_level;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod window;
+mod wrapper;
+
+pub use bits::BitParity;
+pub use consts::*;
+pub use error::{LabelledParityError, ParityError};
+pub use float::FloatParity;
+pub use iter::{
+    AlternateSigns, Decimate, ParityCount, ParityIterator, ParityTransitions, WithParity,
+};
+#[cfg(feature = "rkyv")]
+pub use kind::ArchivedParityKind;
+pub use kind::{EvenClass, ParityKind, RoundingMode};
+#[cfg(feature = "derive")]
+pub use parity_derive::Parity;
+#[cfg(feature = "rkyv")]
+pub use wrapper::{ArchivedEven, ArchivedOdd};
+pub use wrapper::{Constrained, Even, Odd, ParityParam, EVEN_PARAM, ODD_PARAM};
 
 /// Provides an interface to check the evenness or oddness of a value.
 ///
 /// Implemented for all primitive numeric types. For integer types `self.is_even()` is equivalent to `!self.is_odd()`.
 /// For floating-point types, both [Parity::is_even] and [Parity::is_odd] also require that there is no fractional part
 /// in order to return true.
+///
+/// # Floating-point semantics
+/// - Finite integral values have the parity of the integer they represent, and both zeros, including `-0.0`, are
+///   even.
+/// - Subnormal values lie strictly between zero and one in magnitude, so they are fractional and have no parity.
+/// - Infinities and NaN have no parity.
+///
+/// Use [Parity::is_parity_defined] to tell values without a parity apart from values of the other parity.
+///
+/// # Trait objects
+/// `Parity` is dyn-compatible, so values of different types can be stored together as `&dyn Parity` or, with the
+/// `alloc` feature, `Box<dyn Parity>`, which implements `Parity` itself. The methods that take or return `Self` by
+/// value require `Self: Sized`, and are not available on trait objects.
+/// ```
+/// # #[cfg(feature = "alloc")] {
+/// use parity::Parity;
+///
+/// let readings: [Box<dyn Parity>; 3] = [Box::new(4u8), Box::new(-3i64), Box::new(2.5f32)];
+/// let defined: Vec<bool> = readings.iter().map(|r| r.is_parity_defined()).collect();
+/// assert_eq!(defined, [true, true, false]);
+/// let six: Box<dyn Parity> = Box::new(6u16);
+/// assert!(readings[0].same_parity(&six));
+/// # }
+/// ```
 pub trait Parity {
     /// Returns `true` if `self` is even, and false otherwise.
     fn is_even(&self) -> bool;
     /// Returns `true` if `self` is odd, and false otherwise.
     fn is_odd(&self) -> bool;
+
+    /// Returns `true` if `self` is either even or odd.
+    ///
+    /// This is always the case for integers. For floating-point values it is `false` for NaN, infinities and values
+    /// with a fractional part.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::Parity;
+    ///
+    /// assert!(7u8.is_parity_defined());
+    /// assert!((-0.0f64).is_parity_defined());
+    /// assert!(!f64::INFINITY.is_parity_defined());
+    /// assert!(!0.5f32.is_parity_defined());
+    /// ```
+    #[inline]
+    fn is_parity_defined(&self) -> bool {
+        self.is_even() || self.is_odd()
+    }
+
+    /// Returns the parity of `self`, or `None` if it is neither even nor odd.
+    #[inline]
+    fn checked_parity(&self) -> Option<ParityKind> {
+        if self.is_even() {
+            Some(ParityKind::Even)
+        } else if self.is_odd() {
+            Some(ParityKind::Odd)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `self` and `other` are both even or both odd.
+    #[inline]
+    fn same_parity(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.checked_same_parity(other) == Some(true)
+    }
+
+    /// Returns `true` if one of `self` and `other` is even and the other is odd.
+    #[inline]
+    fn opposite_parity(&self, other: &Self) -> bool
+    where
+        Self: Sized,
+    {
+        self.checked_same_parity(other) == Some(false)
+    }
+
+    /// Returns whether `self` and `other` have the same parity, or `None` if either of them has no parity.
+    #[inline]
+    fn checked_same_parity(&self, other: &Self) -> Option<bool>
+    where
+        Self: Sized,
+    {
+        Some(self.checked_parity()? == other.checked_parity()?)
+    }
+
+    /// Returns `self` if it is even, and a [ParityError] carrying it otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{Parity, ParityError};
+    ///
+    /// fn half_width(width: u32) -> Result<u32, ParityError<u32>> {
+    ///     Ok(width.ensure_even()? / 2)
+    /// }
+    ///
+    /// assert_eq!(half_width(640), Ok(320));
+    /// assert_eq!(half_width(641).unwrap_err().into_value(), 641);
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    fn ensure_even(self) -> Result<Self, ParityError<Self>>
+    where
+        Self: Sized,
+    {
+        if self.is_even() {
+            Ok(self)
+        } else {
+            #[cfg(feature = "tracing")]
+            trace::wrong_parity::<Self>(ParityKind::Even);
+            Err(ParityError::new(self, ParityKind::Even))
+        }
+    }
+
+    /// Returns `self` if it is odd, and a [ParityError] carrying it otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::{Parity, ParityKind};
+    ///
+    /// assert_eq!(7.ensure_odd(), Ok(7));
+    /// let err = 1.5.ensure_odd().unwrap_err();
+    /// assert_eq!(err.expected(), ParityKind::Odd);
+    /// assert_eq!(err.to_string(), "expected an odd value, found 1.5");
+    /// ```
+    #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    fn ensure_odd(self) -> Result<Self, ParityError<Self>>
+    where
+        Self: Sized,
+    {
+        if self.is_odd() {
+            Ok(self)
+        } else {
+            #[cfg(feature = "tracing")]
+            trace::wrong_parity::<Self>(ParityKind::Odd);
+            Err(ParityError::new(self, ParityKind::Odd))
+        }
+    }
+
+    /// Returns `self` if it is even.
+    ///
+    /// # Panics
+    /// Panics if `self` is not even, with a message containing `msg` and the value. The reported location is that of
+    /// the caller.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use parity::Parity;
+    ///
+    /// // panics with "row stride: expected an even value, found 3"
+    /// let stride = 3u32.expect_even("row stride");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn expect_even(self, msg: &str) -> Self
+    where
+        Self: Sized + core::fmt::Debug,
+    {
+        match self.ensure_even() {
+            Ok(value) => value,
+            Err(err) => panic!("{msg}: {err}"),
+        }
+    }
+
+    /// Returns `self` if it is odd.
+    ///
+    /// # Panics
+    /// Panics if `self` is not odd, with a message containing `msg` and the value. The reported location is that of
+    /// the caller.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::Parity;
+    ///
+    /// assert_eq!(5i8.expect_odd("window size must be odd"), 5);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn expect_odd(self, msg: &str) -> Self
+    where
+        Self: Sized + core::fmt::Debug,
+    {
+        match self.ensure_odd() {
+            Ok(value) => value,
+            Err(err) => panic!("{msg}: {err}"),
+        }
+    }
+
+    /// Returns `f(self)` if `self` is even, and `self` unchanged otherwise.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::Parity;
+    ///
+    /// // halve the even widths of a list of frames
+    /// let widths: Vec<u32> = [640, 641, 1280].into_iter().map(|w| w.map_even(|w| w / 2)).collect();
+    /// assert_eq!(widths, [320, 641, 640]);
+    /// ```
+    ///
+    /// Chaining `map_even` and [map_odd](Parity::map_odd) applies the second closure to the result of the first, so
+    /// an odd result of the even branch is mapped again; branch on the parity of the input for a step such as Collatz.
+    #[inline]
+    fn map_even<F: FnOnce(Self) -> Self>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_even() {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Returns `f(self)` if `self` is odd, and `self` unchanged otherwise.
+    ///
+    /// Values without a parity, such as fractional floats, are never odd, so they are returned unchanged.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::Parity;
+    ///
+    /// // round an index down to the start of its pair
+    /// assert_eq!(7usize.map_odd(|i| i - 1), 6);
+    /// assert_eq!(1.5f64.map_odd(|x| x - 1.0), 1.5);
+    /// ```
+    #[inline]
+    fn map_odd<F: FnOnce(Self) -> Self>(self, f: F) -> Self
+    where
+        Self: Sized,
+    {
+        if self.is_odd() {
+            f(self)
+        } else {
+            self
+        }
+    }
+}
+
+// Fails to compile if a method is added to `Parity` that makes it no longer dyn-compatible.
+const _: Option<&dyn Parity> = None;
+
+macro_rules! impl_boxed_parity {
+    ($($Dyn:ty),*) => { $(
+        /// Delegates to the boxed value, so trait objects of different types can be compared with
+        /// [Parity::same_parity].
+        #[cfg(feature = "alloc")]
+        impl Parity for alloc::boxed::Box<$Dyn> {
+            #[inline]
+            fn is_even(&self) -> bool {
+                (**self).is_even()
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                (**self).is_odd()
+            }
+
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                (**self).is_parity_defined()
+            }
+
+            #[inline]
+            fn checked_parity(&self) -> Option<ParityKind> {
+                (**self).checked_parity()
+            }
+        }
+    )* };
+}
+
+impl_boxed_parity![
+    dyn Parity + '_,
+    dyn Parity + Send + '_,
+    dyn Parity + Send + Sync + '_
+];
+
+/// Provides the parity of integer types, where every value is either even or odd.
+///
+/// Implemented for all primitive integer types.
+pub trait IntegerParity: Parity {
+    /// Returns the parity of `self`.
+    fn parity(&self) -> ParityKind;
+
+    /// Returns how even `self` is: the exponent of the largest power of two dividing it.
+    ///
+    /// Odd values have a valuation of zero. Zero is divisible by every power of two, so its valuation is `None`.
+    fn two_adic_valuation(&self) -> Option<u32>;
+
+    /// Returns `true` if `self` is divisible by `2^k`.
+    ///
+    /// Zero is divisible by every power of two, including those that overflow the type.
+    #[inline]
+    fn is_multiple_of_pow2(&self, k: u32) -> bool {
+        self.two_adic_valuation()
+            .is_none_or(|valuation| valuation >= k)
+    }
+
+    /// Returns whether `self` is odd, singly even or doubly even.
+    #[inline]
+    fn even_class(&self) -> EvenClass {
+        match self.two_adic_valuation() {
+            Some(0) => EvenClass::Odd,
+            Some(1) => EvenClass::SinglyEven,
+            _ => EvenClass::DoublyEven,
+        }
+    }
+
+    /// Returns `true` if `self` is divisible by four.
+    #[inline]
+    fn is_doubly_even(&self) -> bool {
+        self.even_class() == EvenClass::DoublyEven
+    }
+
+    /// Returns `true` if `self` is even but not divisible by four.
+    #[inline]
+    fn is_singly_even(&self) -> bool {
+        self.even_class() == EvenClass::SinglyEven
+    }
+
+    /// Returns half of `self` if it is even, and `None` if halving would need rounding.
+    fn half_exact(&self) -> Option<Self>
+    where
+        Self: Sized;
+
+    /// Returns half of `self`, rounding odd values according to `mode`.
+    ///
+    /// Halving never overflows, so every mode is defined for the whole range of the type.
+    fn div2(&self, mode: RoundingMode) -> Self
+    where
+        Self: Sized;
+
+    /// Returns `if_even` if `self` is even and `if_odd` if it is odd, selecting with a bit mask instead of a branch.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::IntegerParity;
+    ///
+    /// // alternating weights for Simpson's rule, without a branch in the loop
+    /// let weights: Vec<f64> = (1..6).map(|i: u32| i.if_even_else(2.0, 4.0)).collect();
+    /// assert_eq!(weights, [4.0, 2.0, 4.0, 2.0, 4.0]);
+    /// ```
+    #[inline]
+    fn if_even_else<V: select::MaskSelect>(&self, if_even: V, if_odd: V) -> V
+    where
+        Self: Sized,
+    {
+        V::mask_select(self.is_odd(), if_even, if_odd)
+    }
 }
 
 macro_rules! impl_parity {
@@ -43,12 +498,114 @@ macro_rules! impl_parity {
             fn is_odd(&self) -> bool {
                 *self & 1 != 0
             }
+
+            /// Returns `true`, as every integer is either even or odd.
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                true
+            }
+
+            /// Returns `true` if `self` and `other` are both even or both odd.
+            /// # Example
+            /// ```
+            /// use parity::Parity;
+            #[doc = concat!("assert!(3", stringify!($T), ".same_parity(&5));")]
+            #[doc = concat!("assert!(!3", stringify!($T), ".same_parity(&4));")]
+            /// ```
+            #[inline]
+            fn same_parity(&self, other: &Self) -> bool {
+                (*self ^ *other) & 1 == 0
+            }
+
+            /// Returns `true` if one of `self` and `other` is even and the other is odd.
+            /// # Example
+            /// ```
+            /// use parity::Parity;
+            #[doc = concat!("assert!(3", stringify!($T), ".opposite_parity(&4));")]
+            #[doc = concat!("assert!(!3", stringify!($T), ".opposite_parity(&5));")]
+            /// ```
+            #[inline]
+            fn opposite_parity(&self, other: &Self) -> bool {
+                (*self ^ *other) & 1 != 0
+            }
+
+            /// Returns whether `self` and `other` have the same parity, which is never `None` for integers.
+            #[inline]
+            fn checked_same_parity(&self, other: &Self) -> Option<bool> {
+                Some(self.same_parity(other))
+            }
+        }
+
+        impl IntegerParity for $T {
+            /// Returns the parity of `self`.
+            /// # Example
+            /// ```
+            /// use parity::{IntegerParity, ParityKind};
+            #[doc = concat!("assert_eq!(2", stringify!($T), ".parity(), ParityKind::Even);")]
+            #[doc = concat!("assert_eq!(3", stringify!($T), ".parity(), ParityKind::Odd);")]
+            /// ```
+            #[inline]
+            fn parity(&self) -> ParityKind {
+                ParityKind::from_odd(self.is_odd())
+            }
+
+            /// Returns the exponent of the largest power of two dividing `self`, or `None` for zero.
+            /// # Example
+            /// ```
+            /// use parity::IntegerParity;
+            #[doc = concat!("assert_eq!(12", stringify!($T), ".two_adic_valuation(), Some(2));")]
+            #[doc = concat!("assert_eq!(0", stringify!($T), ".two_adic_valuation(), None);")]
+            /// ```
+            #[inline]
+            fn two_adic_valuation(&self) -> Option<u32> {
+                if *self == 0 {
+                    None
+                } else {
+                    Some(self.trailing_zeros())
+                }
+            }
+
+            /// Returns half of `self` if it is even, and `None` otherwise.
+            /// # Example
+            /// ```
+            /// use parity::IntegerParity;
+            #[doc = concat!("assert_eq!(10", stringify!($T), ".half_exact(), Some(5));")]
+            #[doc = concat!("assert_eq!(11", stringify!($T), ".half_exact(), None);")]
+            /// ```
+            #[inline]
+            fn half_exact(&self) -> Option<Self> {
+                if self.is_even() {
+                    Some(*self / 2)
+                } else {
+                    None
+                }
+            }
+
+            /// Returns half of `self`, rounding odd values according to `mode`.
+            /// # Example
+            /// ```
+            /// use parity::{IntegerParity, RoundingMode};
+            #[doc = concat!("assert_eq!(7", stringify!($T), ".div2(RoundingMode::Floor), 3);")]
+            #[doc = concat!("assert_eq!(7", stringify!($T), ".div2(RoundingMode::Ceil), 4);")]
+            /// ```
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div2(&self, mode: RoundingMode) -> Self {
+                let negative = *self < 0;
+                match mode {
+                    RoundingMode::Floor => *self >> 1,
+                    RoundingMode::Ceil => (*self >> 1) + (*self & 1),
+                    RoundingMode::TowardZero => *self / 2,
+                    RoundingMode::AwayFromZero if negative => *self >> 1,
+                    RoundingMode::AwayFromZero => (*self >> 1) + (*self & 1),
+                }
+            }
         }
     )* };
 }
 
 macro_rules! impl_float_parity {
-    ($($T:ty),*) => { $(
+    ($($T:ty => $U:ty),*) => { $(
         impl Parity for $T {
             /// Returns `true` if `self` is even and has no fractional part.
             /// # Example
@@ -60,7 +617,7 @@ macro_rules! impl_float_parity {
             /// ```
             #[inline]
             fn is_even(&self) -> bool {
-                self.fract() == 0.0 && *self % 2.0 == 0.0
+                self.checked_parity() == Some(ParityKind::Even)
             }
 
             /// Returns `true` if `self` is odd and has no fractional part.
@@ -72,7 +629,63 @@ macro_rules! impl_float_parity {
             /// ```
             #[inline]
             fn is_odd(&self) -> bool {
-                self.fract() == 0.0 && *self % 2.0 != 0.0
+                self.checked_parity() == Some(ParityKind::Odd)
+            }
+
+            /// Returns `true` if `self` is finite and has no fractional part.
+            /// # Example
+            /// ```
+            /// use parity::Parity;
+            #[doc = concat!("assert!((-0.0", stringify!($T), ").is_parity_defined());")]
+            #[doc = concat!("assert!(!", stringify!($T), "::NEG_INFINITY.is_parity_defined());")]
+            #[doc = concat!("assert!(!", stringify!($T), "::NAN.is_parity_defined());")]
+            /// ```
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                self.checked_parity().is_some()
+            }
+
+            /// Returns the parity of `self`, or `None` if it is NaN, infinite or has a fractional part.
+            ///
+            /// The value is classified from its exponent and significand bits in a single pass.
+            /// # Example
+            /// ```
+            /// use parity::{Parity, ParityKind};
+            #[doc = concat!("assert_eq!(6.0", stringify!($T), ".checked_parity(), Some(ParityKind::Even));")]
+            #[doc = concat!("assert_eq!(", stringify!($T), "::MAX.checked_parity(), Some(ParityKind::Even));")]
+            #[doc = concat!("assert_eq!(0.5", stringify!($T), ".checked_parity(), None);")]
+            /// ```
+            #[inline]
+            fn checked_parity(&self) -> Option<ParityKind> {
+                const FRACTION_BITS: u32 = <$T>::MANTISSA_DIGITS - 1;
+                const EXPONENT_MASK: $U = (1 << (<$U>::BITS - 1 - FRACTION_BITS)) - 1;
+                const BIAS: i32 = <$T>::MAX_EXP - 1;
+
+                let bits = self.to_bits();
+                let biased = ((bits >> FRACTION_BITS) & EXPONENT_MASK) as i32;
+                let fraction = bits & ((1 << FRACTION_BITS) - 1);
+                if biased == EXPONENT_MASK as i32 {
+                    // infinities and NaN
+                    return None;
+                }
+                if biased == 0 {
+                    // zeros are even, subnormals are fractional
+                    return (fraction == 0).then_some(ParityKind::Even);
+                }
+                let exponent = biased - BIAS;
+                if exponent < 0 {
+                    return None;
+                }
+                if exponent > FRACTION_BITS as i32 {
+                    // the least significant bit of the significand is worth at least two
+                    return Some(ParityKind::Even);
+                }
+                let significand = fraction | (1 << FRACTION_BITS);
+                let shift = FRACTION_BITS - exponent as u32;
+                if significand & ((1 << shift) - 1) != 0 {
+                    return None;
+                }
+                Some(ParityKind::from_odd((significand >> shift) & 1 == 1))
             }
         }
     )* };
@@ -80,7 +693,36 @@ macro_rules! impl_float_parity {
 
 impl_parity![i8, i16, i32, i64, i128, isize];
 impl_parity![u8, u16, u32, u64, u128, usize];
-impl_float_parity![f32, f64];
+impl_float_parity![f32 => u32, f64 => u64];
+
+/// Returns `true` if `x` is even. Equivalent to [Parity::is_even], but usable as a function path without importing
+/// the trait.
+///
+/// # Example
+/// ```
+/// let values = [3, 5, 8, 9];
+/// assert_eq!(values.iter().position(parity::is_even), Some(2));
+/// let evens: Vec<_> = (0..10).filter(parity::is_even).collect();
+/// assert_eq!(evens, [0, 2, 4, 6, 8]);
+/// ```
+#[inline]
+pub fn is_even<T: Parity + ?Sized>(x: &T) -> bool {
+    x.is_even()
+}
+
+/// Returns `true` if `x` is odd. Equivalent to [Parity::is_odd], but usable as a function path without importing
+/// the trait.
+///
+/// # Example
+/// ```
+/// let values = [2.0, 4.5, 7.0];
+/// assert_eq!(values.into_iter().filter(parity::is_odd).count(), 1);
+/// assert!([1u8, 3, 5].iter().all(parity::is_odd));
+/// ```
+#[inline]
+pub fn is_odd<T: Parity + ?Sized>(x: &T) -> bool {
+    x.is_odd()
+}
 
 #[cfg(test)]
 mod tests {
@@ -134,4 +776,215 @@ mod tests {
         assert!(!f64::NAN.is_even());
         assert!(!f64::NAN.is_odd());
     }
+
+    #[test]
+    fn comparisons() {
+        assert!((-3i32).same_parity(&7));
+        assert!(i64::MIN.opposite_parity(&i64::MAX));
+        assert_eq!(2u8.checked_same_parity(&3), Some(false));
+
+        assert!(2.0.same_parity(&-4.0));
+        assert!(1.0.opposite_parity(&2.0));
+        assert!(!1.5.same_parity(&1.5));
+        assert!(!1.5.opposite_parity(&2.0));
+        assert_eq!(f32::NAN.checked_same_parity(&2.0), None);
+        assert_eq!(3.0f32.checked_same_parity(&5.0), Some(true));
+
+        assert_eq!(7u16.checked_parity(), Some(ParityKind::Odd));
+        assert_eq!(0.5f64.checked_parity(), None);
+    }
+
+    #[test]
+    fn valuations() {
+        assert_eq!(1u8.two_adic_valuation(), Some(0));
+        assert_eq!(u128::MAX.two_adic_valuation(), Some(0));
+        assert_eq!((-8i32).two_adic_valuation(), Some(3));
+        assert_eq!(i64::MIN.two_adic_valuation(), Some(63));
+
+        assert!(48u32.is_multiple_of_pow2(4));
+        assert!(!48u32.is_multiple_of_pow2(5));
+        assert!(7u32.is_multiple_of_pow2(0));
+        assert!(0u8.is_multiple_of_pow2(200));
+    }
+
+    #[test]
+    fn even_classes() {
+        let classes: std::vec::Vec<_> = (-4i32..=4).map(|x| x.even_class()).collect();
+        use EvenClass::*;
+        assert_eq!(
+            classes,
+            [DoublyEven, Odd, SinglyEven, Odd, DoublyEven, Odd, SinglyEven, Odd, DoublyEven]
+        );
+        assert!(6u8.is_singly_even());
+        assert!(!6u8.is_doubly_even());
+        assert!(i8::MIN.is_doubly_even());
+        assert_eq!(6u8.even_class().parity(), ParityKind::Even);
+    }
+
+    #[test]
+    fn halving() {
+        use RoundingMode::*;
+        let halves = |x: i32| [Floor, Ceil, TowardZero, AwayFromZero].map(|mode| x.div2(mode));
+        assert_eq!(halves(7), [3, 4, 3, 4]);
+        assert_eq!(halves(-7), [-4, -3, -3, -4]);
+        assert_eq!(halves(-8), [-4; 4]);
+        assert_eq!(
+            halves(i32::MAX),
+            [
+                i32::MAX / 2,
+                i32::MAX / 2 + 1,
+                i32::MAX / 2,
+                i32::MAX / 2 + 1
+            ]
+        );
+        assert_eq!(halves(i32::MIN), [i32::MIN / 2; 4]);
+        assert_eq!(u8::MAX.div2(Ceil), 128);
+        assert_eq!(u8::MAX.div2(TowardZero), 127);
+
+        assert_eq!((-6i8).half_exact(), Some(-3));
+        assert_eq!(i8::MIN.half_exact(), Some(-64));
+        assert_eq!((-7i8).half_exact(), None);
+    }
+
+    #[test]
+    fn ensure() {
+        assert_eq!(4u8.ensure_even(), Ok(4));
+        let err = (-3i32).ensure_even().unwrap_err();
+        assert_eq!(*err.value(), -3);
+        assert_eq!(err.expected(), ParityKind::Even);
+        assert_eq!(std::format!("{err}"), "expected an even value, found -3");
+        assert!(f64::NAN.ensure_even().is_err());
+        assert!(f64::NAN.ensure_odd().is_err());
+        assert_eq!(2.0.ensure_odd().map_err(ParityError::into_value), Err(2.0));
+    }
+
+    #[test]
+    fn expect() {
+        assert_eq!(8u64.expect_even("even"), 8);
+        assert_eq!((-1.0f32).expect_odd("odd"), -1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "frame length: expected an odd value, found 10")]
+    fn expect_panics() {
+        10usize.expect_odd("frame length");
+    }
+
+    #[test]
+    fn float_edge_cases() {
+        assert!((-0.0f64).is_even());
+        assert!((-0.0f32).is_even());
+        assert_eq!((-0.0f64).checked_parity(), Some(ParityKind::Even));
+        for x in [f64::INFINITY, f64::NEG_INFINITY, f64::NAN] {
+            assert!(!x.is_even() && !x.is_odd());
+            assert!(!x.is_parity_defined());
+        }
+        for x in [f64::MIN_POSITIVE / 2.0, -f64::MIN_POSITIVE, f64::EPSILON] {
+            assert!(!x.is_parity_defined());
+            assert_eq!(x.checked_parity(), None);
+        }
+        assert!(f32::MAX.is_even());
+        assert!(f32::MIN.is_parity_defined());
+        assert!(u8::MAX.is_parity_defined());
+    }
+
+    #[test]
+    fn float_bits_match_arithmetic() {
+        fn reference(x: f64) -> Option<ParityKind> {
+            if x.fract() != 0.0 || x.is_nan() {
+                None
+            } else {
+                Some(ParityKind::from_odd(x % 2.0 != 0.0))
+            }
+        }
+
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for _ in 0..100_000 {
+            let bits = next();
+            let wide = f64::from_bits(bits);
+            assert_eq!(wide.checked_parity(), reference(wide), "{wide:e}");
+            let narrow = f32::from_bits(bits as u32);
+            assert_eq!(
+                narrow.checked_parity(),
+                reference(narrow as f64),
+                "{narrow:e}"
+            );
+            let small = (bits >> 40) as f64 / 4.0;
+            assert_eq!(small.checked_parity(), reference(small), "{small}");
+        }
+        for x in [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            2.0,
+            0.5,
+            1.5,
+            4503599627370495.0,
+            9007199254740991.0,
+            9007199254740992.0,
+        ] {
+            assert_eq!(x.checked_parity(), reference(x), "{x}");
+        }
+    }
+
+    #[test]
+    fn free_functions() {
+        assert!(is_even(&4u8) && !is_even(&-3i64) && !is_even(&0.5f32));
+        assert!(is_odd(&-3i64) && !is_odd(&f64::NAN));
+        assert_eq!((1..=6).filter(is_odd).count(), 3);
+    }
+
+    #[test]
+    fn if_even_else() {
+        for x in -4i32..4 {
+            let expected = if x % 2 == 0 { 'e' as u32 } else { 'o' as u32 };
+            assert_eq!(x.if_even_else('e' as u32, 'o' as u32), expected);
+        }
+        assert_eq!(u128::MAX.if_even_else(-1.0, 1.0), 1.0);
+        assert_eq!(0u8.if_even_else(i64::MIN, i64::MAX), i64::MIN);
+    }
+
+    #[test]
+    fn conditional_maps() {
+        assert_eq!(10u32.map_even(|x| x / 2), 5);
+        assert_eq!(11u32.map_even(|x| x / 2), 11);
+        assert_eq!((-3i8).map_odd(|x| x + 1), -2);
+        assert_eq!((-4i8).map_odd(|x| x + 1), -4);
+        assert_eq!(4.0f32.map_even(|x| x * 0.5), 2.0);
+        assert!(f64::NAN.map_even(|_| 0.0).is_nan());
+        assert!(f64::NAN.map_odd(|_| 0.0).is_nan());
+    }
+
+    #[test]
+    fn trait_objects() {
+        let values: [&dyn Parity; 4] = [&2u8, &-7i32, &0.5f64, &Even::<u64>::ZERO];
+        let parities: std::vec::Vec<_> = values.iter().map(|v| v.checked_parity()).collect();
+        assert_eq!(
+            parities,
+            [
+                Some(ParityKind::Even),
+                Some(ParityKind::Odd),
+                None,
+                Some(ParityKind::Even)
+            ]
+        );
+        assert!(values[1].is_odd() && !values[2].is_parity_defined());
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn boxed_trait_objects() {
+        let boxed: std::boxed::Box<dyn Parity + Send + Sync> = std::boxed::Box::new(9u16);
+        let other: std::boxed::Box<dyn Parity + Send + Sync> = std::boxed::Box::new(-1.0f32);
+        assert!(boxed.same_parity(&other));
+        assert!(other.ensure_even().is_err());
+        assert!(boxed.ensure_odd().is_ok_and(|b| b.is_odd()));
+    }
 }
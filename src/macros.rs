@@ -0,0 +1,469 @@
+//! Assertion macros.
+
+/// Asserts that an expression is even, using [Parity](crate::Parity).
+///
+/// On failure the panic message includes the expression and its value, which must implement [Debug]. An optional
+/// message can be given with the same syntax as [assert!].
+///
+/// # Example
+/// ```
+/// use parity::assert_even;
+///
+/// let len = 4;
+/// assert_even!(len);
+/// assert_even!(len * 3, "record length must be a whole number of words");
+/// ```
+///
+/// ```should_panic
+/// # use parity::assert_even;
+/// assert_even!(3); // assertion `3.is_even()` failed
+/// ```
+#[macro_export]
+macro_rules! assert_even {
+    ($value:expr $(,)?) => {
+        match &$value {
+            value => {
+                if !$crate::Parity::is_even(value) {
+                    ::core::panic!(
+                        "assertion `{}.is_even()` failed\n value: {:?}",
+                        ::core::stringify!($value),
+                        value,
+                    );
+                }
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                if !$crate::Parity::is_even(value) {
+                    ::core::panic!(
+                        "assertion `{}.is_even()` failed: {}\n value: {:?}",
+                        ::core::stringify!($value),
+                        ::core::format_args!($($arg)+),
+                        value,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an expression is odd, using [Parity](crate::Parity).
+///
+/// On failure the panic message includes the expression and its value, which must implement [Debug]. An optional
+/// message can be given with the same syntax as [assert!].
+///
+/// # Example
+/// ```
+/// use parity::assert_odd;
+///
+/// let side = 7;
+/// assert_odd!(side, "grid side {} must have a center cell", side);
+/// ```
+#[macro_export]
+macro_rules! assert_odd {
+    ($value:expr $(,)?) => {
+        match &$value {
+            value => {
+                if !$crate::Parity::is_odd(value) {
+                    ::core::panic!(
+                        "assertion `{}.is_odd()` failed\n value: {:?}",
+                        ::core::stringify!($value),
+                        value,
+                    );
+                }
+            }
+        }
+    };
+    ($value:expr, $($arg:tt)+) => {
+        match &$value {
+            value => {
+                if !$crate::Parity::is_odd(value) {
+                    ::core::panic!(
+                        "assertion `{}.is_odd()` failed: {}\n value: {:?}",
+                        ::core::stringify!($value),
+                        ::core::format_args!($($arg)+),
+                        value,
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that an expression is even in debug builds only. See [assert_even!].
+///
+/// Like [debug_assert!], the check is compiled out unless `debug_assertions` are enabled, so the expression is not
+/// evaluated in release builds.
+///
+/// # Example
+/// ```
+/// use parity::debug_assert_even;
+///
+/// for offset in (0..64).step_by(2) {
+///     debug_assert_even!(offset, "offset must stay word aligned");
+/// }
+/// ```
+#[macro_export]
+macro_rules! debug_assert_even {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_even!($($arg)*);
+        }
+    };
+}
+
+/// Asserts that an expression is odd in debug builds only. See [assert_odd!].
+///
+/// Like [debug_assert!], the check is compiled out unless `debug_assertions` are enabled, so the expression is not
+/// evaluated in release builds.
+#[macro_export]
+macro_rules! debug_assert_odd {
+    ($($arg:tt)*) => {
+        if ::core::cfg!(debug_assertions) {
+            $crate::assert_odd!($($arg)*);
+        }
+    };
+}
+
+/// Fails compilation if a constant integer expression is odd.
+///
+/// The check runs during const evaluation, so it can validate configuration constants such as buffer sizes. It may
+/// be used wherever an item is allowed, and accepts an optional string literal to use as the error message.
+///
+/// # Example
+/// ```
+/// use parity::static_assert_even;
+///
+/// const WORDS: usize = 16;
+/// static_assert_even!(WORDS * 2);
+/// static_assert_even!(WORDS, "the FIFO is split into two halves");
+/// ```
+///
+/// ```compile_fail
+/// # use parity::static_assert_even;
+/// const LEN: u32 = 7;
+/// static_assert_even!(LEN); // error: `LEN` is odd
+/// ```
+#[macro_export]
+macro_rules! static_assert_even {
+    ($value:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            $value % 2 == 0,
+            ::core::concat!("`", ::core::stringify!($value), "` is odd"),
+        );
+    };
+    ($value:expr, $message:literal $(,)?) => {
+        const _: () = ::core::assert!($value % 2 == 0, $message);
+    };
+}
+
+/// Evaluates a constant integer expression at compile time, failing compilation if it is odd and otherwise
+/// expanding to its value.
+///
+/// Usable anywhere a constant is, including array lengths, so buffers built with it are even-sized by construction.
+///
+/// # Example
+/// ```
+/// use parity::even;
+///
+/// const CHUNK: usize = 3;
+/// const N: usize = 4;
+/// let buffer = [0u8; even!(N * CHUNK)];
+/// assert_eq!(buffer.len(), 12);
+/// ```
+///
+/// ```compile_fail
+/// # use parity::even;
+/// let buffer = [0u8; even!(3 * 5)]; // error: `3 * 5` is odd
+/// ```
+#[macro_export]
+macro_rules! even {
+    ($value:expr $(,)?) => {
+        const {
+            let value = $value;
+            ::core::assert!(
+                value % 2 == 0,
+                ::core::concat!("`", ::core::stringify!($value), "` is odd"),
+            );
+            value
+        }
+    };
+}
+
+/// Evaluates a constant integer expression at compile time and expands to its [ParityKind](crate::ParityKind).
+///
+/// The result is a constant, so it can initialize other constants and statics, and constants defined with it can be
+/// used as match patterns.
+///
+/// # Example
+/// ```
+/// use parity::{parity_of, ParityKind};
+///
+/// const LANES: usize = 6;
+/// const LANE_PARITY: ParityKind = parity_of!(LANES / 2);
+///
+/// let layout = match parity_of!(LANES) {
+///     LANE_PARITY => "symmetric halves",
+///     _ => "centered halves",
+/// };
+/// assert_eq!(LANE_PARITY, ParityKind::Odd);
+/// assert_eq!(layout, "centered halves");
+/// ```
+#[macro_export]
+macro_rules! parity_of {
+    ($value:expr $(,)?) => {
+        const { $crate::ParityKind::from_odd($value % 2 != 0) }
+    };
+}
+
+/// Builds an array of consecutive even numbers at compile time.
+///
+/// `even_table!([T; N])` expands to a constant `[T; N]` holding the first `N` even numbers from zero. An optional
+/// `start` sets the first entry and an optional `step` the difference between neighbours; each entry is `step` more
+/// than the one before it, so a negative `step` counts down. Compilation fails if `start` or `step` is odd, or if an
+/// entry overflows `T`.
+///
+/// # Example
+/// ```
+/// use parity::even_table;
+///
+/// static OFFSETS: [u16; 4] = even_table!([u16; 4]);
+/// assert_eq!(OFFSETS, [0, 2, 4, 6]);
+/// assert_eq!(even_table!([i8; 3], start = 10, step = -6), [10, 4, -2]);
+/// ```
+///
+/// ```compile_fail
+/// # use parity::even_table;
+/// let table = even_table!([u8; 3], start = 1); // error: the start of an even table must be even
+/// ```
+#[macro_export]
+macro_rules! even_table {
+    ([$T:ty; $N:expr] $(, start = $start:expr)? $(, step = $step:expr)? $(,)?) => {
+        const {
+            #[allow(unused_variables)]
+            let start: $T = 0;
+            $(let start: $T = $start;)?
+            #[allow(unused_variables)]
+            let step: $T = 2;
+            $(let step: $T = $step;)?
+            ::core::assert!(start % 2 == 0, "the start of an even table must be even");
+            ::core::assert!(step % 2 == 0, "the step of a parity table must be even");
+            let mut table: [$T; $N] = [start; $N];
+            let mut i = 1;
+            while i < table.len() {
+                table[i] = table[i - 1] + step;
+                i += 1;
+            }
+            table
+        }
+    };
+}
+
+/// Builds an array of consecutive odd numbers at compile time.
+///
+/// `odd_table!([T; N])` expands to a constant `[T; N]` holding the first `N` odd numbers from one. The optional
+/// `start` and `step` work as for [even_table!], and compilation fails if `start` is even, `step` is odd, or an
+/// entry overflows `T`.
+///
+/// # Example
+/// ```
+/// use parity::odd_table;
+///
+/// const KERNEL_SIZES: [usize; 4] = odd_table!([usize; 4], start = 3);
+/// assert_eq!(KERNEL_SIZES, [3, 5, 7, 9]);
+/// assert_eq!(odd_table!([i32; 3], start = -1, step = 4), [-1, 3, 7]);
+/// ```
+#[macro_export]
+macro_rules! odd_table {
+    ([$T:ty; $N:expr] $(, start = $start:expr)? $(, step = $step:expr)? $(,)?) => {
+        const {
+            #[allow(unused_variables)]
+            let start: $T = 1;
+            $(let start: $T = $start;)?
+            #[allow(unused_variables)]
+            let step: $T = 2;
+            $(let step: $T = $step;)?
+            ::core::assert!(start % 2 != 0, "the start of an odd table must be odd");
+            ::core::assert!(step % 2 == 0, "the step of a parity table must be even");
+            let mut table: [$T; $N] = [start; $N];
+            let mut i = 1;
+            while i < table.len() {
+                table[i] = table[i - 1] + step;
+                i += 1;
+            }
+            table
+        }
+    };
+}
+
+/// Implements [Parity](crate::Parity) for wrapper types by delegating to an inner value.
+///
+/// Each entry names the type and a closure-like accessor from `&Self` to a value whose type implements `Parity`.
+/// Several entries may be separated by semicolons.
+///
+/// # Example
+/// ```
+/// use parity::{impl_parity_for, Parity};
+///
+/// struct Meters(u32);
+/// struct Reading {
+///     raw: i16,
+/// }
+/// struct Counter(std::cell::Cell<u64>);
+///
+/// impl_parity_for! {
+///     Meters, |m| m.0;
+///     Reading, |r| r.raw;
+///     Counter, |c| c.0.get();
+/// }
+///
+/// assert!(Meters(4).is_even());
+/// assert!(Reading { raw: -3 }.same_parity(&Reading { raw: 1 }));
+/// assert!(Counter(7.into()).is_odd());
+/// ```
+#[macro_export]
+macro_rules! impl_parity_for {
+    ($($T:ty, |$this:ident| $inner:expr);+ $(;)?) => { $(
+        impl $crate::Parity for $T {
+            #[inline]
+            fn is_even(&self) -> bool {
+                let $this = self;
+                $crate::Parity::is_even(&$inner)
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                let $this = self;
+                $crate::Parity::is_odd(&$inner)
+            }
+
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                let $this = self;
+                $crate::Parity::is_parity_defined(&$inner)
+            }
+
+            #[inline]
+            fn checked_parity(&self) -> ::core::option::Option<$crate::ParityKind> {
+                let $this = self;
+                $crate::Parity::checked_parity(&$inner)
+            }
+
+            #[inline]
+            fn same_parity(&self, other: &Self) -> bool {
+                let $this = self;
+                let lhs = &$inner;
+                let $this = other;
+                $crate::Parity::same_parity(lhs, &$inner)
+            }
+
+            #[inline]
+            fn opposite_parity(&self, other: &Self) -> bool {
+                let $this = self;
+                let lhs = &$inner;
+                let $this = other;
+                $crate::Parity::opposite_parity(lhs, &$inner)
+            }
+
+            #[inline]
+            fn checked_same_parity(&self, other: &Self) -> ::core::option::Option<bool> {
+                let $this = self;
+                let lhs = &$inner;
+                let $this = other;
+                $crate::Parity::checked_same_parity(lhs, &$inner)
+            }
+        }
+    )+ };
+}
+
+#[cfg(test)]
+mod tests {
+    static_assert_even!(-4i8);
+    static_assert_even!(u128::MAX - 1, "with a message");
+    #[test]
+    fn passing() {
+        assert_even!(2u8);
+        assert_even!(-4.0, "with a message");
+        assert_odd!(i64::MAX);
+        assert_odd!(1, "with {}", "arguments");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `x.is_even()` failed: bad x\n value: 3")]
+    fn failure_message() {
+        let x = 3;
+        assert_even!(x, "bad x");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion `1.5.is_odd()` failed\n value: 1.5")]
+    fn fractional() {
+        assert_odd!(1.5);
+    }
+
+    #[test]
+    #[cfg_attr(
+        debug_assertions,
+        should_panic(expected = "assertion `3.is_even()` failed")
+    )]
+    fn debug_only() {
+        debug_assert_odd!(3);
+        debug_assert_even!(3);
+    }
+
+    #[test]
+    fn even_values() {
+        const HALF: i32 = 21;
+        let array = [(); even!(HALF as usize * 2)];
+        assert_eq!(array.len(), 42);
+        assert_eq!(even!(-HALF * 2), -42);
+    }
+
+    #[test]
+    fn parity_of() {
+        use crate::ParityKind;
+
+        const EVEN: ParityKind = parity_of!(u64::MAX - 1);
+        const ODD: ParityKind = parity_of!(-7i8);
+        assert_eq!((EVEN, ODD), (ParityKind::Even, ParityKind::Odd));
+        assert_eq!(parity_of!(0), ParityKind::Even);
+        assert_eq!(parity_of!(i128::MIN + 1), ParityKind::Odd);
+    }
+
+    #[test]
+    fn tables() {
+        use crate::Parity;
+
+        const EVENS: [u64; 5] = even_table!([u64; 5]);
+        assert_eq!(EVENS, [0, 2, 4, 6, 8]);
+        assert_eq!(even_table!([u8; 2], start = 252, step = 2), [252, 254]);
+        assert_eq!(even_table!([i16; 0]), [0i16; 0]);
+        assert_eq!(odd_table!([u32; 3]), [1, 3, 5]);
+        assert_eq!(odd_table!([i64; 3], start = 5, step = -10), [5, -5, -15]);
+        assert!(odd_table!([i128; 16], step = 6).iter().all(Parity::is_odd));
+    }
+
+    #[test]
+    fn impl_parity_for() {
+        use crate::{Parity, ParityKind};
+
+        struct Wrapper(f64);
+        struct Nested {
+            inner: Wrapper,
+        }
+        impl_parity_for!(Wrapper, |w| w.0);
+        impl_parity_for! {
+            Nested, |n| n.inner;
+        }
+
+        assert!(Wrapper(2.0).is_even());
+        assert_eq!(Wrapper(2.5).checked_parity(), None);
+        assert!(Wrapper(3.0).opposite_parity(&Wrapper(4.0)));
+        let nested = |x| Nested { inner: Wrapper(x) };
+        assert!(nested(1.0).same_parity(&nested(-1.0)));
+        assert_eq!(nested(1.5).checked_same_parity(&nested(2.0)), None);
+        assert_eq!(nested(-7.0).checked_parity(), Some(ParityKind::Odd));
+    }
+}
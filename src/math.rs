@@ -0,0 +1,1013 @@
+//! Parities of arithmetic results, computed without performing the arithmetic.
+
+use core::iter::FusedIterator;
+use core::ops::Neg;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// Returns the parity of `a + b` without computing the sum, so it cannot overflow.
+///
+/// # Example
+/// ```
+/// use parity::math::parity_of_sum;
+/// use parity::ParityKind;
+///
+/// assert_eq!(parity_of_sum(u64::MAX, u64::MAX), ParityKind::Even);
+/// assert_eq!(parity_of_sum(i128::MIN, 1u8), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn parity_of_sum<A: IntegerParity, B: IntegerParity>(a: A, b: B) -> ParityKind {
+    a.parity() + b.parity()
+}
+
+/// Returns the parity of `a * b` without computing the product, so it cannot overflow.
+///
+/// # Example
+/// ```
+/// use parity::math::parity_of_product;
+/// use parity::ParityKind;
+///
+/// assert_eq!(parity_of_product(u64::MAX, u64::MAX), ParityKind::Odd);
+/// assert_eq!(parity_of_product(i128::MAX, 2u8), ParityKind::Even);
+/// ```
+#[inline]
+pub fn parity_of_product<A: IntegerParity, B: IntegerParity>(a: A, b: B) -> ParityKind {
+    a.parity() * b.parity()
+}
+
+/// Returns the parity of the product of all `factors`, stopping at the first even factor.
+///
+/// The empty product is one, which is odd.
+///
+/// # Example
+/// ```
+/// use parity::math::parity_of_product_iter;
+/// use parity::ParityKind;
+///
+/// assert_eq!(parity_of_product_iter([3u64, 5, 7]), ParityKind::Odd);
+/// assert_eq!(parity_of_product_iter((1..).step_by(1)), ParityKind::Even);
+/// ```
+#[inline]
+pub fn parity_of_product_iter<I>(factors: I) -> ParityKind
+where
+    I: IntoIterator,
+    I::Item: IntegerParity,
+{
+    ParityKind::from_odd(factors.into_iter().all(|factor| factor.is_odd()))
+}
+
+/// Returns the parity of `base` raised to the power `exp` without computing the power.
+///
+/// Any base to the power zero is one, which is odd; this includes `0⁰`, matching [u32::pow].
+///
+/// # Example
+/// ```
+/// use parity::math::parity_of_pow;
+/// use parity::ParityKind;
+///
+/// assert_eq!(parity_of_pow(3u64, 1_000_000), ParityKind::Odd);
+/// assert_eq!(parity_of_pow(10u8, 200), ParityKind::Even);
+/// assert_eq!(parity_of_pow(10u8, 0), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn parity_of_pow<T: IntegerParity>(base: T, exp: u32) -> ParityKind {
+    if exp == 0 {
+        ParityKind::Odd
+    } else {
+        base.parity()
+    }
+}
+
+/// Returns the parity of the binomial coefficient `C(n, k)` in constant time.
+///
+/// By Lucas's theorem `C(n, k)` is odd exactly when every bit set in `k` is also set in `n`, i.e. when adding `k` and
+/// `n - k` in binary produces no carries (Kummer). For `k > n` the coefficient is zero, which is even.
+///
+/// # Example
+/// ```
+/// use parity::math::binomial_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(binomial_parity(5, 2), ParityKind::Even); // 10
+/// assert_eq!(binomial_parity(7, 3), ParityKind::Odd); // 35
+/// assert_eq!(binomial_parity(3, 4), ParityKind::Even); // 0
+/// ```
+#[inline]
+pub const fn binomial_parity(n: u64, k: u64) -> ParityKind {
+    ParityKind::from_odd(k <= n && (n - k) & k == 0)
+}
+
+/// Returns an iterator over the parities of row `n` of Pascal's triangle, `C(n, 0)` to `C(n, n)`.
+///
+/// Stacking the rows draws the Sierpinski triangle, and row `n` is also the state of the Rule 90 automaton after
+/// `n` steps from a single cell. Use [odd_binomial_indices] to visit only the odd entries, and [binomial_row_word] or
+/// [binomial_row_bitmap] for the row as packed bits.
+///
+/// # Example
+/// ```
+/// use parity::math::binomial_row_parity;
+///
+/// let rows: Vec<String> = (0..4)
+///     .map(|n| binomial_row_parity(n).map(|p| if p.is_odd() { '#' } else { '.' }).collect())
+///     .collect();
+/// assert_eq!(rows, ["#", "##", "#.#", "####"]);
+/// ```
+#[inline]
+pub fn binomial_row_parity(n: u64) -> BinomialRowParity {
+    BinomialRowParity {
+        n,
+        front: 0,
+        back: n,
+        done: false,
+    }
+}
+
+/// An iterator over the parities of a row of Pascal's triangle, returned by [binomial_row_parity].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct BinomialRowParity {
+    n: u64,
+    front: u64,
+    back: u64,
+    done: bool,
+}
+
+impl Iterator for BinomialRowParity {
+    type Item = ParityKind;
+
+    #[inline]
+    fn next(&mut self) -> Option<ParityKind> {
+        if self.done {
+            return None;
+        }
+        let k = self.front;
+        self.done = k == self.back;
+        self.front = k.wrapping_add(1);
+        Some(binomial_parity(self.n, k))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let len = usize::try_from(self.back - self.front)
+            .ok()
+            .and_then(|len| len.checked_add(1));
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+impl DoubleEndedIterator for BinomialRowParity {
+    #[inline]
+    fn next_back(&mut self) -> Option<ParityKind> {
+        if self.done {
+            return None;
+        }
+        let k = self.back;
+        self.done = k == self.front;
+        self.back = k.wrapping_sub(1);
+        Some(binomial_parity(self.n, k))
+    }
+}
+
+impl FusedIterator for BinomialRowParity {}
+
+/// Returns an iterator over the `k` in increasing order for which `C(n, k)` is odd.
+///
+/// These are the `k` whose bits are all set in `n`, so there are `2^s` of them for `s` set bits in `n`, and the
+/// iterator takes constant time per item however long the row is.
+///
+/// # Example
+/// ```
+/// use parity::math::odd_binomial_indices;
+///
+/// assert!(odd_binomial_indices(10).eq([0, 2, 8, 10]));
+/// assert_eq!(odd_binomial_indices(1 << 40).count(), 2);
+/// ```
+#[inline]
+pub fn odd_binomial_indices(n: u64) -> OddBinomialIndices {
+    OddBinomialIndices { n, next: Some(0) }
+}
+
+/// An iterator over the odd entries of a row of Pascal's triangle, returned by [odd_binomial_indices].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OddBinomialIndices {
+    n: u64,
+    next: Option<u64>,
+}
+
+impl Iterator for OddBinomialIndices {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        let k = self.next?;
+        // the next submask of `n` in increasing order, wrapping back to zero after `n` itself
+        let next = k.wrapping_sub(self.n) & self.n;
+        self.next = (next != 0).then_some(next);
+        Some(k)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Some(k) = self.next else {
+            return (0, Some(0));
+        };
+        // the submasks not yet returned are those at least `k`, counted by the rank of `k` among them
+        let total = 1u128 << self.n.count_ones();
+        let below = pext(k, self.n) as u128;
+        let len = usize::try_from(total - below).ok();
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+impl FusedIterator for OddBinomialIndices {}
+
+/// Gathers the bits of `value` at the positions set in `mask` into the low bits of the result.
+const fn pext(value: u64, mask: u64) -> u64 {
+    let (mut result, mut mask, mut bit) = (0, mask, 0);
+    while mask != 0 {
+        let lowest = mask & mask.wrapping_neg();
+        if value & lowest != 0 {
+            result |= 1 << bit;
+        }
+        bit += 1;
+        mask &= mask - 1;
+    }
+    result
+}
+
+/// Returns word `index` of row `n` of Pascal's triangle packed as bits: bit `i` is set if `C(n, 64 * index + i)` is
+/// odd. Bits past the end of the row are clear.
+///
+/// Each word takes constant time, since the high bits of `k` only decide whether the word is zero and the low six
+/// bits select a pattern that depends on `n % 64` alone.
+///
+/// # Example
+/// ```
+/// use parity::math::binomial_row_word;
+///
+/// assert_eq!(binomial_row_word(5, 0), 0b11_0011); // C(5, k) is odd for k = 0, 1, 4, 5
+/// assert_eq!(binomial_row_word(64, 1), 1);
+/// assert_eq!(binomial_row_word(64, 2), 0);
+/// ```
+#[inline]
+pub const fn binomial_row_word(n: u64, index: u64) -> u64 {
+    let Some(high) = index.checked_mul(64) else {
+        return 0;
+    };
+    if high & !n != 0 {
+        return 0;
+    }
+    // bit `i` is set for every submask `i` of the low six bits of `n`
+    let (mut word, mut bit) = (1u64, 0);
+    while bit < 6 {
+        if n & (1 << bit) != 0 {
+            word |= word << (1 << bit);
+        }
+        bit += 1;
+    }
+    word
+}
+
+/// Returns row `n` of Pascal's triangle packed as bits, in the layout of [binomial_row_word], with the `n / 64 + 1`
+/// words needed for the whole row.
+///
+/// # Panics
+/// Panics if the row has more words than fit in a `usize`.
+///
+/// # Example
+/// ```
+/// use parity::math::binomial_row_bitmap;
+///
+/// let row = binomial_row_bitmap(100);
+/// assert_eq!(row.len(), 2);
+/// assert_eq!(row.iter().map(|w| w.count_ones()).sum::<u32>(), 8); // 100 has three set bits
+/// ```
+#[cfg(feature = "alloc")]
+pub fn binomial_row_bitmap(n: u64) -> Vec<u64> {
+    let words = usize::try_from(n / 64 + 1).expect("the row does not fit in memory");
+    (0..words as u64)
+        .map(|index| binomial_row_word(n, index))
+        .collect()
+}
+
+/// Returns an iterator over the subsets of an `n`-element set whose number of elements has the given `parity`, as
+/// bitmasks in increasing order, where bit `i` stands for element `i`.
+///
+/// Inclusion–exclusion sums add the terms of one cardinality parity and subtract the others, so iterating the two
+/// halves separately avoids testing every one of the `2^n` masks. For `n > 0` each half has `2^(n - 1)` subsets; the
+/// empty set has one even subset and no odd ones.
+///
+/// # Panics
+/// Panics if `n > 64`.
+///
+/// # Example
+/// ```
+/// use parity::math::subsets_with_parity;
+/// use parity::ParityKind;
+///
+/// assert!(subsets_with_parity(3, ParityKind::Odd).eq([0b001, 0b010, 0b100, 0b111]));
+///
+/// // the number of integers up to 30 divisible by 2, 3 or 5
+/// let primes = [2, 3, 5];
+/// let count_divisible = |mask: u64| {
+///     let product: u64 = (0..3).filter(|i| mask >> i & 1 != 0).map(|i| primes[i]).product();
+///     30 / product
+/// };
+/// let odd: u64 = subsets_with_parity(3, ParityKind::Odd).map(count_divisible).sum();
+/// let even: u64 = subsets_with_parity(3, ParityKind::Even).skip(1).map(count_divisible).sum();
+/// assert_eq!(odd - even, 22);
+/// ```
+#[track_caller]
+pub fn subsets_with_parity(n: u32, parity: ParityKind) -> ParitySubsets {
+    assert!(
+        n <= 64,
+        "a set of {n} elements does not fit in a 64-bit mask"
+    );
+    let remaining = match (n, parity) {
+        (0, ParityKind::Even) => 1,
+        (0, ParityKind::Odd) => 0,
+        _ => 1 << (n - 1),
+    };
+    ParitySubsets {
+        next: parity.is_odd() as u64,
+        remaining,
+    }
+}
+
+/// An iterator over the subsets of a set with a given cardinality parity, returned by [subsets_with_parity].
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ParitySubsets {
+    next: u64,
+    remaining: u64,
+}
+
+impl Iterator for ParitySubsets {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let mask = self.next;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            // `2k` and `2k + 1` differ in one bit, so one of the next three masks has the parity
+            let mut next = mask + 1;
+            while (next.count_ones() ^ mask.count_ones()) & 1 != 0 {
+                next += 1;
+            }
+            self.next = next;
+        }
+        Some(mask)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = usize::try_from(self.remaining).ok();
+        (len.unwrap_or(usize::MAX), len)
+    }
+}
+
+impl FusedIterator for ParitySubsets {}
+
+/// Returns the parity of `n!`, which is odd only for `0!` and `1!`.
+///
+/// # Example
+/// ```
+/// use parity::math::factorial_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(factorial_parity(1), ParityKind::Odd);
+/// assert_eq!(factorial_parity(1_000_000), ParityKind::Even);
+/// ```
+#[inline]
+pub const fn factorial_parity(n: u64) -> ParityKind {
+    ParityKind::from_odd(n <= 1)
+}
+
+/// Returns the exponent of the largest power of two dividing `n!`.
+///
+/// By Legendre's formula this is `n` minus the number of set bits in `n`.
+///
+/// # Example
+/// ```
+/// use parity::math::factorial_two_adic_valuation;
+///
+/// assert_eq!(factorial_two_adic_valuation(4), 3); // 24 = 2³ * 3
+/// assert_eq!(factorial_two_adic_valuation(1), 0);
+/// ```
+#[inline]
+pub const fn factorial_two_adic_valuation(n: u64) -> u64 {
+    n - n.count_ones() as u64
+}
+
+/// Returns the parity of the `n`th Fibonacci number, where `F(0) = 0` and `F(1) = 1`.
+///
+/// Fibonacci parities repeat with period three (even, odd, odd), so `F(n)` is even exactly when `n` is a multiple of
+/// three.
+///
+/// # Example
+/// ```
+/// use parity::math::fibonacci_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(fibonacci_parity(6), ParityKind::Even); // 8
+/// assert_eq!(fibonacci_parity(7), ParityKind::Odd); // 13
+/// ```
+#[inline]
+pub const fn fibonacci_parity(n: u64) -> ParityKind {
+    ParityKind::from_odd(!n.is_multiple_of(3))
+}
+
+/// Returns the parity of the `n`th triangular number `n(n + 1) / 2`.
+///
+/// Triangular numbers are odd exactly when `n ≡ 1` or `n ≡ 2 (mod 4)`.
+///
+/// # Example
+/// ```
+/// use parity::math::triangular_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(triangular_parity(2), ParityKind::Odd); // 3
+/// assert_eq!(triangular_parity(3), ParityKind::Even); // 6
+/// ```
+#[inline]
+pub const fn triangular_parity(n: u64) -> ParityKind {
+    ParityKind::from_odd(matches!(n % 4, 1 | 2))
+}
+
+/// Returns the parity of the `n`th `sides`-gonal number `((sides - 2)n² - (sides - 4)n) / 2`.
+///
+/// Only the residues of the operands modulo four are used, so this cannot overflow.
+///
+/// # Panics
+/// Panics if `sides` is less than three.
+///
+/// # Example
+/// ```
+/// use parity::math::polygonal_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(polygonal_parity(4, 7), ParityKind::Odd); // 49
+/// assert_eq!(polygonal_parity(5, 4), ParityKind::Even); // 22
+/// ```
+#[inline]
+pub const fn polygonal_parity(sides: u64, n: u64) -> ParityKind {
+    assert!(sides >= 3, "polygons have at least three sides");
+    // arithmetic modulo 2⁶⁴ preserves residues modulo 4, which is all that is needed to halve and take the low bit
+    let doubled = (sides.wrapping_sub(2))
+        .wrapping_mul(n)
+        .wrapping_mul(n)
+        .wrapping_sub(sides.wrapping_sub(4).wrapping_mul(n));
+    ParityKind::from_odd(doubled & 0b10 != 0)
+}
+
+/// Returns the parity of the sum of the digits of `n` written in base `radix`.
+///
+/// # Panics
+/// Panics if `radix` is less than two.
+///
+/// # Example
+/// ```
+/// use parity::math::digit_sum_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(digit_sum_parity(1234, 10), ParityKind::Even); // 1 + 2 + 3 + 4
+/// assert_eq!(digit_sum_parity(0xff, 16), ParityKind::Even); // 15 + 15
+/// assert_eq!(digit_sum_parity(7, 2), ParityKind::Odd); // 1 + 1 + 1
+/// ```
+pub const fn digit_sum_parity(mut n: u64, radix: u32) -> ParityKind {
+    assert!(radix >= 2, "radix must be at least 2");
+    let radix = radix as u64;
+    let mut odd = false;
+    while n != 0 {
+        odd ^= n % radix % 2 == 1;
+        n /= radix;
+    }
+    ParityKind::from_odd(odd)
+}
+
+/// Returns the parity of the alternating sum of the digits of `n` written in base `radix`, starting with a positive
+/// least significant digit.
+///
+/// Negating a term does not change the parity of a sum, so this always agrees with [digit_sum_parity]; it is
+/// provided so divisibility rules built on the alternating sum can say what they mean.
+///
+/// # Panics
+/// Panics if `radix` is less than two.
+///
+/// # Example
+/// ```
+/// use parity::math::alternating_digit_sum_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(alternating_digit_sum_parity(918_082, 10), ParityKind::Even); // 2 - 8 + 0 - 8 + 1 - 9
+/// ```
+#[inline]
+pub const fn alternating_digit_sum_parity(n: u64, radix: u32) -> ParityKind {
+    digit_sum_parity(n, radix)
+}
+
+/// Returns `true` if `n` is the square of an integer.
+///
+/// # Example
+/// ```
+/// use parity::math::is_perfect_square;
+///
+/// assert!(is_perfect_square(0));
+/// assert!(is_perfect_square(144));
+/// assert!(!is_perfect_square(u64::MAX));
+/// ```
+#[inline]
+pub const fn is_perfect_square(n: u64) -> bool {
+    let root = n.isqrt();
+    root * root == n
+}
+
+/// Returns the parity of the number of positive divisors of `n`.
+///
+/// Divisors pair up as `d` and `n / d`, and only a square root is paired with itself, so the count is odd exactly
+/// when `n` is a perfect square.
+///
+/// # Panics
+/// Panics if `n` is zero, which every positive integer divides.
+///
+/// # Example
+/// ```
+/// use parity::math::divisor_count_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(divisor_count_parity(36), ParityKind::Odd); // 1, 2, 3, 4, 6, 9, 12, 18, 36
+/// assert_eq!(divisor_count_parity(12), ParityKind::Even); // 1, 2, 3, 4, 6, 12
+/// ```
+#[inline]
+#[track_caller]
+pub const fn divisor_count_parity(n: u64) -> ParityKind {
+    assert!(n != 0, "zero has infinitely many divisors");
+    ParityKind::from_odd(is_perfect_square(n))
+}
+
+/// Returns the parity of `σ(n)`, the sum of the positive divisors of `n`.
+///
+/// The odd divisors of `n` are the divisors of its odd part `m`, so `σ(n)` has the parity of their count, which is
+/// odd exactly when `m` is a square. That makes `σ(n)` odd exactly when `n` is a square or twice a square.
+///
+/// # Panics
+/// Panics if `n` is zero.
+///
+/// # Example
+/// ```
+/// use parity::math::sigma_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(sigma_parity(18), ParityKind::Odd); // 1 + 2 + 3 + 6 + 9 + 18 = 39
+/// assert_eq!(sigma_parity(6), ParityKind::Even); // 1 + 2 + 3 + 6 = 12
+/// ```
+#[inline]
+#[track_caller]
+pub const fn sigma_parity(n: u64) -> ParityKind {
+    assert!(n != 0, "zero has infinitely many divisors");
+    ParityKind::from_odd(is_perfect_square(n >> n.trailing_zeros()))
+}
+
+/// Returns `(-1)^n`: `1` if `n` is even and `-1` if it is odd.
+///
+/// The exponent can be of any integer type, including negative values, whose powers of `-1` are the same as those of
+/// their absolute value.
+///
+/// # Example
+/// ```
+/// use parity::math::sign_pow;
+///
+/// // the cofactor signs along the first row of a 4x4 determinant expansion
+/// let signs: Vec<i32> = (0..4).map(sign_pow).collect();
+/// assert_eq!(signs, [1, -1, 1, -1]);
+/// assert_eq!(sign_pow(-3i8), -1);
+/// ```
+#[inline]
+pub fn sign_pow<N: IntegerParity>(n: N) -> i32 {
+    n.if_even_else(1, -1)
+}
+
+/// Returns `(-1)^n` as a float: `1.0` if `n` is even and `-1.0` if it is odd.
+///
+/// # Example
+/// ```
+/// use parity::math::sign_pow_f64;
+///
+/// // the first terms of the Leibniz series for pi / 4
+/// let sum: f64 = (0..4u32).map(|k| sign_pow_f64(k) / f64::from(2 * k + 1)).sum();
+/// assert_eq!(sum, 1.0 - 1.0 / 3.0 + 1.0 / 5.0 - 1.0 / 7.0);
+/// ```
+#[inline]
+pub fn sign_pow_f64<N: IntegerParity>(n: N) -> f64 {
+    n.if_even_else(1.0, -1.0)
+}
+
+/// Returns `value` multiplied by `(-1)^n`: `value` if `n` is even and `-value` if it is odd.
+///
+/// This negates instead of multiplying, so it works for any type with [Neg], including [f32] and unsigned wrappers
+/// such as [core::num::Wrapping]. Negating the minimum of a signed integer type overflows, as usual.
+///
+/// # Example
+/// ```
+/// use parity::math::signed_by_parity;
+///
+/// assert_eq!(signed_by_parity(5i64, 3u8), -5);
+/// assert_eq!(signed_by_parity(2.5f32, 10), 2.5);
+/// ```
+#[inline]
+pub fn signed_by_parity<T: Neg<Output = T>, N: IntegerParity>(value: T, n: N) -> T {
+    if n.is_odd() {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Returns `f` applied `n` times to `x`, where `f` is an involution, a function that undoes itself.
+///
+/// Applying an involution twice gives back the input, so `n` applications are the same as `n mod 2`: `f(x)` if `n`
+/// is odd, and `x` otherwise. The inverse of an involution is itself, so a negative `n` means the same. The result
+/// is only correct if `f(f(x)) == x` for every `x`.
+///
+/// # Example
+/// ```
+/// use parity::math::involution_pow;
+///
+/// let toggles = 1_000_001u64;
+/// assert_eq!(involution_pow(|on: bool| !on, toggles, false), true);
+/// assert_eq!(involution_pow(i32::wrapping_neg, -4, 7), 7);
+/// ```
+#[inline]
+pub fn involution_pow<T, N: IntegerParity, F: FnOnce(T) -> T>(f: F, n: N, x: T) -> T {
+    if n.is_odd() {
+        f(x)
+    } else {
+        x
+    }
+}
+
+/// Applies the in-place involution `f` to `x` as many times as `n`, that is once if `n` is odd and not at all
+/// otherwise.
+///
+/// This is [involution_pow] for operations such as [`<[T]>::reverse`](slice::reverse) that modify a value in place,
+/// and is only correct if calling `f` twice leaves `x` as it was.
+///
+/// # Example
+/// ```
+/// use parity::math::apply_n_mod2;
+///
+/// let mut row = [1, 2, 3];
+/// // a boustrophedon scan reverses every other row
+/// apply_n_mod2(<[i32]>::reverse, 3u32, &mut row[..]);
+/// assert_eq!(row, [3, 2, 1]);
+/// apply_n_mod2(<[i32]>::reverse, 4u32, &mut row[..]);
+/// assert_eq!(row, [3, 2, 1]);
+/// ```
+#[inline]
+pub fn apply_n_mod2<T: ?Sized, N: IntegerParity, F: FnOnce(&mut T)>(f: F, n: N, x: &mut T) {
+    if n.is_odd() {
+        f(x);
+    }
+}
+
+/// Tracks the parity of an accumulating sum or product without storing the quantity itself, so it can never
+/// overflow.
+///
+/// A new tracker stands for zero, the empty sum. Start products from [ParityTracker::one].
+///
+/// # Example
+/// ```
+/// use parity::math::ParityTracker;
+/// use parity::ParityKind;
+///
+/// let mut total = ParityTracker::new();
+/// for x in [u64::MAX; 3] {
+///     total.add(x);
+/// }
+/// assert_eq!(total.current(), ParityKind::Odd);
+///
+/// total.mul(4u8);
+/// assert_eq!(total.current(), ParityKind::Even);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityTracker {
+    parity: ParityKind,
+}
+
+impl ParityTracker {
+    /// Returns a tracker for zero.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::with_parity(ParityKind::Even)
+    }
+
+    /// Returns a tracker for one, the empty product.
+    #[inline]
+    pub const fn one() -> Self {
+        Self::with_parity(ParityKind::Odd)
+    }
+
+    /// Returns a tracker for a quantity of the given `parity`.
+    #[inline]
+    pub const fn with_parity(parity: ParityKind) -> Self {
+        Self { parity }
+    }
+
+    /// Returns the parity of the tracked quantity.
+    #[inline]
+    pub const fn current(&self) -> ParityKind {
+        self.parity
+    }
+
+    /// Adds `x` to the tracked quantity. Subtracting `x` has the same effect on the parity.
+    #[inline]
+    pub fn add<T: IntegerParity>(&mut self, x: T) -> &mut Self {
+        self.parity = self.parity + x.parity();
+        self
+    }
+
+    /// Multiplies the tracked quantity by `x`.
+    #[inline]
+    pub fn mul<T: IntegerParity>(&mut self, x: T) -> &mut Self {
+        self.parity = self.parity * x.parity();
+        self
+    }
+
+    /// Resets the tracked quantity to zero.
+    #[inline]
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+impl Default for ParityTracker {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn involutions() {
+        let reversed = |s: [u8; 3]| [s[2], s[1], s[0]];
+        for n in -6i64..6 {
+            let mut looped = [1, 2, 3];
+            for _ in 0..n.unsigned_abs() {
+                looped = reversed(looped);
+            }
+            assert_eq!(involution_pow(reversed, n, [1, 2, 3]), looped);
+
+            let mut bits = 0b1010u8;
+            apply_n_mod2(|b: &mut u8| *b ^= 0xff, n, &mut bits);
+            assert_eq!(bits, if n % 2 == 0 { 0b1010 } else { 0b1111_0101 });
+        }
+        assert_eq!(involution_pow(u32::reverse_bits, u128::MAX, 1), 1 << 31);
+    }
+
+    #[test]
+    fn sums() {
+        for a in -5i32..5 {
+            for b in -5i32..5 {
+                assert_eq!(parity_of_sum(a, b), (a + b).parity());
+            }
+        }
+        assert_eq!(parity_of_sum(u128::MAX, 2u8), ParityKind::Odd);
+    }
+
+    #[test]
+    fn products() {
+        for a in -5i32..5 {
+            for b in -5i32..5 {
+                assert_eq!(parity_of_product(a, b), (a * b).parity());
+            }
+        }
+        assert_eq!(parity_of_product_iter([] as [u8; 0]), ParityKind::Odd);
+        assert_eq!(parity_of_product_iter([u8::MAX; 100]), ParityKind::Odd);
+        assert_eq!(parity_of_product_iter([1i8, -1, 0]), ParityKind::Even);
+    }
+
+    #[test]
+    fn binomials() {
+        // rows of Pascal's triangle built by addition
+        let mut row = std::vec![1u64];
+        for n in 0..64u64 {
+            for (k, c) in row.iter().enumerate() {
+                assert_eq!(binomial_parity(n, k as u64), c.parity());
+            }
+            assert_eq!(binomial_parity(n, n + 1), ParityKind::Even);
+            row = (0..=row.len())
+                .map(|k| {
+                    let left = if k > 0 { row[k - 1] } else { 0 };
+                    left.wrapping_add(*row.get(k).unwrap_or(&0))
+                })
+                .collect();
+        }
+    }
+
+    #[test]
+    fn binomial_rows() {
+        for n in (0..200).chain([u64::MAX - 3, 1 << 63]) {
+            let row = binomial_row_parity(n);
+            if n < 200 {
+                assert_eq!(row.size_hint(), (n as usize + 1, Some(n as usize + 1)));
+                assert!(row.clone().eq((0..=n).map(|k| binomial_parity(n, k))));
+                assert!(row.rev().eq((0..=n).rev().map(|k| binomial_parity(n, k))));
+
+                let odd: std::vec::Vec<u64> = (0..=n)
+                    .filter(|&k| binomial_parity(n, k).is_odd())
+                    .collect();
+                #[cfg(feature = "alloc")]
+                {
+                    let bitmap = binomial_row_bitmap(n);
+                    let from_bits: std::vec::Vec<u64> = (0..bitmap.len() as u64 * 64)
+                        .filter(|&k| bitmap[k as usize / 64] & 1 << (k % 64) != 0)
+                        .collect();
+                    assert_eq!(from_bits, odd);
+                }
+                assert!(odd_binomial_indices(n).eq(odd));
+            }
+
+            if n.count_ones() < 8 {
+                let mut odd = odd_binomial_indices(n);
+                for expected in (0..=1usize << n.count_ones()).rev() {
+                    assert_eq!(odd.size_hint(), (expected, Some(expected)));
+                    odd.next();
+                }
+            }
+        }
+
+        let mut row = binomial_row_parity(u64::MAX);
+        assert_eq!(row.next_back(), Some(ParityKind::Odd));
+        assert_eq!(row.next(), Some(ParityKind::Odd));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(row.size_hint(), (usize::MAX - 1, Some(usize::MAX - 1)));
+
+        let mut single = binomial_row_parity(0);
+        assert_eq!(single.next_back(), Some(ParityKind::Odd));
+        assert_eq!((single.next(), single.size_hint()), (None, (0, Some(0))));
+        assert_eq!(binomial_row_word(u64::MAX, u64::MAX), 0);
+        assert_eq!(binomial_row_word(u64::MAX, u64::MAX / 64), u64::MAX);
+    }
+
+    #[test]
+    fn parity_subsets() {
+        for n in 0..=12u32 {
+            for parity in ParityKind::iter() {
+                let expected: std::vec::Vec<u64> = (0..1u64 << n)
+                    .filter(|mask| mask.count_ones() as u64 % 2 == parity.is_odd() as u64)
+                    .collect();
+                let subsets = subsets_with_parity(n, parity);
+                assert_eq!(subsets.size_hint(), (expected.len(), Some(expected.len())));
+                assert!(subsets.eq(expected));
+            }
+        }
+
+        let mut all = subsets_with_parity(64, ParityKind::Even);
+        assert_eq!(all.size_hint().1, usize::try_from(1u64 << 63).ok());
+        assert_eq!(all.nth(2), Some(0b101));
+        assert_eq!(subsets_with_parity(64, ParityKind::Odd).next(), Some(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "a set of 65 elements does not fit in a 64-bit mask")]
+    fn too_many_elements() {
+        let _ = subsets_with_parity(65, ParityKind::Even);
+    }
+
+    #[test]
+    fn factorials() {
+        let mut factorial = 1u128;
+        for n in 0..=34u64 {
+            if n > 0 {
+                factorial *= n as u128;
+            }
+            assert_eq!(factorial_parity(n), factorial.parity());
+            assert_eq!(
+                factorial_two_adic_valuation(n),
+                factorial.trailing_zeros() as u64
+            );
+        }
+        assert_eq!(factorial_two_adic_valuation(u64::MAX), u64::MAX - 64);
+    }
+
+    #[test]
+    fn fibonacci() {
+        let (mut a, mut b) = (0u128, 1u128);
+        for n in 0..180 {
+            assert_eq!(fibonacci_parity(n), a.parity());
+            (a, b) = (b, a + b);
+        }
+    }
+
+    #[test]
+    fn divisors() {
+        for n in 1..2000u64 {
+            let divisors = (1..=n).filter(|d| n.is_multiple_of(*d));
+            assert_eq!(divisor_count_parity(n), divisors.clone().count().parity());
+            assert_eq!(sigma_parity(n), divisors.sum::<u64>().parity());
+        }
+        assert!(is_perfect_square(u32::MAX as u64 * u32::MAX as u64));
+        assert!(!is_perfect_square(u32::MAX as u64 * u32::MAX as u64 - 1));
+        assert_eq!(divisor_count_parity(u64::MAX), ParityKind::Even);
+        assert_eq!(sigma_parity(1 << 63), ParityKind::Odd);
+    }
+
+    #[test]
+    #[should_panic(expected = "zero has infinitely many divisors")]
+    fn divisors_of_zero() {
+        sigma_parity(0);
+    }
+
+    #[test]
+    fn polygonal_numbers() {
+        for n in 0..100u64 {
+            assert_eq!(triangular_parity(n), (n * (n + 1) / 2).parity());
+            assert_eq!(polygonal_parity(3, n), triangular_parity(n));
+            for sides in 3..12u64 {
+                let value = ((sides - 2) * n * n + 4 * n - sides * n) / 2;
+                assert_eq!(polygonal_parity(sides, n), value.parity());
+            }
+        }
+        assert_eq!(polygonal_parity(u64::MAX, u64::MAX), ParityKind::Even);
+    }
+
+    #[test]
+    fn digit_sums() {
+        for radix in 2..=36u32 {
+            for n in (0..10_000u64).chain([u64::MAX]) {
+                let mut rest = n;
+                let (mut sum, mut alternating, mut sign) = (0i64, 0i64, 1i64);
+                while rest != 0 {
+                    let digit = (rest % radix as u64) as i64;
+                    sum += digit;
+                    alternating += sign * digit;
+                    sign = -sign;
+                    rest /= radix as u64;
+                }
+                assert_eq!(digit_sum_parity(n, radix), sum.parity());
+                assert_eq!(alternating_digit_sum_parity(n, radix), alternating.parity());
+            }
+        }
+    }
+
+    #[test]
+    fn powers() {
+        for base in -4i64..4 {
+            for exp in 0..5 {
+                assert_eq!(parity_of_pow(base, exp), base.pow(exp).parity());
+            }
+        }
+    }
+
+    #[test]
+    fn tracker() {
+        let mut sum = ParityTracker::new();
+        let mut product = ParityTracker::one();
+        let mut expected = (0i64, 1i64);
+        for x in [3i64, -8, 5, 7, 9, -11] {
+            sum.add(x);
+            product.mul(x);
+            expected = (expected.0 + x, expected.1 * x);
+            assert_eq!(sum.current(), expected.0.parity());
+            assert_eq!(product.current(), expected.1.parity());
+        }
+        assert_eq!(ParityTracker::default(), ParityTracker::new());
+        assert_eq!(
+            ParityTracker::new().add(1u8).mul(u128::MAX).current(),
+            ParityKind::Odd
+        );
+        sum.reset();
+        assert_eq!(sum.current(), ParityKind::Even);
+    }
+
+    #[test]
+    fn signs() {
+        for n in -10i32..10 {
+            assert_eq!(sign_pow(n), (-1i32).pow(n.unsigned_abs()));
+            assert_eq!(sign_pow_f64(n), (-1.0f64).powi(n));
+            assert_eq!(signed_by_parity(7i16, n), 7 * (-1i16).pow(n.unsigned_abs()));
+        }
+        assert_eq!(sign_pow(u128::MAX), -1);
+        assert_eq!(signed_by_parity(-0.0f64, 1u8).to_bits(), 0.0f64.to_bits());
+        assert_eq!(
+            signed_by_parity(core::num::Wrapping(1u8), 1),
+            core::num::Wrapping(u8::MAX)
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Parity of IP addresses and ports, for splitting traffic into A/B routes or test partitions.
+//!
+//! An address has the parity of its numeric form, which is that of its last octet or segment, and a socket
+//! address has the parity of its IP address. [PortParity] gives the parity of the port instead.
+//!
+//! # Example
+//! ```
+//! use core::net::{Ipv4Addr, SocketAddr};
+//! use parity::net::PortParity;
+//! use parity::{Parity, ParityKind};
+//!
+//! assert!(Ipv4Addr::new(192, 168, 1, 7).is_odd());
+//! let peer: SocketAddr = "[2001:db8::10]:8081".parse()?;
+//! assert!(peer.is_even());
+//! assert_eq!(peer.port_parity(), ParityKind::Odd);
+//! # Ok::<(), core::net::AddrParseError>(())
+//! ```
+
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// An IPv4 address has the parity of its last octet.
+impl Parity for Ipv4Addr {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.octets()[3].is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.octets()[3].is_odd()
+    }
+}
+
+/// An IPv6 address has the parity of its last octet, the low byte of its last segment.
+impl Parity for Ipv6Addr {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.octets()[15].is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.octets()[15].is_odd()
+    }
+}
+
+/// An IP address has the parity of the IPv4 or IPv6 address it holds.
+///
+/// An IPv4-mapped IPv6 address such as `::ffff:10.0.0.1` ends in the octets of the IPv4 address, so it has the
+/// same parity.
+impl Parity for IpAddr {
+    #[inline]
+    fn is_even(&self) -> bool {
+        !self.is_odd()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        match self {
+            IpAddr::V4(ip) => ip.is_odd(),
+            IpAddr::V6(ip) => ip.is_odd(),
+        }
+    }
+}
+
+macro_rules! impl_socket_parity {
+    ($($T:ty),*) => { $(
+        /// A socket address has the parity of its IP address; see [PortParity] for the parity of its port.
+        impl Parity for $T {
+            #[inline]
+            fn is_even(&self) -> bool {
+                self.ip().is_even()
+            }
+
+            #[inline]
+            fn is_odd(&self) -> bool {
+                self.ip().is_odd()
+            }
+        }
+
+        impl PortParity for $T {
+            #[inline]
+            fn port_parity(&self) -> ParityKind {
+                self.port().parity()
+            }
+        }
+    )* };
+}
+
+/// Parity of the port of a socket address.
+pub trait PortParity {
+    /// Returns the parity of the port number.
+    fn port_parity(&self) -> ParityKind;
+}
+
+impl_socket_parity![SocketAddr, SocketAddrV4, SocketAddrV6];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn addresses() {
+        for last in 0..=u8::MAX {
+            let v4 = Ipv4Addr::new(10, 0, 0, last);
+            assert_eq!(v4.checked_parity(), Some(last.parity()));
+            assert_eq!(IpAddr::V4(v4).is_odd(), last.is_odd());
+            assert_eq!(IpAddr::V6(v4.to_ipv6_mapped()).is_odd(), last.is_odd());
+            assert_eq!(u32::from(v4).parity(), last.parity());
+        }
+        let v6 = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0x1, 0x2ff);
+        assert!(v6.is_odd());
+        assert_eq!(u128::from(v6).parity(), ParityKind::Odd);
+        assert!(Ipv6Addr::UNSPECIFIED.is_even());
+    }
+
+    #[test]
+    fn sockets() {
+        let v4 = SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 8080);
+        assert!(v4.is_odd());
+        assert_eq!(v4.port_parity(), ParityKind::Even);
+        let v6 = SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0);
+        assert!(v6.is_odd());
+        assert_eq!(v6.port_parity(), ParityKind::Odd);
+        assert_eq!(SocketAddr::V4(v4).port_parity(), ParityKind::Even);
+        assert!(SocketAddr::V6(v6).same_parity(&SocketAddr::V4(v4)));
+    }
+}
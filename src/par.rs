@@ -0,0 +1,78 @@
+//! Parity statistics over slices, computed in parallel with `rayon`.
+//!
+//! # Example
+//! ```
+//! use parity::par::{par_all_even, par_count_parities};
+//!
+//! let values: Vec<u64> = (0..1_000_000).map(|i| i * 2).collect();
+//! assert!(par_all_even(&values));
+//! assert_eq!(par_count_parities(&values).even, 1_000_000);
+//! ```
+
+use alloc::vec::Vec;
+
+use rayon::prelude::*;
+
+use crate::slice::bitmap_word;
+use crate::{Parity, ParityCount};
+
+/// Counts the even and odd elements of `values` in parallel. Elements without a parity are not counted.
+///
+/// See [ParityIterator::count_parities](crate::ParityIterator::count_parities).
+pub fn par_count_parities<T: Parity + Sync>(values: &[T]) -> ParityCount {
+    values
+        .par_iter()
+        .fold(ParityCount::default, |mut count, value| {
+            if let Some(parity) = value.checked_parity() {
+                count.record(parity);
+            }
+            count
+        })
+        .reduce(ParityCount::default, |a, b| ParityCount {
+            even: a.even + b.even,
+            odd: a.odd + b.odd,
+        })
+}
+
+/// Returns `true` if every element of `values` is even, checking in parallel and stopping early at an element that
+/// is not.
+pub fn par_all_even<T: Parity + Sync>(values: &[T]) -> bool {
+    values.par_iter().all(T::is_even)
+}
+
+/// Returns `true` if every element of `values` is odd, checking in parallel and stopping early at an element that
+/// is not.
+pub fn par_all_odd<T: Parity + Sync>(values: &[T]) -> bool {
+    values.par_iter().all(T::is_odd)
+}
+
+/// Returns the [parity_bitmap](crate::slice::parity_bitmap) of `values`, computing the words in parallel.
+pub fn par_parity_bitmap<T: Parity + Sync>(values: &[T]) -> Vec<u64> {
+    values.par_chunks(64).map(bitmap_word).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::slice::parity_bitmap;
+    use crate::ParityIterator;
+
+    #[test]
+    fn matches_sequential() {
+        let values: Vec<i32> = (-5000..5000).map(|i| i * 7 / 3).collect();
+        assert_eq!(
+            par_count_parities(&values),
+            values.iter().copied().count_parities()
+        );
+        assert_eq!(par_parity_bitmap(&values), parity_bitmap(&values));
+        assert!(!par_all_even(&values) && !par_all_odd(&values));
+
+        let evens: Vec<u8> = (0..=254).step_by(2).collect();
+        assert!(par_all_even(&evens));
+        assert!(par_all_odd::<u8>(&[]));
+        let floats = [2.0, 0.5, 3.0, f64::NAN];
+        assert_eq!(par_count_parities(&floats).total(), 2);
+    }
+}
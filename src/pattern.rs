@@ -0,0 +1,406 @@
+//! Declarative patterns over the parities of a sequence.
+//!
+//! A [ParityPattern] is a fixed-length sequence of positions that each accept even values, odd values or any value.
+//! It can be built with its methods or parsed from a string of `E` for even, `O` for odd and `?` for any value, each
+//! optionally followed by a repetition count, so `"O2E"` is two odd values followed by an even one. Letters may be
+//! lowercase, and whitespace is ignored.
+//!
+//! # Example
+//! ```
+//! use parity::pattern::ParityPattern;
+//!
+//! let pattern: ParityPattern = "O2E".parse().unwrap();
+//! assert!(pattern.matches(&[3, 5, 4]));
+//! assert_eq!(pattern.find(&[2, 1, 1, 8, 9]), Some(1));
+//!
+//! // values must alternate, starting even
+//! let alternating = ParityPattern::new().even(1).odd(1);
+//! assert!(alternating.matches_cycle(&[0, 7, 2, 3, 10]));
+//! assert!(!alternating.matches_cycle(&[1, 2]));
+//! ```
+
+use core::fmt;
+use core::iter::{self, FusedIterator};
+use core::str::FromStr;
+
+use alloc::vec::Vec;
+
+use crate::{Parity, ParityKind};
+
+/// A run of positions of a [ParityPattern] that accept the same parity, or any value if `parity` is `None`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Segment {
+    parity: Option<ParityKind>,
+    count: usize,
+}
+
+/// A sequence of positions that each accept even values, odd values or any value. See the
+/// [module documentation](self) for the string syntax.
+///
+/// Adjacent positions accepting the same values are merged, so patterns describing the same positions compare equal
+/// however they were written.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ParityPattern {
+    segments: Vec<Segment>,
+    len: usize,
+}
+
+impl ParityPattern {
+    /// Returns an empty pattern, which only matches an empty sequence.
+    #[inline]
+    pub const fn new() -> Self {
+        ParityPattern {
+            segments: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Appends `count` positions accepting values of the given `parity`, or any value if `parity` is `None`.
+    ///
+    /// # Panics
+    /// Panics if the length of the pattern overflows `usize`.
+    #[must_use]
+    pub fn then(mut self, parity: Option<ParityKind>, count: usize) -> Self {
+        self.len = self
+            .len
+            .checked_add(count)
+            .expect("the length of the pattern overflows");
+        if count == 0 {
+            return self;
+        }
+        match self.segments.last_mut() {
+            Some(last) if last.parity == parity => last.count += count,
+            _ => self.segments.push(Segment { parity, count }),
+        }
+        self
+    }
+
+    /// Appends `count` positions accepting even values.
+    #[inline]
+    #[must_use]
+    pub fn even(self, count: usize) -> Self {
+        self.then(Some(ParityKind::Even), count)
+    }
+
+    /// Appends `count` positions accepting odd values.
+    #[inline]
+    #[must_use]
+    pub fn odd(self, count: usize) -> Self {
+        self.then(Some(ParityKind::Odd), count)
+    }
+
+    /// Appends `count` positions accepting any value, including values without a parity.
+    #[inline]
+    #[must_use]
+    pub fn any(self, count: usize) -> Self {
+        self.then(None, count)
+    }
+
+    /// Returns the number of positions in the pattern.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the pattern has no positions.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns what each position accepts, in order: a parity, or `None` for any value.
+    pub fn positions(&self) -> impl Iterator<Item = Option<ParityKind>> + Clone + '_ {
+        self.segments
+            .iter()
+            .flat_map(|segment| iter::repeat_n(segment.parity, segment.count))
+    }
+
+    /// Returns `true` if `values` has exactly the length of the pattern and every value is accepted by its position.
+    ///
+    /// Values without a parity are only accepted by positions accepting any value.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::pattern::ParityPattern;
+    ///
+    /// let pattern: ParityPattern = "E?O".parse().unwrap();
+    /// assert!(pattern.matches(&[2.0, 0.5, 1.0]));
+    /// assert!(!pattern.matches(&[2.0, 0.5, 1.0, 3.0]));
+    /// ```
+    pub fn matches<T: Parity>(&self, values: &[T]) -> bool {
+        values.len() == self.len && self.accepts(values)
+    }
+
+    /// Returns `true` if every value is accepted by the position at its index modulo the length of the pattern, so
+    /// that `values` is the pattern repeated, possibly with a final partial repetition. An empty pattern only matches
+    /// an empty sequence.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::pattern::ParityPattern;
+    ///
+    /// let pattern: ParityPattern = "OE2".parse().unwrap();
+    /// assert!(pattern.matches_cycle(&[1, 2, 4, 3, 6]));
+    /// assert!(!pattern.matches_cycle(&[1, 2, 3]));
+    /// ```
+    pub fn matches_cycle<T: Parity>(&self, values: &[T]) -> bool {
+        if self.is_empty() {
+            return values.is_empty();
+        }
+        values
+            .iter()
+            .zip(self.positions().cycle())
+            .all(|(value, position)| accepts(position, value))
+    }
+
+    /// Returns the index of the first window of `values` that the pattern [matches](ParityPattern::matches).
+    ///
+    /// # Example
+    /// ```
+    /// use parity::pattern::ParityPattern;
+    ///
+    /// let pattern = ParityPattern::new().odd(2);
+    /// assert_eq!(pattern.find(&[1, 2, 3, 5]), Some(2));
+    /// assert_eq!(pattern.find(&[1, 2, 3]), None);
+    /// ```
+    pub fn find<T: Parity>(&self, values: &[T]) -> Option<usize> {
+        self.match_indices(values).next().map(|(index, _)| index)
+    }
+
+    /// Returns an iterator over the non-overlapping windows of `values` that the pattern matches, as their start
+    /// index and the window, scanning from the front.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::pattern::ParityPattern;
+    ///
+    /// let pattern: ParityPattern = "EO".parse().unwrap();
+    /// let found: Vec<usize> = pattern.match_indices(&[2, 3, 5, 4, 1, 6]).map(|(i, _)| i).collect();
+    /// assert_eq!(found, [0, 3]);
+    /// ```
+    #[inline]
+    pub fn match_indices<'a, T: Parity>(&'a self, values: &'a [T]) -> MatchIndices<'a, T> {
+        MatchIndices {
+            pattern: self,
+            values,
+            start: 0,
+        }
+    }
+
+    /// Returns `true` if each value of `window`, which must not be longer than the pattern, is accepted by its
+    /// position.
+    fn accepts<T: Parity>(&self, window: &[T]) -> bool {
+        window
+            .iter()
+            .zip(self.positions())
+            .all(|(value, position)| accepts(position, value))
+    }
+}
+
+/// Returns `true` if a position accepting the given parity, or any value if it is `None`, accepts `value`.
+#[inline]
+fn accepts<T: Parity>(position: Option<ParityKind>, value: &T) -> bool {
+    position.is_none() || value.checked_parity() == position
+}
+
+/// Formats the pattern in the string syntax, with repetition counts for runs longer than one position, such as
+/// `O2E?`.
+impl fmt::Display for ParityPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            f.write_str(match segment.parity {
+                Some(ParityKind::Even) => "E",
+                Some(ParityKind::Odd) => "O",
+                None => "?",
+            })?;
+            if segment.count > 1 {
+                write!(f, "{}", segment.count)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when parsing a [ParityPattern] from an invalid string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParsePatternError {
+    index: usize,
+    invalid_count: bool,
+}
+
+impl ParsePatternError {
+    /// Returns the byte index in the string of the character or repetition count that could not be parsed.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for ParsePatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.invalid_count {
+            write!(
+                f,
+                "the repetition count at index {} is zero or too large",
+                self.index
+            )
+        } else {
+            write!(
+                f,
+                "expected `E`, `O` or `?` at index {} of the parity pattern",
+                self.index
+            )
+        }
+    }
+}
+
+impl core::error::Error for ParsePatternError {}
+
+impl FromStr for ParityPattern {
+    type Err = ParsePatternError;
+
+    fn from_str(s: &str) -> Result<Self, ParsePatternError> {
+        let mut pattern = ParityPattern::new();
+        let mut chars = s.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            let parity = match c {
+                'E' | 'e' => Some(ParityKind::Even),
+                'O' | 'o' => Some(ParityKind::Odd),
+                '?' => None,
+                c if c.is_whitespace() => continue,
+                _ => {
+                    return Err(ParsePatternError {
+                        index,
+                        invalid_count: false,
+                    })
+                }
+            };
+            let mut count = None;
+            while let Some(&(digit_index, digit)) = chars.peek() {
+                let Some(digit) = digit.to_digit(10) else {
+                    break;
+                };
+                chars.next();
+                let error = ParsePatternError {
+                    index: digit_index,
+                    invalid_count: true,
+                };
+                count = Some(
+                    count
+                        .unwrap_or(0usize)
+                        .checked_mul(10)
+                        .and_then(|count| count.checked_add(digit as usize))
+                        .ok_or(error)?,
+                );
+            }
+            let count = count.unwrap_or(1);
+            if count == 0 || pattern.len.checked_add(count).is_none() {
+                return Err(ParsePatternError {
+                    index: index + c.len_utf8(),
+                    invalid_count: true,
+                });
+            }
+            pattern = pattern.then(parity, count);
+        }
+        Ok(pattern)
+    }
+}
+
+/// An iterator over the non-overlapping matches of a [ParityPattern] in a slice, returned by
+/// [ParityPattern::match_indices].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MatchIndices<'a, T> {
+    pattern: &'a ParityPattern,
+    values: &'a [T],
+    start: usize,
+}
+
+impl<T> Clone for MatchIndices<'_, T> {
+    fn clone(&self) -> Self {
+        MatchIndices {
+            pattern: self.pattern,
+            values: self.values,
+            start: self.start,
+        }
+    }
+}
+
+impl<'a, T: Parity> Iterator for MatchIndices<'a, T> {
+    type Item = (usize, &'a [T]);
+
+    fn next(&mut self) -> Option<(usize, &'a [T])> {
+        let len = self.pattern.len();
+        while self.start <= self.values.len() {
+            let window = self.values.get(self.start..self.start.checked_add(len)?)?;
+            let index = self.start;
+            if self.pattern.accepts(window) {
+                // an empty pattern matches at every index, so it still has to move on
+                self.start += len.max(1);
+                return Some((index, window));
+            }
+            self.start += 1;
+        }
+        None
+    }
+}
+
+impl<T: Parity> FusedIterator for MatchIndices<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use std::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn parsing() {
+        let pattern: ParityPattern = "e o2 ?3E".parse().unwrap();
+        assert_eq!(pattern, ParityPattern::new().even(1).odd(2).any(3).even(1));
+        assert_eq!(pattern.len(), 7);
+        assert_eq!(pattern.to_string(), "EO2?3E");
+        assert_eq!("EEO".parse::<ParityPattern>().unwrap().to_string(), "E2O");
+        assert_eq!(
+            "".parse::<ParityPattern>().unwrap(),
+            ParityPattern::new().odd(0)
+        );
+
+        assert_eq!("EX".parse::<ParityPattern>().unwrap_err().index(), 1);
+        let zero = "O0".parse::<ParityPattern>().unwrap_err();
+        assert_eq!(zero.index(), 1);
+        assert_eq!(
+            zero.to_string(),
+            "the repetition count at index 1 is zero or too large"
+        );
+        assert!("E99999999999999999999999".parse::<ParityPattern>().is_err());
+        assert_eq!("2E".parse::<ParityPattern>().unwrap_err().index(), 0);
+    }
+
+    #[test]
+    fn matching() {
+        let pattern = ParityPattern::new().odd(2).even(1);
+        assert!(pattern.matches(&[1u8, 3, 0]));
+        assert!(!pattern.matches(&[1u8, 3]));
+        assert!(!pattern.matches(&[1.0, f64::NAN, 0.0]));
+        assert!(ParityPattern::new().any(1).matches(&[f64::NAN]));
+
+        let values = [1, 1, 2, 3, 5, 8, 7, 9, 4];
+        let found: std::vec::Vec<_> = pattern.match_indices(&values).collect();
+        assert_eq!(
+            found,
+            [
+                (0, &[1, 1, 2][..]),
+                (3, &[3, 5, 8][..]),
+                (6, &[7, 9, 4][..])
+            ]
+        );
+        assert_eq!(pattern.find(&values[1..]), Some(2));
+        assert_eq!(pattern.find(&[1, 1]), None);
+
+        let empty = ParityPattern::new();
+        assert!(empty.matches::<u8>(&[]));
+        assert_eq!(empty.match_indices(&[1, 2]).count(), 3);
+        assert!(empty.matches_cycle::<u8>(&[]) && !empty.matches_cycle(&[1]));
+        assert!(pattern.matches_cycle(&[3, 5, 2, 1]));
+    }
+}
@@ -0,0 +1,477 @@
+//! Parity of permutations and sequences.
+//!
+//! The parity of a permutation is the parity of the number of transpositions needed to produce it, which is also the
+//! parity of its number of inversions. Even permutations have sign `+1` and odd ones sign `-1`.
+
+use core::fmt;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::ParityKind;
+
+/// The error returned when a slice of indices is not a permutation of `0..len`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct NotAPermutation {
+    index: usize,
+}
+
+impl NotAPermutation {
+    /// Returns the position of the first element that is out of range or repeats an earlier one.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NotAPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "element {} is out of range or repeated, so the slice is not a permutation",
+            self.index
+        )
+    }
+}
+
+impl core::error::Error for NotAPermutation {}
+
+/// Returns the parity of the permutation that maps `i` to `perm[i]`.
+///
+/// Runs in linear time by counting cycles: a permutation of `n` elements with `c` cycles is a product of `n - c`
+/// transpositions.
+///
+/// # Errors
+/// Fails if `perm` is not a permutation of `0..perm.len()`.
+///
+/// # Example
+/// ```
+/// use parity::perm::permutation_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(permutation_parity(&[0, 1, 2]), Ok(ParityKind::Even));
+/// assert_eq!(permutation_parity(&[1, 0, 2]), Ok(ParityKind::Odd));
+/// assert_eq!(permutation_parity(&[1, 2, 0]), Ok(ParityKind::Even));
+/// assert_eq!(permutation_parity(&[0, 0, 1]).unwrap_err().index(), 1);
+/// ```
+pub fn permutation_parity(perm: &[usize]) -> Result<ParityKind, NotAPermutation> {
+    let mut seen = vec![false; perm.len()];
+    for (index, &value) in perm.iter().enumerate() {
+        match seen.get_mut(value) {
+            Some(seen @ false) => *seen = true,
+            _ => return Err(NotAPermutation { index }),
+        }
+    }
+
+    // every element is now marked, so clear the marks while walking each cycle once
+    let mut cycles = 0;
+    for start in 0..perm.len() {
+        if !seen[start] {
+            continue;
+        }
+        cycles += 1;
+        let mut i = start;
+        while seen[i] {
+            seen[i] = false;
+            i = perm[i];
+        }
+    }
+    Ok(ParityKind::from_odd(
+        !(perm.len() - cycles).is_multiple_of(2),
+    ))
+}
+
+/// Returns the parity of the permutation with the given disjoint cycles, such as `[[0, 2, 1], [3, 4]]` for the
+/// permutation mapping `0 → 2 → 1 → 0` and swapping 3 and 4.
+///
+/// A cycle of length `k` is a product of `k - 1` transpositions, so only the cycle lengths matter and the elements
+/// are not inspected; passing cycles that overlap gives the parity of their product, composed in any order. Fixed
+/// points may be listed as cycles of length one or left out.
+///
+/// # Example
+/// ```
+/// use parity::perm::parity_from_cycles;
+/// use parity::ParityKind;
+///
+/// assert_eq!(parity_from_cycles(&[vec![0, 2, 1], vec![3, 4]]), ParityKind::Odd);
+/// assert_eq!(parity_from_cycles(&[[5, 6], [7, 8]]), ParityKind::Even);
+/// assert_eq!(parity_from_cycles::<Vec<usize>>(&[]), ParityKind::Even);
+/// ```
+#[inline]
+pub fn parity_from_cycles<C: AsRef<[usize]>>(cycles: &[C]) -> ParityKind {
+    parity_from_cycle_type(cycles.iter().map(|cycle| cycle.as_ref().len()))
+}
+
+/// Returns the parity of a permutation with cycles of the given lengths, its cycle type.
+///
+/// A permutation is odd exactly when it has an odd number of cycles of even length. Lengths of zero and one stand
+/// for no transpositions.
+///
+/// # Example
+/// ```
+/// use parity::perm::parity_from_cycle_type;
+/// use parity::ParityKind;
+///
+/// // a 3-cycle and a transposition
+/// assert_eq!(parity_from_cycle_type([3, 2]), ParityKind::Odd);
+/// // the rotation of a cube by a quarter turn about a face axis permutes its 8 corners in two 4-cycles
+/// assert_eq!(parity_from_cycle_type([4, 4]), ParityKind::Even);
+/// ```
+pub fn parity_from_cycle_type<I: IntoIterator<Item = usize>>(lengths: I) -> ParityKind {
+    let odd = lengths
+        .into_iter()
+        .fold(false, |odd, len| odd ^ (len.saturating_sub(1) % 2 == 1));
+    ParityKind::from_odd(odd)
+}
+
+/// Returns the Levi-Civita symbol `ε` of `indices`: `1` for an even permutation of `0..len`, `-1` for an odd one and
+/// `0` if any index repeats.
+///
+/// # Panics
+/// Panics if an index is not less than `indices.len()`.
+///
+/// # Example
+/// The z component of a cross product, `Σ ε(2, j, k) a[j] b[k]`:
+/// ```
+/// use parity::perm::levi_civita;
+///
+/// let (a, b) = ([1, 2, 3], [4, 5, 6]);
+/// let mut z = 0;
+/// for j in 0..3 {
+///     for k in 0..3 {
+///         z += i32::from(levi_civita(&[2, j, k])) * a[j] * b[k];
+///     }
+/// }
+/// assert_eq!(z, a[0] * b[1] - a[1] * b[0]);
+/// ```
+pub fn levi_civita(indices: &[usize]) -> i8 {
+    if let Some(&index) = indices.iter().find(|&&index| index >= indices.len()) {
+        panic!(
+            "index {index} is out of range for a Levi-Civita symbol of order {}",
+            indices.len()
+        );
+    }
+    match permutation_parity(indices) {
+        Ok(ParityKind::Even) => 1,
+        Ok(ParityKind::Odd) => -1,
+        Err(_) => 0,
+    }
+}
+
+/// Returns the parity of the number of inversions in `seq`: pairs `i < j` with `seq[i] > seq[j]`.
+///
+/// This is the parity of any sequence of swaps that sorts `seq`, and for a permutation of `0..len` it equals
+/// [permutation_parity]. Equal elements do not form an inversion. Runs in `O(n log n)` time with a merge sort over
+/// references, leaving `seq` untouched.
+///
+/// # Example
+/// ```
+/// use parity::perm::inversion_parity;
+/// use parity::ParityKind;
+///
+/// assert_eq!(inversion_parity(&["b", "a", "c"]), ParityKind::Odd);
+/// assert_eq!(inversion_parity(&[3, 1, 2]), ParityKind::Even);
+/// assert_eq!(inversion_parity(&[1, 1, 1]), ParityKind::Even);
+/// ```
+pub fn inversion_parity<T: Ord>(seq: &[T]) -> ParityKind {
+    let mut items: Vec<&T> = seq.iter().collect();
+    let mut buf = Vec::with_capacity(items.len());
+    ParityKind::from_odd(merge_sort_inversions(&mut items, &mut buf))
+}
+
+/// Sorts `items`, returning whether the number of inversions was odd.
+fn merge_sort_inversions<'a, T: Ord>(items: &mut [&'a T], buf: &mut Vec<&'a T>) -> bool {
+    if items.len() < 2 {
+        return false;
+    }
+    let mid = items.len() / 2;
+    let mut odd = merge_sort_inversions(&mut items[..mid], buf);
+    odd ^= merge_sort_inversions(&mut items[mid..], buf);
+
+    buf.clear();
+    let (mut i, mut j) = (0, mid);
+    while i < mid && j < items.len() {
+        if items[j] < items[i] {
+            // the right element jumps over every remaining left element
+            odd ^= !(mid - i).is_multiple_of(2);
+            buf.push(items[j]);
+            j += 1;
+        } else {
+            buf.push(items[i]);
+            i += 1;
+        }
+    }
+    buf.extend_from_slice(&items[i..mid]);
+    buf.extend_from_slice(&items[j..]);
+    items.copy_from_slice(buf);
+    odd
+}
+
+/// Returns `true` if the 15 puzzle with `tiles` can be slid into order.
+///
+/// `tiles` lists the board in row-major order with `0` for the blank, and the solved board is `1` to `15` followed
+/// by the blank. See [sliding_puzzle_solvable] for the rule and for other board sizes.
+///
+/// # Panics
+/// Panics if `tiles` is not a permutation of `0..16`.
+///
+/// # Example
+/// ```
+/// use parity::perm::fifteen_puzzle_solvable;
+///
+/// let solved = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0];
+/// assert!(fifteen_puzzle_solvable(&solved));
+///
+/// // Sam Loyd's puzzle, with 14 and 15 swapped, cannot be solved
+/// let loyd = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 15, 14, 0];
+/// assert!(!fifteen_puzzle_solvable(&loyd));
+/// ```
+#[track_caller]
+pub fn fifteen_puzzle_solvable(tiles: &[u8; 16]) -> bool {
+    sliding_puzzle_solvable(&tiles.map(usize::from), 4)
+}
+
+/// Returns `true` if the sliding puzzle on a `size` by `size` board with `tiles` can be slid into order.
+///
+/// `tiles` lists the board in row-major order with `0` for the blank, and the solved board is `1` to
+/// `size * size - 1` followed by the blank. Every slide swaps the blank with a neighbouring tile, flipping both the
+/// parity of the arrangement as a permutation and the parity of the blank's taxicab distance from the bottom-right
+/// corner. The two parities therefore stay equal or unequal for good, and a board is solvable exactly when they are
+/// equal.
+///
+/// # Panics
+/// Panics if `size` is zero, since the board then has no blank, or if `tiles` is not a permutation of
+/// `0..size * size`.
+///
+/// # Example
+/// ```
+/// use parity::perm::sliding_puzzle_solvable;
+///
+/// // the 8 puzzle with the blank moved up from the solved board
+/// assert!(sliding_puzzle_solvable(&[1, 2, 3, 4, 5, 0, 7, 8, 6], 3));
+/// assert!(!sliding_puzzle_solvable(&[2, 1, 3, 4, 5, 6, 7, 8, 0], 3));
+/// ```
+#[track_caller]
+pub fn sliding_puzzle_solvable(tiles: &[usize], size: usize) -> bool {
+    assert!(size > 0, "a sliding puzzle board needs at least one cell");
+    let cells = size.checked_mul(size);
+    assert!(
+        cells == Some(tiles.len()),
+        "{} tiles do not fill a {size}x{size} board",
+        tiles.len()
+    );
+    // the solved position of each tile, with the blank last
+    let targets: Vec<usize> = tiles
+        .iter()
+        .map(|&tile| if tile == 0 { tiles.len() } else { tile } - 1)
+        .collect();
+    let arrangement = match permutation_parity(&targets) {
+        Ok(parity) => parity,
+        Err(err) => panic!(
+            "tile {} is out of range or repeated, so the board is not a {size}x{size} puzzle",
+            tiles[err.index()]
+        ),
+    };
+    let blank = targets
+        .iter()
+        .position(|&target| target == tiles.len() - 1)
+        .expect("a permutation contains the blank");
+    let distance = (size - 1 - blank / size) + (size - 1 - blank % size);
+    arrangement == ParityKind::from_odd(!distance.is_multiple_of(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parity_by_inversions(perm: &[usize]) -> ParityKind {
+        let mut inversions = 0;
+        for i in 0..perm.len() {
+            for j in i + 1..perm.len() {
+                inversions += usize::from(perm[i] > perm[j]);
+            }
+        }
+        ParityKind::from_odd(inversions % 2 == 1)
+    }
+
+    #[test]
+    fn matches_inversion_count() {
+        let mut perm = [0, 1, 2, 3, 4, 5];
+        // walk all permutations in lexicographic order
+        loop {
+            assert_eq!(permutation_parity(&perm), Ok(parity_by_inversions(&perm)));
+            let Some(i) = (0..perm.len() - 1).rev().find(|&i| perm[i] < perm[i + 1]) else {
+                break;
+            };
+            let j = (i + 1..perm.len())
+                .rev()
+                .find(|&j| perm[j] > perm[i])
+                .unwrap();
+            perm.swap(i, j);
+            perm[i + 1..].reverse();
+        }
+        assert_eq!(permutation_parity(&[]), Ok(ParityKind::Even));
+    }
+
+    #[test]
+    fn rejects_non_permutations() {
+        assert_eq!(
+            permutation_parity(&[0, 3, 1]),
+            Err(NotAPermutation { index: 1 })
+        );
+        assert_eq!(
+            permutation_parity(&[2, 0, 2]),
+            Err(NotAPermutation { index: 2 })
+        );
+        assert_eq!(permutation_parity(&[1]), Err(NotAPermutation { index: 0 }));
+    }
+
+    #[test]
+    fn inversions() {
+        let mut perm = [0, 1, 2, 3, 4];
+        for round in 0..200usize {
+            assert_eq!(inversion_parity(&perm), parity_by_inversions(&perm));
+            assert_eq!(Ok(inversion_parity(&perm)), permutation_parity(&perm));
+            perm.swap(round % 5, (round * 7 + 3) % 5);
+        }
+
+        let seq = [5, 3, 5, 1, 3, 9, 0, 0, 2];
+        let mut count = 0;
+        for i in 0..seq.len() {
+            for j in i + 1..seq.len() {
+                count += usize::from(seq[i] > seq[j]);
+            }
+        }
+        assert_eq!(inversion_parity(&seq), ParityKind::from_odd(count % 2 == 1));
+        assert_eq!(inversion_parity::<u8>(&[]), ParityKind::Even);
+    }
+
+    #[test]
+    fn cycles() {
+        // 0 → 3 → 5 → 0, 1 ↔ 4, 2 and 6 fixed
+        let cycles = [vec![0, 3, 5], vec![1, 4], vec![2]];
+        let mut perm: Vec<usize> = (0..7).collect();
+        for cycle in &cycles {
+            for (i, &from) in cycle.iter().enumerate() {
+                perm[from] = cycle[(i + 1) % cycle.len()];
+            }
+        }
+        assert_eq!(perm, [3, 4, 2, 5, 1, 0, 6]);
+        assert_eq!(Ok(parity_from_cycles(&cycles)), permutation_parity(&perm));
+        assert_eq!(parity_from_cycles(&cycles[..1]), ParityKind::Even);
+
+        for len in 0..10usize {
+            let rotation: Vec<usize> = (0..len).map(|i| (i + 1) % len).collect();
+            assert_eq!(
+                Ok(parity_from_cycle_type([len])),
+                permutation_parity(&rotation)
+            );
+        }
+        assert_eq!(parity_from_cycle_type([2, 2, 2]), ParityKind::Odd);
+        assert_eq!(parity_from_cycle_type([0, 1, 1, 5]), ParityKind::Even);
+    }
+
+    #[test]
+    fn levi_civita_symbol() {
+        assert_eq!(levi_civita(&[0, 1, 2]), 1);
+        assert_eq!(levi_civita(&[2, 0, 1]), 1);
+        assert_eq!(levi_civita(&[0, 2, 1]), -1);
+        assert_eq!(levi_civita(&[1, 1, 0]), 0);
+        assert_eq!(levi_civita(&[0, 1]), 1);
+        assert_eq!(levi_civita(&[1, 0, 3, 2]), 1);
+        assert_eq!(levi_civita(&[]), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "index 3 is out of range")]
+    fn levi_civita_out_of_range() {
+        levi_civita(&[0, 3, 1]);
+    }
+
+    /// Returns every board reachable from the solved `size` by `size` board, by breadth-first search.
+    fn reachable_boards(size: usize) -> std::collections::BTreeSet<Vec<usize>> {
+        let cells = size * size;
+        let solved: Vec<usize> = (1..cells).chain([0]).collect();
+        let mut seen = std::collections::BTreeSet::from([solved.clone()]);
+        let mut queue = std::vec![solved];
+        while let Some(board) = queue.pop() {
+            let blank = board.iter().position(|&tile| tile == 0).unwrap();
+            let (row, col) = (blank / size, blank % size);
+            let neighbours = [
+                (row > 0).then(|| blank - size),
+                (row + 1 < size).then(|| blank + size),
+                (col > 0).then(|| blank - 1),
+                (col + 1 < size).then(|| blank + 1),
+            ];
+            for neighbour in neighbours.into_iter().flatten() {
+                let mut next = board.clone();
+                next.swap(blank, neighbour);
+                if seen.insert(next.clone()) {
+                    queue.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    #[test]
+    fn sliding_puzzles_match_search() {
+        for size in 1..=3 {
+            let reachable = reachable_boards(size);
+            let mut count = 0usize;
+            for_each_permutation(size * size, &mut |board: &[usize]| {
+                count += 1;
+                assert_eq!(
+                    sliding_puzzle_solvable(board, size),
+                    reachable.contains(board),
+                    "{board:?}"
+                );
+            });
+            // exactly half of the boards are solvable
+            assert_eq!(reachable.len(), count.div_ceil(2));
+        }
+    }
+
+    /// Calls `f` with every permutation of `0..n`.
+    fn for_each_permutation(n: usize, f: &mut impl FnMut(&[usize])) {
+        fn permute(items: &mut [usize], k: usize, f: &mut impl FnMut(&[usize])) {
+            if k == items.len() {
+                return f(items);
+            }
+            for i in k..items.len() {
+                items.swap(k, i);
+                permute(items, k + 1, f);
+                items.swap(k, i);
+            }
+        }
+        permute(&mut (0..n).collect::<Vec<_>>(), 0, f);
+    }
+
+    #[test]
+    fn fifteen_puzzle() {
+        let mut tiles = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0];
+        tiles.swap(15, 11);
+        tiles.swap(11, 10);
+        assert!(fifteen_puzzle_solvable(&tiles));
+        tiles.swap(0, 1);
+        assert!(!fifteen_puzzle_solvable(&tiles));
+    }
+
+    #[test]
+    #[should_panic(expected = "tile 3 is out of range or repeated")]
+    fn sliding_puzzle_needs_a_permutation() {
+        sliding_puzzle_solvable(&[1, 2, 3, 3], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "5 tiles do not fill a 2x2 board")]
+    fn sliding_puzzle_needs_a_square() {
+        sliding_puzzle_solvable(&[1, 2, 3, 4, 0], 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs at least one cell")]
+    fn sliding_puzzle_needs_a_cell() {
+        sliding_puzzle_solvable(&[], 0);
+    }
+}
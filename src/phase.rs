@@ -0,0 +1,247 @@
+//! Behavior that alternates with the parity of a counter.
+//!
+//! Two-phase algorithms, such as mark-and-sweep passes that flip the meaning of a mark bit every cycle, count their
+//! epochs and act on the parity of the count. [PhaseFlag] keeps that count and exposes its parity, and
+//! [DoubleBuffer] uses it to pick between two buffers every frame.
+
+use crate::{Parity, ParityKind};
+
+/// A counter of epochs whose parity is the current phase.
+///
+/// The counter starts at zero, in the [Even](ParityKind::Even) phase, and only moves forward with
+/// [advance](PhaseFlag::advance). It wraps around after `u64::MAX` epochs, which keeps the phases alternating since
+/// `u64::MAX` is odd.
+///
+/// # Example
+/// ```
+/// use parity::phase::PhaseFlag;
+/// use parity::ParityKind;
+///
+/// // a mark bit whose meaning flips every collection, so marks never need clearing
+/// let mut epoch = PhaseFlag::new();
+/// let mut marked = [false; 4];
+/// let is_live = |mark: bool, epoch: &PhaseFlag| mark == epoch.phase().is_odd();
+///
+/// epoch.advance();
+/// marked[1] = epoch.phase().is_odd();
+/// assert!(is_live(marked[1], &epoch) && !is_live(marked[0], &epoch));
+///
+/// assert_eq!(epoch.advance(), ParityKind::Even);
+/// assert!(!is_live(marked[1], &epoch));
+/// assert_eq!(epoch.epoch(), 2);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PhaseFlag {
+    epoch: u64,
+}
+
+impl PhaseFlag {
+    /// Returns a flag at epoch zero, in the even phase.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::at_epoch(0)
+    }
+
+    /// Returns a flag at the given `epoch`, such as one restored from a saved state.
+    #[inline]
+    pub const fn at_epoch(epoch: u64) -> Self {
+        PhaseFlag { epoch }
+    }
+
+    /// Returns the number of epochs the flag has advanced through.
+    #[inline]
+    pub const fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Returns the current phase, the parity of the epoch.
+    #[inline]
+    pub const fn phase(&self) -> ParityKind {
+        ParityKind::from_odd(self.epoch & 1 != 0)
+    }
+
+    /// Moves to the next epoch and returns its phase, the opposite of the previous one.
+    #[inline]
+    pub fn advance(&mut self) -> ParityKind {
+        self.epoch = self.epoch.wrapping_add(1);
+        self.phase()
+    }
+}
+
+/// The parity of the epoch, so a flag is even during even phases.
+impl Parity for PhaseFlag {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.phase().is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.phase().is_odd()
+    }
+
+    #[inline]
+    fn is_parity_defined(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn checked_parity(&self) -> Option<ParityKind> {
+        Some(self.phase())
+    }
+}
+
+/// Two buffers that trade places every frame, chosen by the parity of a frame counter.
+///
+/// Frame `n` uses buffer `n % 2` as its current buffer, and the other one holds what the previous frame produced.
+/// [swap](DoubleBuffer::swap) moves to the next frame, so the buffer that was current becomes the previous one and
+/// the old previous buffer is reused, without moving either.
+///
+/// # Example
+/// ```
+/// use parity::phase::DoubleBuffer;
+///
+/// // a one-dimensional cellular automaton, stepping each generation from the last one
+/// let mut cells = DoubleBuffer::new([false, false, true, false, false], [false; 5]);
+/// for _ in 0..2 {
+///     cells.swap();
+///     let (next, last) = cells.current_and_previous_mut();
+///     for i in 0..next.len() {
+///         let left = i.checked_sub(1).is_some_and(|l| last[l]);
+///         let right = last.get(i + 1).copied().unwrap_or(false);
+///         next[i] = left ^ right; // Rule 90
+///     }
+/// }
+/// assert_eq!(cells.frame(), 2);
+/// assert_eq!(*cells.current(), [true, false, false, false, true]);
+/// assert_eq!(*cells.previous(), [false, true, false, true, false]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DoubleBuffer<T> {
+    buffers: [T; 2],
+    frame: PhaseFlag,
+}
+
+impl<T> DoubleBuffer<T> {
+    /// Returns a double buffer at frame zero, with `current` as its current buffer and `previous` as the other one.
+    #[inline]
+    pub const fn new(current: T, previous: T) -> Self {
+        DoubleBuffer {
+            buffers: [current, previous],
+            frame: PhaseFlag::new(),
+        }
+    }
+
+    /// Returns the number of the current frame, which counts the calls to [swap](DoubleBuffer::swap).
+    #[inline]
+    pub const fn frame(&self) -> u64 {
+        self.frame.epoch()
+    }
+
+    /// Returns the parity of the current frame, which decides the current buffer.
+    #[inline]
+    pub const fn phase(&self) -> ParityKind {
+        self.frame.phase()
+    }
+
+    /// Moves to the next frame, making the current buffer the previous one.
+    #[inline]
+    pub fn swap(&mut self) {
+        self.frame.advance();
+    }
+
+    /// Returns the buffer of the current frame.
+    #[inline]
+    pub fn current(&self) -> &T {
+        &self.buffers[self.current_index()]
+    }
+
+    /// Returns the buffer of the current frame, to write to.
+    #[inline]
+    pub fn current_mut(&mut self) -> &mut T {
+        &mut self.buffers[self.current_index()]
+    }
+
+    /// Returns the buffer of the previous frame.
+    #[inline]
+    pub fn previous(&self) -> &T {
+        &self.buffers[1 - self.current_index()]
+    }
+
+    /// Returns the buffer of the previous frame, to write to.
+    #[inline]
+    pub fn previous_mut(&mut self) -> &mut T {
+        &mut self.buffers[1 - self.current_index()]
+    }
+
+    /// Returns the current buffer to write to alongside the previous buffer to read from, to compute a frame from
+    /// the last one.
+    #[inline]
+    pub fn current_and_previous_mut(&mut self) -> (&mut T, &T) {
+        let [even, odd] = &mut self.buffers;
+        match self.frame.phase() {
+            ParityKind::Even => (even, odd),
+            ParityKind::Odd => (odd, even),
+        }
+    }
+
+    /// Returns the buffers, the one used by even frames first.
+    #[inline]
+    pub fn into_inner(self) -> [T; 2] {
+        self.buffers
+    }
+
+    /// Returns the index into the buffers of the current one.
+    #[inline]
+    const fn current_index(&self) -> usize {
+        self.frame.epoch() as usize & 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phases() {
+        let mut flag = PhaseFlag::default();
+        assert_eq!((flag.epoch(), flag.phase()), (0, ParityKind::Even));
+        assert!(flag.is_even());
+        let phases: std::vec::Vec<_> = (0..4).map(|_| flag.advance()).collect();
+        assert_eq!(
+            phases,
+            [
+                ParityKind::Odd,
+                ParityKind::Even,
+                ParityKind::Odd,
+                ParityKind::Even
+            ]
+        );
+        assert!(flag > PhaseFlag::at_epoch(3));
+
+        let mut last = PhaseFlag::at_epoch(u64::MAX);
+        assert!(last.is_odd());
+        assert_eq!(last.advance(), ParityKind::Even);
+        assert_eq!(last, PhaseFlag::new());
+    }
+
+    #[test]
+    fn double_buffer() {
+        let mut buffers = DoubleBuffer::new(1, 2);
+        assert_eq!((*buffers.current(), *buffers.previous()), (1, 2));
+        buffers.swap();
+        assert_eq!((buffers.frame(), buffers.phase()), (1, ParityKind::Odd));
+        assert_eq!((*buffers.current(), *buffers.previous()), (2, 1));
+        *buffers.current_mut() = 20;
+        *buffers.previous_mut() += 10;
+        buffers.swap();
+        assert_eq!((*buffers.current(), *buffers.previous()), (11, 20));
+        let (current, previous) = buffers.current_and_previous_mut();
+        *current = *previous * 2;
+        assert_eq!(buffers.into_inner(), [40, 20]);
+
+        let empty: DoubleBuffer<std::vec::Vec<u8>> = DoubleBuffer::default();
+        assert!(empty.current().is_empty() && empty.frame() == 0);
+    }
+}
@@ -0,0 +1,145 @@
+//! Nameable predicates testing the parity of a value.
+//!
+//! Closures have types that cannot be written down, so a struct or function generic over a predicate cannot name
+//! `|x| x.is_even()` in its signature. [EvenPredicate] and [OddPredicate] are zero-sized types that can.
+//!
+//! Stable Rust does not allow implementing the [Fn] traits, so on stable the predicates are applied with
+//! [call](EvenPredicate::call), or turned into a function pointer with [as_fn](EvenPredicate::as_fn) for APIs such
+//! as `Vec::retain` and [slice::partition_point]. With the `nightly` feature they implement [Fn], [FnMut] and
+//! [FnOnce] on references to any `T: Parity`, and can be passed to those APIs directly.
+//!
+//! # Example
+//! ```
+//! use parity::predicate::{EvenPredicate, ParityPredicate};
+//!
+//! /// Keeps the values accepted by a predicate chosen by type.
+//! struct Filter<P> {
+//!     predicate: P,
+//! }
+//!
+//! impl<P: ParityPredicate> Filter<P> {
+//!     fn apply(&self, values: &mut Vec<i32>) {
+//!         values.retain(|v| self.predicate.test(v));
+//!     }
+//! }
+//!
+//! let mut values = vec![1, 2, 3, 4];
+//! Filter { predicate: EvenPredicate }.apply(&mut values);
+//! assert_eq!(values, [2, 4]);
+//!
+//! let sorted = [2, 4, 6, 7, 9];
+//! assert_eq!(sorted.partition_point(EvenPredicate.as_fn()), 3);
+//! ```
+
+use crate::{Parity, ParityKind};
+
+/// A predicate on the parity of a value, implemented by [EvenPredicate] and [OddPredicate].
+pub trait ParityPredicate {
+    /// The parity that the predicate accepts.
+    const PARITY: ParityKind;
+
+    /// Returns `true` if `value` has the parity [PARITY](Self::PARITY).
+    #[inline]
+    fn test<T: Parity + ?Sized>(&self, value: &T) -> bool {
+        value.checked_parity() == Some(Self::PARITY)
+    }
+}
+
+macro_rules! parity_predicate {
+    ($($(#[$attr:meta])* $Name:ident, $Kind:ident, $is:ident;)*) => { $(
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $Name;
+
+        impl $Name {
+            #[doc = concat!("Returns `value.", stringify!($is), "()`.")]
+            #[inline]
+            pub fn call<T: Parity + ?Sized>(self, value: &T) -> bool {
+                value.$is()
+            }
+
+            #[doc = concat!("Returns the predicate as a function pointer calling [Parity::", stringify!($is), "].")]
+            #[inline]
+            pub fn as_fn<T: Parity>(self) -> fn(&T) -> bool {
+                |value| value.$is()
+            }
+        }
+
+        impl ParityPredicate for $Name {
+            const PARITY: ParityKind = ParityKind::$Kind;
+
+            #[inline]
+            fn test<T: Parity + ?Sized>(&self, value: &T) -> bool {
+                value.$is()
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<'a, T: Parity + ?Sized> FnOnce<(&'a T,)> for $Name {
+            type Output = bool;
+
+            #[inline]
+            extern "rust-call" fn call_once(self, (value,): (&'a T,)) -> bool {
+                value.$is()
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<'a, T: Parity + ?Sized> FnMut<(&'a T,)> for $Name {
+            #[inline]
+            extern "rust-call" fn call_mut(&mut self, (value,): (&'a T,)) -> bool {
+                value.$is()
+            }
+        }
+
+        #[cfg(feature = "nightly")]
+        impl<'a, T: Parity + ?Sized> Fn<(&'a T,)> for $Name {
+            #[inline]
+            extern "rust-call" fn call(&self, (value,): (&'a T,)) -> bool {
+                value.$is()
+            }
+        }
+    )* };
+}
+
+parity_predicate! {
+    /// A zero-sized predicate accepting even values.
+    EvenPredicate, Even, is_even;
+    /// A zero-sized predicate accepting odd values.
+    OddPredicate, Odd, is_odd;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn predicates() {
+        assert!(EvenPredicate.call(&4u8));
+        assert!(!EvenPredicate.call(&0.5f64));
+        assert!(OddPredicate.test(&-3i64));
+        assert!(!OddPredicate.test(&f32::NAN));
+        assert_eq!(core::mem::size_of::<EvenPredicate>(), 0);
+
+        let values = [1u32, 2, 3, 4, 5];
+        assert_eq!(values.iter().filter(|v| OddPredicate.call(*v)).count(), 3);
+        let mut odd: std::vec::Vec<u32> = values.to_vec();
+        odd.retain(OddPredicate.as_fn());
+        assert_eq!(odd, [1, 3, 5]);
+        assert_eq!(<OddPredicate as ParityPredicate>::PARITY, ParityKind::Odd);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn fn_traits() {
+        fn count<P: Fn(&i32) -> bool>(values: &[i32], predicate: P) -> usize {
+            values.iter().filter(|v| predicate(v)).count()
+        }
+
+        let mut values = std::vec![1, 3, 2, 4, 6];
+        assert_eq!(count(&values, EvenPredicate), 3);
+        assert_eq!(values.partition_point(OddPredicate), 2);
+        values.retain(EvenPredicate);
+        assert_eq!(values, [2, 4, 6]);
+    }
+}
@@ -0,0 +1,30 @@
+//! The traits, types and macros most code needs, for a single glob import.
+//!
+//! # Example
+//! ```
+//! use parity::prelude::*;
+//!
+//! assert!(4u8.is_even());
+//! assert_eq!(3i64.parity(), ParityKind::Odd);
+//! assert_eq!(0b111u32.bit_parity(), ParityKind::Odd);
+//! assert!((1..10).evens().eq([2, 4, 6, 8]));
+//! assert_even!(10);
+//! ```
+
+pub use crate::array::ParityArray;
+pub use crate::combinators::{OptionParity, ResultParity};
+pub use crate::ct::ConstantTimeParity;
+pub use crate::layout::LayoutParity;
+#[cfg(feature = "rand")]
+pub use crate::random::ParityRng;
+pub use crate::slice::ParitySlice;
+#[cfg(feature = "nightly")]
+pub use crate::ConstParity;
+pub use crate::{
+    assert_even, assert_odd, debug_assert_even, debug_assert_odd, even, impl_parity_for, parity_of,
+    static_assert_even,
+};
+pub use crate::{
+    BitParity, Even, FloatParity, IntegerParity, Odd, Parity, ParityError, ParityIterator,
+    ParityKind,
+};
@@ -0,0 +1,211 @@
+//! Parity of pointer addresses, and pointers carrying a tag in their low bit.
+//!
+//! [Parity] is implemented for raw pointers and [NonNull] by the parity of their address. Pointers to types aligned
+//! to two or more are always even, which leaves the lowest address bit free to store a tag.
+
+use core::fmt;
+use core::ptr::NonNull;
+
+use crate::{Parity, ParityError, ParityKind};
+
+macro_rules! impl_pointer_parity {
+    ($($Ptr:ty),*) => { $(
+        impl<T: ?Sized> Parity for $Ptr {
+            /// Returns `true` if the address of `self` is even.
+            #[inline]
+            fn is_even(&self) -> bool {
+                self.addr() & 1 == 0
+            }
+
+            /// Returns `true` if the address of `self` is odd.
+            #[inline]
+            fn is_odd(&self) -> bool {
+                self.addr() & 1 != 0
+            }
+
+            /// Returns `true`, as every address is either even or odd.
+            #[inline]
+            fn is_parity_defined(&self) -> bool {
+                true
+            }
+        }
+    )* };
+}
+
+impl_pointer_parity![*const T, *mut T];
+
+impl<T: ?Sized> Parity for NonNull<T> {
+    /// Returns `true` if the address of `self` is even.
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.as_ptr().is_even()
+    }
+
+    /// Returns `true` if the address of `self` is odd.
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.as_ptr().is_odd()
+    }
+
+    /// Returns `true`, as every address is either even or odd.
+    #[inline]
+    fn is_parity_defined(&self) -> bool {
+        true
+    }
+}
+
+/// A raw pointer with a one-bit tag stored in the low bit of its address.
+///
+/// Only even pointers can be tagged, which is every pointer to a type aligned to two or more. The tag is a
+/// [ParityKind] because it is the parity of the stored address: [ParityKind::Even] leaves the pointer unchanged.
+/// The address is changed with [pointer::map_addr], so the pointer keeps its provenance.
+///
+/// # Example
+/// ```
+/// use parity::ptr::TaggedPtr;
+/// use parity::ParityKind;
+///
+/// let mut value = 7u32;
+/// let mut tagged = TaggedPtr::new(&mut value, ParityKind::Odd);
+/// assert_eq!(tagged.tag(), ParityKind::Odd);
+/// assert_eq!(unsafe { *tagged.untagged() }, 7);
+///
+/// tagged.set_tag(ParityKind::Even);
+/// assert_eq!(tagged.as_raw(), tagged.untagged());
+/// ```
+pub struct TaggedPtr<T> {
+    ptr: *mut T,
+}
+
+impl<T> TaggedPtr<T> {
+    /// Tags the even pointer `ptr` with `tag`.
+    ///
+    /// # Panics
+    /// Panics if `ptr` is odd.
+    #[inline]
+    #[track_caller]
+    pub fn new(ptr: *mut T, tag: ParityKind) -> Self {
+        match Self::try_new(ptr, tag) {
+            Ok(tagged) => tagged,
+            Err(err) => panic!("cannot tag pointer: {err}"),
+        }
+    }
+
+    /// Tags the even pointer `ptr` with `tag`, failing if `ptr` is odd.
+    #[inline]
+    pub fn try_new(ptr: *mut T, tag: ParityKind) -> Result<Self, ParityError<*mut T>> {
+        let ptr = ptr.ensure_even()?;
+        Ok(Self {
+            ptr: ptr.map_addr(|addr| addr | tag.is_odd() as usize),
+        })
+    }
+
+    /// Returns the tag.
+    #[inline]
+    pub fn tag(&self) -> ParityKind {
+        ParityKind::from_odd(self.ptr.is_odd())
+    }
+
+    /// Replaces the tag.
+    #[inline]
+    pub fn set_tag(&mut self, tag: ParityKind) {
+        self.ptr = self
+            .untagged()
+            .map_addr(|addr| addr | tag.is_odd() as usize);
+    }
+
+    /// Returns the pointer without its tag.
+    #[inline]
+    pub fn untagged(&self) -> *mut T {
+        self.ptr.map_addr(|addr| addr & !1)
+    }
+
+    /// Returns the stored pointer, including the tag.
+    #[inline]
+    pub fn as_raw(&self) -> *mut T {
+        self.ptr
+    }
+}
+
+impl<T> Clone for TaggedPtr<T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TaggedPtr<T> {}
+
+impl<T> PartialEq for TaggedPtr<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl<T> Eq for TaggedPtr<T> {}
+
+impl<T> fmt::Debug for TaggedPtr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TaggedPtr")
+            .field("ptr", &self.untagged())
+            .field("tag", &self.tag())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_parity() {
+        let bytes = [0u16; 2];
+        let ptr: *const u8 = bytes.as_ptr().cast();
+        assert!(ptr.is_even());
+        assert!(ptr.wrapping_add(1).is_odd());
+        assert_eq!(
+            ptr.cast_mut().wrapping_add(3).checked_parity(),
+            Some(ParityKind::Odd)
+        );
+        assert!(NonNull::from(&bytes[1]).is_even());
+    }
+
+    #[test]
+    fn tagging() {
+        let mut values = [1u64, 2];
+        let first = values.as_mut_ptr();
+        let mut tagged = TaggedPtr::new(first, ParityKind::Odd);
+        assert_eq!(tagged.tag(), ParityKind::Odd);
+        assert_eq!(tagged.untagged(), first);
+        assert_ne!(tagged.as_raw(), first);
+        tagged.set_tag(ParityKind::Odd);
+        assert_eq!(tagged.untagged(), first);
+        tagged.set_tag(ParityKind::Even);
+        assert_eq!(tagged.as_raw(), first);
+
+        let copy = tagged;
+        assert_eq!(copy, tagged);
+        unsafe { *copy.untagged().add(1) = 9 };
+        assert_eq!(values, [1, 9]);
+    }
+
+    #[test]
+    fn odd_pointers() {
+        let bytes = [0u16; 2];
+        let odd = bytes.as_ptr().cast::<u8>().wrapping_add(1).cast_mut();
+        let err = TaggedPtr::try_new(odd, ParityKind::Even).unwrap_err();
+        assert_eq!(*err.value(), odd);
+        assert_eq!(err.expected(), ParityKind::Even);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot tag pointer")]
+    fn odd_pointer_panics() {
+        let bytes = [0u16; 2];
+        TaggedPtr::new(
+            bytes.as_ptr().cast::<u8>().wrapping_add(1).cast_mut(),
+            ParityKind::Odd,
+        );
+    }
+}
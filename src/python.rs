@@ -0,0 +1,116 @@
+//! A Python extension module built with PyO3.
+//!
+//! The module is named `parity` and exposes:
+//! - `is_even(x)`, `is_odd(x)` and `parity(x)` for Python `int`s of any size and `float`s, with the same semantics as
+//!   [Parity]. `parity` returns `"even"`, `"odd"` or `None`.
+//! - `bit_parity(data)`, the parity of the number of set bits in a `bytes`-like object, as `"even"` or `"odd"`.
+//! - `lrc(data)`, the [longitudinal redundancy check](crate::checksum::lrc) byte of `data`.
+//!
+//! Build it with `maturin`, enabling `pyo3/extension-module` alongside the `python` feature.
+
+use pyo3::prelude::*;
+use pyo3::types::PyInt;
+
+use crate::{BitParity, Parity, ParityKind};
+
+/// A Python number: either an `int` of any size or a `float`.
+#[derive(FromPyObject)]
+enum Number<'py> {
+    Int(Bound<'py, PyInt>),
+    Float(f64),
+}
+
+impl Number<'_> {
+    fn checked_parity(&self) -> PyResult<Option<ParityKind>> {
+        match self {
+            Number::Int(x) => Ok(Some(ParityKind::from_odd(
+                x.bitand(1)?.extract::<u8>()? == 1,
+            ))),
+            Number::Float(x) => Ok(x.checked_parity()),
+        }
+    }
+}
+
+fn name(parity: ParityKind) -> &'static str {
+    match parity {
+        ParityKind::Even => "even",
+        ParityKind::Odd => "odd",
+    }
+}
+
+/// Returns `True` if `x` is even.
+#[pyfunction]
+fn is_even(x: Number<'_>) -> PyResult<bool> {
+    Ok(x.checked_parity()? == Some(ParityKind::Even))
+}
+
+/// Returns `True` if `x` is odd.
+#[pyfunction]
+fn is_odd(x: Number<'_>) -> PyResult<bool> {
+    Ok(x.checked_parity()? == Some(ParityKind::Odd))
+}
+
+/// Returns `"even"` or `"odd"`, or `None` if `x` has no parity.
+#[pyfunction]
+fn parity(x: Number<'_>) -> PyResult<Option<&'static str>> {
+    Ok(x.checked_parity()?.map(name))
+}
+
+/// Returns the parity of the number of set bits in `data`.
+#[pyfunction]
+fn bit_parity(data: &[u8]) -> &'static str {
+    // XOR preserves the parity of each bit column, so the fold has as many set bits as `data` modulo two
+    name(crate::checksum::lrc(data).bit_parity())
+}
+
+/// Returns the longitudinal redundancy check byte of `data`.
+#[pyfunction]
+fn lrc(data: &[u8]) -> u8 {
+    crate::checksum::lrc(data)
+}
+
+/// The `parity` Python module.
+#[pymodule(name = "parity")]
+pub fn parity_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(is_even, m)?)?;
+    m.add_function(wrap_pyfunction!(is_odd, m)?)?;
+    m.add_function(wrap_pyfunction!(parity, m)?)?;
+    m.add_function(wrap_pyfunction!(bit_parity, m)?)?;
+    m.add_function(wrap_pyfunction!(lrc, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use pyo3::py_run;
+
+    use super::*;
+
+    #[test]
+    fn from_python() {
+        Python::initialize();
+        Python::attach(|py| {
+            let parity = PyModule::new(py, "parity").unwrap();
+            parity_module(&parity).unwrap();
+            py_run!(
+                py,
+                parity,
+                r#"
+assert parity.is_even(2**100) and parity.is_odd(-(2**100) - 1)
+assert parity.is_even(True) is False and parity.is_odd(True)
+assert parity.parity(3) == "odd" and parity.parity(-0.0) == "even"
+assert parity.parity(2.5) is None and parity.parity(float("inf")) is None
+assert not parity.is_even(float("nan")) and not parity.is_odd(float("nan"))
+assert parity.bit_parity(b"\x03\x01") == "odd" and parity.bit_parity(b"") == "even"
+assert parity.lrc(b"\x0f\xf0\x01") == 0xfe
+try:
+    parity.is_even("2")
+except TypeError:
+    pass
+else:
+    raise AssertionError("strings have no parity")
+"#
+            );
+        });
+    }
+}
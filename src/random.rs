@@ -0,0 +1,218 @@
+//! Sampling random integers of a given parity, and random parities with a given bias.
+//!
+//! # Example
+//! ```
+//! use parity::random::ParityRng;
+//! use parity::Parity;
+//! use rand::rngs::SmallRng;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = SmallRng::seed_from_u64(7);
+//! let x: u32 = rng.gen_even_in(10..20).unwrap();
+//! assert!(x.is_even() && (10..20).contains(&x));
+//!
+//! // there is no odd value in 4..=4
+//! assert_eq!(rng.gen_odd_in(4u8..=4), None);
+//! ```
+
+use core::ops::RangeBounds;
+
+use rand::distr::{Bernoulli, BernoulliError, Distribution};
+use rand::{Rng, RngExt};
+
+use crate::range::ParityRange;
+use crate::ParityKind;
+
+/// An integer type whose values can be sampled by parity.
+///
+/// Implemented for all primitive integer types.
+pub trait SampleParity: Sized {
+    /// Samples a value of the given `parity` uniformly from `range`, or returns `None` if the range contains no such
+    /// value.
+    fn sample_parity<R, B>(rng: &mut R, range: B, parity: ParityKind) -> Option<Self>
+    where
+        R: Rng + ?Sized,
+        B: RangeBounds<Self>;
+}
+
+/// Extends random number generators with uniform sampling of even and odd integers.
+///
+/// Only values of the requested parity are considered, so every one of them is equally likely and no samples are
+/// rejected. Implemented for every [Rng].
+pub trait ParityRng: Rng {
+    /// Samples a value of the given `parity` uniformly from `range`, or returns `None` if there is none.
+    #[inline]
+    fn gen_parity_in<T, B>(&mut self, range: B, parity: ParityKind) -> Option<T>
+    where
+        T: SampleParity,
+        B: RangeBounds<T>,
+    {
+        T::sample_parity(self, range, parity)
+    }
+
+    /// Samples an even value uniformly from `range`, or returns `None` if there is none.
+    #[inline]
+    fn gen_even_in<T, B>(&mut self, range: B) -> Option<T>
+    where
+        T: SampleParity,
+        B: RangeBounds<T>,
+    {
+        self.gen_parity_in(range, ParityKind::Even)
+    }
+
+    /// Samples an odd value uniformly from `range`, or returns `None` if there is none.
+    #[inline]
+    fn gen_odd_in<T, B>(&mut self, range: B) -> Option<T>
+    where
+        T: SampleParity,
+        B: RangeBounds<T>,
+    {
+        self.gen_parity_in(range, ParityKind::Odd)
+    }
+}
+
+impl<R: Rng + ?Sized> ParityRng for R {}
+
+macro_rules! impl_sample_parity {
+    ($($T:ty),*) => { $(
+        impl SampleParity for $T {
+            fn sample_parity<R, B>(rng: &mut R, range: B, parity: ParityKind) -> Option<Self>
+            where
+                R: Rng + ?Sized,
+                B: RangeBounds<Self>,
+            {
+                let (first, steps) = <$T>::parity_range(&range, parity)?;
+                Some(<$T>::nth_step(first, rng.random_range(0..=steps)))
+            }
+        }
+    )* };
+}
+
+impl_sample_parity![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+/// A distribution of parities that yields [ParityKind::Even] with a fixed probability, for simulating biased event
+/// streams.
+///
+/// # Example
+/// ```
+/// use parity::random::ParityDistribution;
+/// use parity::ParityKind;
+/// use rand::distr::Distribution;
+/// use rand::rngs::SmallRng;
+/// use rand::SeedableRng;
+///
+/// let mostly_even = ParityDistribution::new(0.9)?;
+/// let mut rng = SmallRng::seed_from_u64(3);
+/// let evens = mostly_even
+///     .sample_iter(&mut rng)
+///     .take(1000)
+///     .filter(|&parity| parity == ParityKind::Even)
+///     .count();
+/// assert!((850..950).contains(&evens));
+/// # Ok::<(), rand::distr::BernoulliError>(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParityDistribution {
+    even: Bernoulli,
+}
+
+impl ParityDistribution {
+    /// Returns a distribution yielding [ParityKind::Even] with probability `p_even`.
+    ///
+    /// # Errors
+    /// Fails if `p_even` is not between 0 and 1, like [Bernoulli::new].
+    #[inline]
+    pub fn new(p_even: f64) -> Result<Self, BernoulliError> {
+        Bernoulli::new(p_even).map(|even| ParityDistribution { even })
+    }
+
+    /// Returns a distribution yielding [ParityKind::Even] with probability `even / total`.
+    ///
+    /// # Errors
+    /// Fails if `total` is zero or less than `even`, like [Bernoulli::from_ratio].
+    #[inline]
+    pub fn from_ratio(even: u32, total: u32) -> Result<Self, BernoulliError> {
+        Bernoulli::from_ratio(even, total).map(|even| ParityDistribution { even })
+    }
+
+    /// Returns the probability of [ParityKind::Even].
+    #[inline]
+    pub fn p_even(&self) -> f64 {
+        self.even.p()
+    }
+}
+
+impl Distribution<ParityKind> for ParityDistribution {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ParityKind {
+        ParityKind::from_odd(!self.even.sample(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use core::ops::Bound;
+
+    use super::*;
+    use crate::Parity;
+
+    #[test]
+    fn covers_every_value() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let mut seen = [false; 256];
+        for _ in 0..10_000 {
+            let x: i8 = rng.gen_odd_in(..).unwrap();
+            assert!(x.is_odd());
+            seen[x as u8 as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&seen| seen).count(), 128);
+
+        for _ in 0..1000 {
+            let x = rng.gen_even_in(-5i64..=5).unwrap();
+            assert!(x.is_even() && (-4..=4).contains(&x));
+            let x = rng.gen_odd_in(u128::MAX - 3..).unwrap();
+            assert!(x == u128::MAX - 2 || x == u128::MAX);
+        }
+    }
+
+    #[test]
+    fn empty_ranges() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        assert_eq!(rng.gen_even_in(3u32..=3), None);
+        assert_eq!(rng.gen_even_in(3u32..4), None);
+        assert_eq!(rng.gen_odd_in(5u32..5), None);
+        assert_eq!(
+            rng.gen_odd_in((Bound::Included(9i16), Bound::Included(1))),
+            None
+        );
+        assert_eq!(rng.gen_even_in(u8::MAX..=u8::MAX), None);
+        assert_eq!(rng.gen_odd_in(0u8..=0), None);
+        assert_eq!(rng.gen_odd_in(..i8::MIN), None);
+        assert_eq!(rng.gen_parity_in(6u8..7, ParityKind::Even), Some(6));
+    }
+
+    #[test]
+    fn biased_parities() {
+        let mut rng = SmallRng::seed_from_u64(4);
+        let always_even = ParityDistribution::new(1.0).unwrap();
+        let never_even = ParityDistribution::from_ratio(0, 5).unwrap();
+        for _ in 0..100 {
+            assert_eq!(always_even.sample(&mut rng), ParityKind::Even);
+            assert_eq!(never_even.sample(&mut rng), ParityKind::Odd);
+        }
+
+        let quarter = ParityDistribution::from_ratio(1, 4).unwrap();
+        assert_eq!(quarter.p_even(), 0.25);
+        let evens = (0..10_000)
+            .filter(|_| quarter.sample(&mut rng) == ParityKind::Even)
+            .count();
+        assert!((2300..2700).contains(&evens));
+
+        assert!(ParityDistribution::new(1.5).is_err());
+        assert!(ParityDistribution::new(f64::NAN).is_err());
+        assert!(ParityDistribution::from_ratio(3, 2).is_err());
+    }
+}
@@ -0,0 +1,52 @@
+use core::ops::{Bound, RangeBounds};
+
+use crate::{IntegerParity, ParityKind};
+
+/// Enumerates the values of one parity in a range of integers as `first + 2 * k` for `k` in `0..=steps`.
+pub(crate) trait ParityRange: Sized {
+    /// An unsigned type wide enough to count the steps of any range.
+    type Steps;
+
+    /// Returns the first value of the given `parity` in `range` and the number of steps to the last one, or `None`
+    /// if the range contains no such value.
+    fn parity_range<B: RangeBounds<Self>>(
+        range: &B,
+        parity: ParityKind,
+    ) -> Option<(Self, Self::Steps)>;
+
+    /// Returns `first + 2 * k`.
+    fn nth_step(first: Self, k: Self::Steps) -> Self;
+}
+
+macro_rules! impl_parity_range {
+    ($($T:ty => $U:ty),*) => { $(
+        impl ParityRange for $T {
+            type Steps = $U;
+
+            #[inline]
+            fn parity_range<B: RangeBounds<Self>>(range: &B, parity: ParityKind) -> Option<(Self, $U)> {
+                let start = match range.start_bound() {
+                    Bound::Included(&start) => start,
+                    Bound::Excluded(&start) => start.checked_add(1)?,
+                    Bound::Unbounded => <$T>::MIN,
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(&end) => end,
+                    Bound::Excluded(&end) => end.checked_sub(1)?,
+                    Bound::Unbounded => <$T>::MAX,
+                };
+                let first = if start.parity() == parity { start } else { start.checked_add(1)? };
+                let last = if end.parity() == parity { end } else { end.checked_sub(1)? };
+                (first <= last).then(|| (first, last.abs_diff(first) / 2))
+            }
+
+            #[inline]
+            fn nth_step(first: Self, k: $U) -> Self {
+                (first as $U).wrapping_add(2 * k) as $T
+            }
+        }
+    )* };
+}
+
+impl_parity_range![u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize];
+impl_parity_range![i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize];
@@ -0,0 +1,62 @@
+//! Choosing between two values without branching.
+
+/// A type whose values can be chosen between by masking their bits, without a branch.
+///
+/// Implemented for all primitive integer and floating-point types, and `bool`.
+pub trait MaskSelect: Copy {
+    /// Returns `if_false` when `condition` is `false` and `if_true` when it is `true`, by combining the bit patterns
+    /// of both under a mask.
+    fn mask_select(condition: bool, if_false: Self, if_true: Self) -> Self;
+}
+
+macro_rules! impl_mask_select {
+    ($($T:ty),*) => { $(
+        impl MaskSelect for $T {
+            #[inline]
+            fn mask_select(condition: bool, if_false: Self, if_true: Self) -> Self {
+                let mask = (condition as $T).wrapping_neg();
+                (if_false & !mask) | (if_true & mask)
+            }
+        }
+    )* };
+}
+
+impl_mask_select![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+macro_rules! impl_float_mask_select {
+    ($($T:ty),*) => { $(
+        impl MaskSelect for $T {
+            #[inline]
+            fn mask_select(condition: bool, if_false: Self, if_true: Self) -> Self {
+                <$T>::from_bits(MaskSelect::mask_select(condition, if_false.to_bits(), if_true.to_bits()))
+            }
+        }
+    )* };
+}
+
+impl_float_mask_select![f32, f64];
+
+impl MaskSelect for bool {
+    #[inline]
+    fn mask_select(condition: bool, if_false: Self, if_true: Self) -> Self {
+        (if_false & !condition) | (if_true & condition)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn selects() {
+        assert_eq!(u8::mask_select(false, 1, 2), 1);
+        assert_eq!(i128::mask_select(true, i128::MIN, -1), -1);
+        assert_eq!(
+            f64::mask_select(true, 1.5, -0.0).to_bits(),
+            (-0.0f64).to_bits()
+        );
+        assert!(f32::mask_select(false, f32::NAN, 1.0).is_nan());
+        assert!(bool::mask_select(true, false, true));
+        assert!(!bool::mask_select(false, false, true));
+    }
+}
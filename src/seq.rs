@@ -0,0 +1,250 @@
+//! Sequences defined by parity.
+//!
+//! # Example
+//! Alternating picks by the Thue–Morse sequence are fairer than taking turns: after any power of two picks both
+//! players have had the same number of first, second, ... choices.
+//! ```
+//! use parity::seq::ThueMorse;
+//! use parity::ParityKind;
+//!
+//! let turns: Vec<char> = ThueMorse::new()
+//!     .take(8)
+//!     .map(|turn| if turn == ParityKind::Even { 'A' } else { 'B' })
+//!     .collect();
+//! assert_eq!(turns.iter().collect::<String>(), "ABBABAAB");
+//! ```
+
+use core::iter::FusedIterator;
+
+use crate::{BitParity, ParityKind};
+
+/// Returns the `n`th term of the Thue–Morse sequence, the parity of the number of set bits in `n`.
+///
+/// # Example
+/// ```
+/// use parity::seq::thue_morse;
+/// use parity::ParityKind;
+///
+/// assert_eq!(thue_morse(0), ParityKind::Even);
+/// assert_eq!(thue_morse(7), ParityKind::Odd);
+/// ```
+#[inline]
+pub fn thue_morse(n: u64) -> ParityKind {
+    n.bit_parity()
+}
+
+/// An infinite iterator over the terms of the Thue–Morse sequence, starting at term 0.
+///
+/// Term `n` is [thue_morse(n)](thue_morse). The iterator stops after term `u64::MAX`, which is far enough for any
+/// practical purpose.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ThueMorse {
+    next: Option<u64>,
+}
+
+impl ThueMorse {
+    /// Returns an iterator starting at term 0.
+    #[inline]
+    pub const fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Returns an iterator starting at term `n`.
+    #[inline]
+    pub const fn starting_at(n: u64) -> Self {
+        Self { next: Some(n) }
+    }
+}
+
+impl Default for ThueMorse {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for ThueMorse {
+    type Item = ParityKind;
+
+    #[inline]
+    fn next(&mut self) -> Option<ParityKind> {
+        let n = self.next?;
+        self.next = n.checked_add(1);
+        Some(thue_morse(n))
+    }
+
+    #[inline]
+    fn nth(&mut self, k: usize) -> Option<ParityKind> {
+        self.next = self.next.and_then(|n| n.checked_add(k as u64));
+        self.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.next {
+            Some(n) => match usize::try_from(u64::MAX - n) {
+                Ok(remaining) => (remaining.saturating_add(1), remaining.checked_add(1)),
+                Err(_) => (usize::MAX, None),
+            },
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl FusedIterator for ThueMorse {}
+
+/// Applies the Collatz map to `n`: halves it if it is even, and returns `3n + 1` if it is odd.
+///
+/// Returns `None` if `3n + 1` overflows a `u64`.
+///
+/// # Example
+/// ```
+/// use parity::seq::collatz_step;
+///
+/// assert_eq!(collatz_step(6), Some(3));
+/// assert_eq!(collatz_step(3), Some(10));
+/// assert_eq!(collatz_step(u64::MAX), None);
+/// ```
+#[inline]
+pub const fn collatz_step(n: u64) -> Option<u64> {
+    if n.is_multiple_of(2) {
+        Some(n / 2)
+    } else {
+        match n.checked_mul(3) {
+            Some(tripled) => tripled.checked_add(1),
+            None => None,
+        }
+    }
+}
+
+/// An iterator over the Collatz trajectory of a starting value, from the value itself down to 1.
+///
+/// Each term is the [collatz_step] of the one before. Zero is a fixed point of the map, so its trajectory is just
+/// zero. If a step overflows the iterator stops after the last term it could compute, and
+/// [Collatz::overflowed_at] returns that term.
+///
+/// # Example
+/// ```
+/// use parity::seq::Collatz;
+///
+/// let trajectory: Vec<u64> = Collatz::new(6).collect();
+/// assert_eq!(trajectory, [6, 3, 10, 5, 16, 8, 4, 2, 1]);
+///
+/// let mut overflowing = Collatz::new(u64::MAX - 1);
+/// assert_eq!(overflowing.by_ref().count(), 2);
+/// assert_eq!(overflowing.overflowed_at(), Some(u64::MAX / 2));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Collatz {
+    next: Option<u64>,
+    overflowed_at: Option<u64>,
+}
+
+impl Collatz {
+    /// Returns an iterator over the trajectory of `start`.
+    #[inline]
+    pub const fn new(start: u64) -> Self {
+        Self {
+            next: Some(start),
+            overflowed_at: None,
+        }
+    }
+
+    /// Returns the term whose successor overflowed a `u64`, if the trajectory stopped there instead of at 1.
+    #[inline]
+    pub const fn overflowed_at(&self) -> Option<u64> {
+        self.overflowed_at
+    }
+}
+
+impl Iterator for Collatz {
+    type Item = u64;
+
+    #[inline]
+    fn next(&mut self) -> Option<u64> {
+        let n = self.next?;
+        self.next = None;
+        if n > 1 {
+            match collatz_step(n) {
+                Some(next) => self.next = Some(next),
+                None => self.overflowed_at = Some(n),
+            }
+        }
+        Some(n)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::from(self.next.is_some()), None)
+    }
+}
+
+impl FusedIterator for Collatz {}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn doubling_construction() {
+        // each block of 2^k terms is followed by its complement
+        let terms: Vec<ParityKind> = ThueMorse::new().take(1 << 10).collect();
+        for k in 0..10 {
+            let (first, second) = terms[..2 << k].split_at(1 << k);
+            assert!(first.iter().zip(second).all(|(a, b)| a != b));
+        }
+    }
+
+    #[test]
+    fn skipping() {
+        let mut iter = ThueMorse::new();
+        assert_eq!(iter.nth(6), Some(ParityKind::Even));
+        assert_eq!(iter.next(), Some(ParityKind::Odd));
+        assert_eq!(ThueMorse::starting_at(7).next(), Some(ParityKind::Odd));
+
+        let mut iter = ThueMorse::starting_at(u64::MAX - 1);
+        assert_eq!(iter.size_hint().0, 2);
+        assert_eq!(iter.nth(1), Some(ParityKind::Even));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(
+            ThueMorse::new().nth(usize::MAX),
+            Some(thue_morse(usize::MAX as u64))
+        );
+    }
+
+    #[test]
+    fn collatz_trajectories() {
+        assert_eq!(Collatz::new(1).collect::<Vec<_>>(), [1]);
+        assert_eq!(Collatz::new(0).collect::<Vec<_>>(), [0]);
+        assert_eq!(Collatz::new(27).count(), 112);
+        assert_eq!(Collatz::new(27).max(), Some(9232));
+        for start in 1..1000u64 {
+            let mut trajectory = Collatz::new(start);
+            let mut previous = trajectory.next().unwrap();
+            for n in trajectory.by_ref() {
+                assert_eq!(collatz_step(previous), Some(n));
+                previous = n;
+            }
+            assert_eq!(previous, 1);
+            assert_eq!(trajectory.overflowed_at(), None);
+        }
+    }
+
+    #[test]
+    fn collatz_overflow() {
+        // the largest odd value whose successor fits
+        let largest = (u64::MAX - 1) / 3 - 1;
+        assert_eq!(collatz_step(largest), Some(u64::MAX - 5));
+        assert_eq!(collatz_step(largest + 2), None);
+
+        let mut trajectory = Collatz::new(largest + 2);
+        assert_eq!(trajectory.size_hint(), (1, None));
+        assert_eq!(trajectory.next(), Some(largest + 2));
+        assert_eq!(trajectory.next(), None);
+        assert_eq!(trajectory.size_hint(), (0, None));
+        assert_eq!(trajectory.overflowed_at(), Some(largest + 2));
+    }
+}
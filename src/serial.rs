@@ -0,0 +1,175 @@
+//! The parity modes of asynchronous serial links, for UART emulation and protocol analyzers.
+//!
+//! A character frame here is the data bits in the low bits of a `u16` followed by the parity bit, if any, in the bit
+//! above them. Start and stop bits are not included.
+//!
+//! # Example
+//! ```
+//! use parity::serial::ParityBit;
+//!
+//! // 8E1: eight data bits and an even parity bit
+//! let frame = ParityBit::Even.frame(0x41, 8);
+//! assert_eq!(frame, 0x041);
+//! assert!(ParityBit::Even.verify(frame, 8));
+//! assert!(!ParityBit::Even.verify(frame ^ 0x100, 8));
+//! assert_eq!(ParityBit::Odd.compute_for(0x41, 8), Some(true));
+//! ```
+
+use crate::{BitParity, ParityKind};
+
+/// The parity bit of a serial character frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ParityBit {
+    /// No parity bit is sent.
+    None,
+    /// The parity bit makes the number of set bits in the data and parity bits even.
+    Even,
+    /// The parity bit makes the number of set bits in the data and parity bits odd.
+    Odd,
+    /// The parity bit is always set.
+    Mark,
+    /// The parity bit is always clear.
+    Space,
+}
+
+#[track_caller]
+fn check_word(word: u16, data_bits: u32) {
+    assert!(
+        (1..16).contains(&data_bits),
+        "{data_bits} data bits do not leave room for a parity bit in a 16-bit frame"
+    );
+    assert!(
+        word >> data_bits == 0,
+        "0x{word:x} does not fit in {data_bits} data bits"
+    );
+}
+
+impl ParityBit {
+    /// Returns the number of parity bits in a frame: zero for [ParityBit::None] and one otherwise.
+    #[inline]
+    pub const fn bits(self) -> u32 {
+        match self {
+            ParityBit::None => 0,
+            _ => 1,
+        }
+    }
+
+    /// Returns the parity bit this mode sends after the `data_bits` low bits of `word`, or `None` if it sends none.
+    ///
+    /// # Panics
+    /// Panics if `data_bits` is not between 1 and 15, or if `word` does not fit in `data_bits` bits.
+    #[inline]
+    #[track_caller]
+    pub fn compute_for(self, word: u16, data_bits: u32) -> Option<bool> {
+        check_word(word, data_bits);
+        match self {
+            ParityBit::None => None,
+            ParityBit::Even => Some(word.bit_parity() == ParityKind::Odd),
+            ParityBit::Odd => Some(word.bit_parity() == ParityKind::Even),
+            ParityBit::Mark => Some(true),
+            ParityBit::Space => Some(false),
+        }
+    }
+
+    /// Returns the frame for `word`: its `data_bits` low bits followed by the parity bit of this mode, if any.
+    ///
+    /// # Panics
+    /// Panics if `data_bits` is not between 1 and 15, or if `word` does not fit in `data_bits` bits.
+    #[inline]
+    #[track_caller]
+    pub fn frame(self, word: u16, data_bits: u32) -> u16 {
+        match self.compute_for(word, data_bits) {
+            Some(true) => word | 1 << data_bits,
+            _ => word,
+        }
+    }
+
+    /// Returns `true` if `frame`, `data_bits` data bits followed by the parity bit of this mode, carries the parity
+    /// bit this mode chooses for its data.
+    ///
+    /// Without a parity bit every frame is valid, as long as it fits in `data_bits` bits.
+    ///
+    /// # Panics
+    /// Panics if `data_bits` is not between 1 and 15.
+    #[inline]
+    #[track_caller]
+    pub fn verify(self, frame: u16, data_bits: u32) -> bool {
+        check_word(0, data_bits);
+        let frame_bits = data_bits + self.bits();
+        if frame_bits < 16 && frame >> frame_bits != 0 {
+            return false;
+        }
+        let data = frame & ((1 << data_bits) - 1);
+        self.frame(data, data_bits) == frame
+    }
+}
+
+impl From<ParityKind> for ParityBit {
+    /// Returns the mode that gives the data and parity bits of every frame the parity `parity` of set bits.
+    #[inline]
+    fn from(parity: ParityKind) -> Self {
+        match parity {
+            ParityKind::Even => ParityBit::Even,
+            ParityKind::Odd => ParityBit::Odd,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODES: [ParityBit; 5] = [
+        ParityBit::None,
+        ParityBit::Even,
+        ParityBit::Odd,
+        ParityBit::Mark,
+        ParityBit::Space,
+    ];
+
+    #[test]
+    fn frames() {
+        for data_bits in [5, 7, 8, 9] {
+            for word in 0..1u16 << data_bits {
+                for mode in MODES {
+                    let frame = mode.frame(word, data_bits);
+                    assert!(mode.verify(frame, data_bits));
+                    assert_eq!(frame & ((1 << data_bits) - 1), word);
+                    assert_eq!(
+                        frame >> data_bits == 1,
+                        mode.compute_for(word, data_bits) == Some(true)
+                    );
+                    if mode != ParityBit::None {
+                        assert!(!mode.verify(frame ^ 1 << data_bits, data_bits));
+                    }
+                }
+                assert_eq!(
+                    ParityBit::Even.frame(word, data_bits).bit_parity(),
+                    ParityKind::Even
+                );
+                assert_eq!(
+                    ParityBit::Odd.frame(word, data_bits).bit_parity(),
+                    ParityKind::Odd
+                );
+            }
+        }
+        assert!(!ParityBit::None.verify(0x100, 8));
+        assert!(!ParityBit::Space.verify(0x200, 8));
+        assert_eq!(ParityBit::None.compute_for(0x7f, 7), None);
+        assert_eq!(ParityBit::from(ParityKind::Odd), ParityBit::Odd);
+        assert_eq!(ParityBit::Mark.bits() + ParityBit::None.bits(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "0x80 does not fit in 7 data bits")]
+    fn word_too_wide() {
+        ParityBit::Even.compute_for(0x80, 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "16 data bits do not leave room for a parity bit in a 16-bit frame")]
+    fn too_many_data_bits() {
+        ParityBit::None.verify(0, 16);
+    }
+}
@@ -0,0 +1,155 @@
+//! Splitting ID spaces into shards or A/B buckets by parity.
+//!
+//! These helpers read the low bits of a key as they are, without hashing it, so they are only as balanced as those
+//! bits. That holds for random IDs and for sequential counters, but not for values whose low bits are fixed or
+//! correlated: aligned addresses, IDs allocated in strides, millisecond timestamps rounded to seconds, or keys
+//! that are themselves chosen by parity. Hash such keys first, and keep the split stable by never changing the
+//! hash or the number of buckets once data has been placed.
+//!
+//! # Example
+//! ```
+//! use parity::shard::{shard_by_low_bits, shard_by_parity};
+//! use parity::ParityKind;
+//!
+//! let user_id = 1_000_003u64;
+//! assert_eq!(shard_by_parity(user_id), ParityKind::Odd);
+//! assert_eq!(shard_by_low_bits(user_id, 8), 3);
+//! ```
+
+#[cfg(feature = "uuid")]
+use uuid::Uuid;
+
+use crate::{IntegerParity, ParityKind};
+
+/// Returns the half of a two-way split that `key` belongs to: the parity of its lowest bit.
+///
+/// This is [`shard_by_low_bits`] with two buckets, and is balanced only if the lowest bit of the keys is; see the
+/// [module documentation](self).
+#[inline]
+pub fn shard_by_parity<T: IntegerParity>(key: T) -> ParityKind {
+    key.parity()
+}
+
+/// Returns the bucket, from `0` to `n_buckets - 1`, that `key` belongs to: its lowest `log2(n_buckets)` bits.
+///
+/// Only a power of two number of buckets is accepted, since taking the remainder by any other number favours the
+/// lowest buckets whenever the key range is not a multiple of it. Doubling the number of buckets splits every bucket
+/// in two, so each key either stays in bucket `b` or moves to bucket `b + n_buckets`.
+///
+/// Every bucket gets the same share of keys only if the low bits of the keys are uniformly distributed; see the
+/// [module documentation](self).
+///
+/// # Panics
+/// Panics if `n_buckets` is not a power of two.
+///
+/// # Example
+/// ```
+/// use parity::shard::shard_by_low_bits;
+///
+/// assert_eq!(shard_by_low_bits(0b1011_0110, 4), 0b10);
+/// assert_eq!(shard_by_low_bits(0b1011_0110, 8), 0b110);
+/// assert_eq!(shard_by_low_bits(42, 1), 0);
+/// ```
+#[inline]
+#[track_caller]
+pub const fn shard_by_low_bits(key: u64, n_buckets: usize) -> usize {
+    assert!(
+        n_buckets.is_power_of_two(),
+        "the number of buckets must be a power of two"
+    );
+    // the mask is below `n_buckets`, so the result fits in `usize`
+    (key & (n_buckets as u64 - 1)) as usize
+}
+
+// the version nibble and the (up to three) variant bits, which are the same for every UUID of one kind
+#[cfg(feature = "uuid")]
+const UUID_FIXED_BITS: u128 = 0xf << 76 | 0x7 << 61;
+
+/// Returns the parity of the set bits of `id`, ignoring its version and variant fields, to split UUIDs of any
+/// version into two even halves.
+///
+/// The low bit of a UUID, its [`Parity`](crate::Parity), is random in version 4 and 7 UUIDs but fixed per host in
+/// version 1 and 6 UUIDs, so buckets keyed on it can be badly skewed. This helper instead folds in every bit that
+/// varies between UUIDs, so it is balanced for random, time-based and name-based UUIDs alike, and a UUID lands in
+/// the same bucket whichever version and variant fields it was given.
+///
+/// # Example
+/// ```
+/// use parity::shard::uuid_shard_parity;
+/// use parity::ParityKind;
+/// use uuid::Uuid;
+///
+/// // two version 1 UUIDs from the same host, which share their low bit
+/// let a = Uuid::from_u128(0xc232ab00_9414_11ec_b3c8_9f6bdeced846);
+/// let b = Uuid::from_u128(0xc232ab01_9414_11ec_b3c8_9f6bdeced846);
+/// assert_eq!(uuid_shard_parity(a), ParityKind::Odd);
+/// assert_eq!(uuid_shard_parity(b), ParityKind::Even);
+/// ```
+#[cfg(feature = "uuid")]
+#[inline]
+pub const fn uuid_shard_parity(id: Uuid) -> ParityKind {
+    ParityKind::from_odd((id.as_u128() & !UUID_FIXED_BITS).count_ones() % 2 == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn low_bits() {
+        for key in (0..1000u64).chain(u64::MAX - 1000..=u64::MAX) {
+            assert_eq!(
+                shard_by_low_bits(key, 2),
+                usize::from(shard_by_parity(key).is_odd())
+            );
+            let bucket = shard_by_low_bits(key, 64);
+            assert!(bucket < 64);
+            assert_eq!(shard_by_low_bits(key, 128) % 64, bucket);
+        }
+        assert_eq!(shard_by_low_bits(u64::MAX, 1 << 20), (1 << 20) - 1);
+        assert_eq!(shard_by_parity(-7i32), ParityKind::Odd);
+    }
+
+    #[test]
+    fn sequential_keys_are_balanced() {
+        let mut counts = [0; 16];
+        for key in 5000..5000 + 16 * 100 {
+            counts[shard_by_low_bits(key, 16)] += 1;
+        }
+        assert_eq!(counts, [100; 16]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of buckets must be a power of two")]
+    fn rejects_other_bucket_counts() {
+        shard_by_low_bits(7, 3);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_fixed_fields_are_ignored() {
+        let random = 0x67e5_5044_10b1_426f_9247_bb68_0e5f_e0c9u128;
+        let parity = uuid_shard_parity(Uuid::from_u128(random));
+        for version in 0..16u128 {
+            for variant in 0..8u128 {
+                let id = random & !UUID_FIXED_BITS | version << 76 | variant << 61;
+                assert_eq!(uuid_shard_parity(Uuid::from_u128(id)), parity);
+            }
+        }
+        assert_eq!(uuid_shard_parity(Uuid::nil()), ParityKind::Even);
+        assert_eq!(uuid_shard_parity(Uuid::max()), ParityKind::Odd);
+    }
+
+    #[cfg(feature = "uuid")]
+    #[test]
+    fn uuid_buckets_are_balanced() {
+        // version 1 UUIDs from one host differ only in their timestamp
+        let base = 0xc232ab00_9414_11ec_b3c8_9f6bdeced846u128;
+        let odd = (0..1000u128)
+            .filter(|&tick| {
+                uuid_shard_parity(Uuid::from_u128(base + (tick << 96))) == ParityKind::Odd
+            })
+            .count();
+        assert_eq!(odd, 500);
+    }
+}
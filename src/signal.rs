@@ -0,0 +1,113 @@
+//! Even and odd parts of sampled functions, and even and odd phases of sampled signals.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::iter::{Skip, StepBy};
+use core::slice;
+
+use crate::ParityKind;
+
+/// Splits the samples `x` into their even and odd parts about the center of the slice.
+///
+/// The even part satisfies `even[i] == even[n - 1 - i]`, the odd part satisfies `odd[i] == -odd[n - 1 - i]`, and
+/// `even[i] + odd[i]` is `x[i]` up to rounding. For an odd number of samples the center sample belongs entirely to
+/// the even part.
+///
+/// # Example
+/// ```
+/// use parity::signal::even_odd_decompose;
+///
+/// let (even, odd) = even_odd_decompose(&[1.0, 2.0, 6.0]);
+/// assert_eq!(even, [3.5, 2.0, 3.5]);
+/// assert_eq!(odd, [-2.5, 0.0, 2.5]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn even_odd_decompose(x: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    x.iter()
+        .zip(x.iter().rev())
+        .map(|(&a, &b)| ((a + b) / 2.0, (a - b) / 2.0))
+        .unzip()
+}
+
+/// Returns an iterator over the samples of `x` whose index has the given `phase` parity.
+///
+/// Phase [ParityKind::Even] yields `x[0], x[2], ...` and phase [ParityKind::Odd] yields `x[1], x[3], ...`, the
+/// two polyphase components used by half-band filters and decimation-in-time FFTs.
+///
+/// # Example
+/// ```
+/// use parity::signal::polyphase;
+/// use parity::ParityKind;
+///
+/// let x = [1, 2, 3, 4, 5];
+/// assert!(polyphase(&x, ParityKind::Even).eq(&[1, 3, 5]));
+/// assert!(polyphase(&x, ParityKind::Odd).eq(&[2, 4]));
+/// ```
+#[inline]
+pub fn polyphase<T>(x: &[T], phase: ParityKind) -> StepBy<Skip<slice::Iter<'_, T>>> {
+    x.iter().skip(phase.is_odd() as usize).step_by(2)
+}
+
+/// Splits the samples `x` into the even-indexed samples (phase 0) and the odd-indexed samples (phase 1).
+///
+/// For an odd number of samples the even phase has one sample more. This is [deinterleave](crate::slice::deinterleave)
+/// under its signal processing name.
+///
+/// # Example
+/// ```
+/// use parity::signal::split_polyphase;
+///
+/// let (even, odd) = split_polyphase(&[1.0, -1.0, 2.0, -2.0, 3.0]);
+/// assert_eq!(even, [1.0, 2.0, 3.0]);
+/// assert_eq!(odd, [-1.0, -2.0]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn split_polyphase<T: Clone>(x: &[T]) -> (Vec<T>, Vec<T>) {
+    crate::slice::deinterleave(x)
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symmetric_parts() {
+        for n in 0..8 {
+            let x: Vec<f64> = (0..n)
+                .map(|i| f64::from(i * i) - 3.5 * f64::from(i))
+                .collect();
+            let (even, odd) = even_odd_decompose(&x);
+            for i in 0..x.len() {
+                let j = x.len() - 1 - i;
+                assert_eq!(even[i], even[j]);
+                assert_eq!(odd[i], -odd[j]);
+                assert_eq!(even[i] + odd[i], x[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn already_symmetric() {
+        let (even, odd) = even_odd_decompose(&[1.0, 4.0, 4.0, 1.0]);
+        assert_eq!(
+            (even, odd),
+            (std::vec![1.0, 4.0, 4.0, 1.0], std::vec![0.0; 4])
+        );
+        let (even, odd) = even_odd_decompose(&[-2.0, 0.0, 2.0]);
+        assert_eq!((even, odd), (std::vec![0.0; 3], std::vec![-2.0, 0.0, 2.0]));
+    }
+
+    #[test]
+    fn polyphase_split() {
+        for n in 0..7 {
+            let x: Vec<u32> = (0..n).collect();
+            let (even, odd) = split_polyphase(&x);
+            assert_eq!(even.len(), x.len().div_ceil(2));
+            assert_eq!(odd.len(), x.len() / 2);
+            assert!(even.iter().all(|i| i % 2 == 0));
+            assert!(odd.iter().all(|i| i % 2 == 1));
+            assert_eq!(polyphase(&x, ParityKind::Odd).len(), odd.len());
+        }
+        assert_eq!(polyphase::<u8>(&[], ParityKind::Even).next(), None);
+    }
+}
@@ -0,0 +1,141 @@
+//! Vectorized filtering of even and odd integers.
+//!
+//! On x86-64 processors with AVX2 the filters classify and compress eight values at a time, checking for AVX2 at
+//! runtime when `std` is enabled and at compile time otherwise. Everywhere else they fall back to a scalar loop, so
+//! the results never depend on the hardware.
+
+use alloc::vec::Vec;
+
+use crate::ParityKind;
+
+/// Returns the even values of `values`, in order.
+///
+/// # Example
+/// ```
+/// use parity::simd::filter_even_simd;
+///
+/// let values: Vec<u32> = (0..20).collect();
+/// assert_eq!(filter_even_simd(&values), [0, 2, 4, 6, 8, 10, 12, 14, 16, 18]);
+/// ```
+#[inline]
+pub fn filter_even_simd(values: &[u32]) -> Vec<u32> {
+    filter_parity_simd(values, ParityKind::Even)
+}
+
+/// Returns the odd values of `values`, in order.
+#[inline]
+pub fn filter_odd_simd(values: &[u32]) -> Vec<u32> {
+    filter_parity_simd(values, ParityKind::Odd)
+}
+
+/// Returns the values of `values` with the given `parity`, in order.
+pub fn filter_parity_simd(values: &[u32], parity: ParityKind) -> Vec<u32> {
+    let mut out = Vec::new();
+    #[cfg(target_arch = "x86_64")]
+    if has_avx2() {
+        // SAFETY: AVX2 is available
+        unsafe { avx2::filter(values, parity.is_odd(), &mut out) };
+        return out;
+    }
+    scalar_filter(values, parity.is_odd(), &mut out);
+    out
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline]
+fn has_avx2() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+fn scalar_filter(values: &[u32], odd: bool, out: &mut Vec<u32>) {
+    out.extend(values.iter().filter(|&&x| (x & 1 == 1) == odd));
+}
+
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use alloc::vec::Vec;
+    use core::arch::x86_64::*;
+
+    /// For each 8-bit lane mask, the indices of the selected lanes packed to the front.
+    static COMPRESS: [[u32; 8]; 256] = {
+        let mut table = [[0; 8]; 256];
+        let mut mask = 0;
+        while mask < 256 {
+            let (mut lane, mut packed) = (0, 0);
+            while lane < 8 {
+                if mask >> lane & 1 == 1 {
+                    table[mask][packed] = lane as u32;
+                    packed += 1;
+                }
+                lane += 1;
+            }
+            mask += 1;
+        }
+        table
+    };
+
+    /// Appends the values of `values` whose low bit is `odd` to `out`.
+    ///
+    /// # Safety
+    /// AVX2 must be available.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn filter(values: &[u32], odd: bool, out: &mut Vec<u32>) {
+        let one = _mm256_set1_epi32(1);
+        let wanted = if odd { one } else { _mm256_setzero_si256() };
+        // every chunk stores all eight lanes, so keep room for a full vector past the values kept so far
+        out.reserve(values.len());
+        let chunks = values.chunks_exact(8);
+        let rest = chunks.remainder();
+        for chunk in chunks {
+            let len = out.len();
+            // SAFETY: chunks are eight values long and `out` has room for eight more since at most `len` of the
+            // values before this chunk were kept
+            unsafe {
+                let v = _mm256_loadu_si256(chunk.as_ptr().cast());
+                let selected = _mm256_cmpeq_epi32(_mm256_and_si256(v, one), wanted);
+                let mask = _mm256_movemask_ps(_mm256_castsi256_ps(selected)) as usize;
+                let indices = _mm256_loadu_si256(COMPRESS[mask].as_ptr().cast());
+                let packed = _mm256_permutevar8x32_epi32(v, indices);
+                _mm256_storeu_si256(out.as_mut_ptr().add(len).cast(), packed);
+                out.set_len(len + mask.count_ones() as usize);
+            }
+        }
+        super::scalar_filter(rest, odd, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn matches_scalar_filter() {
+        let mut seed = 0x853c_49e6_748f_ea9bu64;
+        for len in (0..40).chain([255, 256, 1001]) {
+            let values: Vec<u32> = (0..len)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed as u32
+                })
+                .collect();
+            for parity in [ParityKind::Even, ParityKind::Odd] {
+                let mut expected = Vec::new();
+                scalar_filter(&values, parity.is_odd(), &mut expected);
+                assert_eq!(filter_parity_simd(&values, parity), expected);
+            }
+        }
+        assert_eq!(filter_odd_simd(&[1, 2, 3, u32::MAX]), [1, 3, u32::MAX]);
+        assert!(filter_even_simd(&[7; 9]).is_empty());
+    }
+}
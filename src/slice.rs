@@ -0,0 +1,816 @@
+//! Parity-aware helpers for slices.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::{Skip, StepBy};
+use core::ops::{Deref, Index};
+
+use crate::iter::ParityRunStats;
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// The error returned when an even-length view is requested of an odd-length slice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct OddLengthError {
+    len: usize,
+}
+
+impl OddLengthError {
+    /// Returns the length of the rejected slice.
+    pub fn slice_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl fmt::Display for OddLengthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected an even number of elements, found {}", self.len)
+    }
+}
+
+impl core::error::Error for OddLengthError {}
+
+/// A borrowed slice that is guaranteed to have an even length.
+///
+/// Checking the length once at construction allows pair-wise access without handling a leftover element, and the
+/// split operations only produce views that keep the guarantee.
+///
+/// # Example
+/// ```
+/// use parity::slice::EvenSlice;
+///
+/// let words = EvenSlice::try_new(&[0x12u8, 0x34, 0x56, 0x78]).unwrap();
+/// let values: Vec<u16> = words.pairs().map(|&pair| u16::from_be_bytes(pair)).collect();
+/// assert_eq!(values, [0x1234, 0x5678]);
+///
+/// assert!(EvenSlice::try_new(&[1, 2, 3]).is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct EvenSlice<'a, T> {
+    slice: &'a [T],
+}
+
+impl<T> Clone for EvenSlice<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for EvenSlice<'_, T> {}
+
+impl<'a, T> EvenSlice<'a, T> {
+    /// Wraps `slice`, failing if its length is odd.
+    #[inline]
+    pub const fn try_new(slice: &'a [T]) -> Result<Self, OddLengthError> {
+        if crate::const_is_even_usize(slice.len()) {
+            Ok(EvenSlice { slice })
+        } else {
+            Err(OddLengthError { len: slice.len() })
+        }
+    }
+
+    /// Wraps `slice` without its last element if its length is odd.
+    #[inline]
+    pub const fn new_truncated(slice: &'a [T]) -> Self {
+        EvenSlice {
+            slice: slice.as_chunks::<2>().0.as_flattened(),
+        }
+    }
+
+    /// Returns an empty view.
+    #[inline]
+    pub const fn empty() -> Self {
+        EvenSlice { slice: &[] }
+    }
+
+    /// Returns the underlying slice.
+    #[inline]
+    pub const fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+
+    /// Returns the elements as a slice of consecutive pairs.
+    #[inline]
+    pub const fn as_pairs(&self) -> &'a [[T; 2]] {
+        self.slice.as_chunks::<2>().0
+    }
+
+    /// Returns an iterator over consecutive pairs of elements.
+    #[inline]
+    pub fn pairs(&self) -> core::slice::Iter<'a, [T; 2]> {
+        self.as_pairs().iter()
+    }
+
+    /// Returns the number of pairs, which is half the length.
+    #[inline]
+    pub const fn pair_count(&self) -> usize {
+        self.slice.len() / 2
+    }
+
+    /// Splits the view after `pairs` pairs, i.e. at index `2 * pairs`.
+    ///
+    /// # Panics
+    /// Panics if `pairs` is greater than [EvenSlice::pair_count].
+    #[inline]
+    pub fn split_at_pair(&self, pairs: usize) -> (Self, Self) {
+        assert!(pairs <= self.pair_count(), "split position out of bounds");
+        let (head, tail) = self.slice.split_at(pairs * 2);
+        (EvenSlice { slice: head }, EvenSlice { slice: tail })
+    }
+
+    /// Splits the view at an even index, or returns `None` if `mid` is odd or out of bounds.
+    #[inline]
+    pub fn checked_split_at(&self, mid: usize) -> Option<(Self, Self)> {
+        if mid.is_even() && mid <= self.slice.len() {
+            Some(self.split_at_pair(mid / 2))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first pair and the rest of the view, or `None` if it is empty.
+    #[inline]
+    pub fn split_first_pair(&self) -> Option<(&'a [T; 2], Self)> {
+        let (first, rest) = self.as_pairs().split_first()?;
+        Some((
+            first,
+            EvenSlice {
+                slice: rest.as_flattened(),
+            },
+        ))
+    }
+
+    /// Returns the last pair and the rest of the view, or `None` if it is empty.
+    #[inline]
+    pub fn split_last_pair(&self) -> Option<(&'a [T; 2], Self)> {
+        let (last, rest) = self.as_pairs().split_last()?;
+        Some((
+            last,
+            EvenSlice {
+                slice: rest.as_flattened(),
+            },
+        ))
+    }
+}
+
+impl<T> Deref for EvenSlice<'_, T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<T> AsRef<[T]> for EvenSlice<'_, T> {
+    #[inline]
+    fn as_ref(&self) -> &[T] {
+        self.slice
+    }
+}
+
+impl<T> Default for EvenSlice<'_, T> {
+    #[inline]
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl<'a, T> TryFrom<&'a [T]> for EvenSlice<'a, T> {
+    type Error = OddLengthError;
+
+    #[inline]
+    fn try_from(slice: &'a [T]) -> Result<Self, OddLengthError> {
+        Self::try_new(slice)
+    }
+}
+
+impl<'a, T> IntoIterator for EvenSlice<'a, T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.iter()
+    }
+}
+
+/// The median of a collection, which depends on the parity of its length.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Median<T> {
+    /// The middle element of an odd number of elements.
+    Middle(T),
+    /// The two middle elements of an even number of elements, the lower one first.
+    Pair(T, T),
+}
+
+impl<T> Median<T> {
+    /// Returns the lower and upper middle elements, which are the same element for [Median::Middle].
+    #[inline]
+    pub fn bounds(self) -> (T, T)
+    where
+        T: Clone,
+    {
+        match self {
+            Median::Middle(middle) => (middle.clone(), middle),
+            Median::Pair(lower, upper) => (lower, upper),
+        }
+    }
+
+    /// Applies `f` to each middle element.
+    #[inline]
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Median<U> {
+        match self {
+            Median::Middle(middle) => Median::Middle(f(middle)),
+            Median::Pair(lower, upper) => Median::Pair(f(lower), f(upper)),
+        }
+    }
+}
+
+/// Returns the median of `values`: the middle element for an odd length, and the two middle elements for an even
+/// length. Returns `None` for an empty slice.
+///
+/// The slice is reordered in linear time so that the middle elements are in place, without sorting it.
+///
+/// # Example
+/// ```
+/// use parity::slice::{median_by_parity, Median};
+///
+/// let mut odd = [5, 1, 4];
+/// assert_eq!(median_by_parity(&mut odd), Some(Median::Middle(&4)));
+///
+/// let mut even = [7, 1, 4, 2];
+/// let median = median_by_parity(&mut even).unwrap();
+/// assert_eq!(median, Median::Pair(&2, &4));
+/// let (lower, upper) = median.bounds();
+/// assert_eq!((lower + upper) as f64 / 2.0, 3.0);
+/// ```
+pub fn median_by_parity<T: Ord>(values: &mut [T]) -> Option<Median<&T>> {
+    median_by_parity_by(values, T::cmp)
+}
+
+/// Returns the median of `values` with respect to the comparison function `compare`. See [median_by_parity].
+///
+/// # Example
+/// ```
+/// use parity::slice::{median_by_parity_by, Median};
+///
+/// let mut samples = [2.5, -1.0, 8.0, 0.5];
+/// let median = median_by_parity_by(&mut samples, f64::total_cmp);
+/// assert_eq!(median, Some(Median::Pair(&0.5, &2.5)));
+/// ```
+pub fn median_by_parity_by<T, F>(values: &mut [T], mut compare: F) -> Option<Median<&T>>
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let len = values.len();
+    if len == 0 {
+        return None;
+    }
+    let (lower, upper, _) = values.select_nth_unstable_by(len / 2, &mut compare);
+    match len.parity() {
+        ParityKind::Odd => Some(Median::Middle(&*upper)),
+        ParityKind::Even => {
+            // the lower middle element is the largest of the lower half
+            let lower = lower.iter().max_by(|a, b| compare(a, b))?;
+            Some(Median::Pair(lower, &*upper))
+        }
+    }
+}
+
+/// Classifies every element of `values` at once, returning a bitmap where bit `i % 64` of word `i / 64` is set if
+/// `values[i]` is even.
+///
+/// Bits past the end of the slice are clear, so they do not count as even elements.
+///
+/// # Example
+/// ```
+/// use parity::slice::parity_bitmap;
+///
+/// let values: Vec<u32> = (0..100).map(|i| i * 3).collect();
+/// let bitmap = parity_bitmap(&values);
+/// assert_eq!(bitmap.len(), 2);
+/// assert!(bitmap[0] & 1 != 0); // 0 is even
+/// assert!(bitmap[0] & 2 == 0); // 3 is odd
+/// let evens: u32 = bitmap.iter().map(|word| word.count_ones()).sum();
+/// assert_eq!(evens, 50);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parity_bitmap<T: Parity>(values: &[T]) -> Vec<u64> {
+    values.chunks(64).map(bitmap_word).collect()
+}
+
+/// Returns the [parity_bitmap] word of up to 64 values.
+#[cfg(feature = "alloc")]
+pub(crate) fn bitmap_word<T: Parity>(chunk: &[T]) -> u64 {
+    chunk
+        .iter()
+        .enumerate()
+        .fold(0, |word, (i, value)| word | (value.is_even() as u64) << i)
+}
+
+/// Classifies every element of `values` at once, returning a [BitVec](bitvec::vec::BitVec) whose bit `i` is set if
+/// `values[i]` is even. See [parity_bitmap].
+#[cfg(all(feature = "alloc", feature = "bitvec"))]
+pub fn parity_bitvec<T: Parity>(values: &[T]) -> bitvec::vec::BitVec<u64> {
+    let mut bits = bitvec::vec::BitVec::from_vec(parity_bitmap(values));
+    bits.truncate(values.len());
+    bits
+}
+
+/// Reorders `values` so that the even elements come first, returning how many there are.
+///
+/// The relative order of the elements is not preserved. Elements without a parity, such as fractional floats, are
+/// placed after the even ones.
+///
+/// Every element costs one unconditional swap and no data-dependent branch, so the running time does not suffer
+/// from branch misprediction when evens and odds are mixed unpredictably.
+///
+/// # Example
+/// ```
+/// use parity::slice::partition_by_parity;
+/// use parity::Parity;
+///
+/// let mut values = [5, 2, 7, 8, 1, 4];
+/// let evens = partition_by_parity(&mut values);
+/// assert_eq!(evens, 3);
+/// assert!(values[..evens].iter().all(i32::is_even));
+/// assert!(values[evens..].iter().all(i32::is_odd));
+/// ```
+pub fn partition_by_parity<T: Parity>(values: &mut [T]) -> usize {
+    let mut evens = 0;
+    for i in 0..values.len() {
+        // values[..evens] are even and values[evens..i] are not, so the swap either moves an even value to the end
+        // of the even run or exchanges two values that are not even
+        let even = values[i].is_even();
+        values.swap(evens, i);
+        evens += even as usize;
+    }
+    evens
+}
+
+/// Splits `values` into its even-indexed and odd-indexed elements, such as the left and right channels of
+/// interleaved stereo samples or the real and imaginary parts of interleaved complex numbers.
+///
+/// For an odd number of elements the even-indexed half has one element more.
+///
+/// # Example
+/// ```
+/// use parity::slice::deinterleave;
+///
+/// let (left, right) = deinterleave(&[1, -1, 2, -2, 3]);
+/// assert_eq!(left, [1, 2, 3]);
+/// assert_eq!(right, [-1, -2]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn deinterleave<T: Clone>(values: &[T]) -> (Vec<T>, Vec<T>) {
+    let mut even = Vec::with_capacity(values.len().div_ceil(2));
+    let mut odd = Vec::with_capacity(values.len() / 2);
+    for pair in values.chunks(2) {
+        even.push(pair[0].clone());
+        odd.extend(pair.get(1).cloned());
+    }
+    (even, odd)
+}
+
+/// Writes the even-indexed elements of `values` to `even` and the odd-indexed elements to `odd`, converting an
+/// interleaved buffer into two planar ones without allocating.
+///
+/// # Panics
+/// Panics if `even` does not have room for exactly the `values.len().div_ceil(2)` even-indexed elements and `odd`
+/// for exactly the `values.len() / 2` odd-indexed ones.
+///
+/// # Example
+/// ```
+/// use parity::slice::deinterleave_into;
+///
+/// let (mut re, mut im) = ([0.0; 2], [0.0; 2]);
+/// deinterleave_into(&[1.0, 0.5, -2.0, 4.0], &mut re, &mut im);
+/// assert_eq!((re, im), ([1.0, -2.0], [0.5, 4.0]));
+/// ```
+#[track_caller]
+pub fn deinterleave_into<T: Clone>(values: &[T], even: &mut [T], odd: &mut [T]) {
+    assert!(
+        even.len() == values.len().div_ceil(2) && odd.len() == values.len() / 2,
+        "{} elements do not deinterleave into planes of {} and {} elements",
+        values.len(),
+        even.len(),
+        odd.len()
+    );
+    for (plane, phase) in [(even, 0), (odd, 1)] {
+        for (slot, value) in plane.iter_mut().zip(values.iter().skip(phase).step_by(2)) {
+            slot.clone_from(value);
+        }
+    }
+}
+
+macro_rules! index_parity_view {
+    ($(#[$attr:meta])* $Name:ident, $offset:literal, $parity:literal) => {
+        $(#[$attr])*
+        #[derive(Debug, PartialEq, Eq, Hash)]
+        pub struct $Name<'a, T> {
+            slice: &'a [T],
+        }
+
+        impl<T> Clone for $Name<'_, T> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<T> Copy for $Name<'_, T> {}
+
+        impl<'a, T> $Name<'a, T> {
+            #[doc = concat!("Returns a view of the ", $parity, "-indexed elements of `slice`.")]
+            #[inline]
+            pub const fn new(slice: &'a [T]) -> Self {
+                Self { slice }
+            }
+
+            /// Returns the underlying slice, including the elements hidden by the view.
+            #[inline]
+            pub const fn as_slice(&self) -> &'a [T] {
+                self.slice
+            }
+
+            /// Returns the number of elements in the view.
+            #[inline]
+            pub const fn len(&self) -> usize {
+                if $offset == 0 {
+                    self.slice.len().div_ceil(2)
+                } else {
+                    self.slice.len() / 2
+                }
+            }
+
+            /// Returns `true` if the view has no elements.
+            #[inline]
+            pub const fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            #[doc = concat!("Returns element `i` of the view, which is element `2 * i + ", $offset, "` of the slice,")]
+            /// or `None` if it is out of bounds.
+            #[inline]
+            pub fn get(&self, i: usize) -> Option<&'a T> {
+                self.slice.get(i.checked_mul(2)?.checked_add($offset)?)
+            }
+
+            /// Returns an iterator over the elements of the view.
+            #[inline]
+            pub fn iter(&self) -> StepBy<Skip<core::slice::Iter<'a, T>>> {
+                self.slice.iter().skip($offset).step_by(2)
+            }
+        }
+
+        impl<T> Index<usize> for $Name<'_, T> {
+            type Output = T;
+
+            #[inline]
+            #[track_caller]
+            fn index(&self, i: usize) -> &T {
+                match self.get(i) {
+                    Some(element) => element,
+                    None => panic!("index {i} is out of bounds for a view of length {}", self.len()),
+                }
+            }
+        }
+
+        impl<'a, T> IntoIterator for $Name<'a, T> {
+            type Item = &'a T;
+            type IntoIter = StepBy<Skip<core::slice::Iter<'a, T>>>;
+
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+    };
+}
+
+index_parity_view! {
+    /// A view of the elements at even indices of a slice, `slice[0], slice[2], ...`, without copying.
+    ///
+    /// # Example
+    /// ```
+    /// use parity::slice::{EvenView, OddView};
+    ///
+    /// // interleaved stereo samples
+    /// let samples = [1, -1, 2, -2, 3, -3];
+    /// let (left, right) = (EvenView::new(&samples), OddView::new(&samples));
+    /// assert_eq!((left.len(), left[2]), (3, 3));
+    /// assert!(right.iter().eq(&[-1, -2, -3]));
+    /// ```
+    EvenView, 0, "even"
+}
+
+index_parity_view! {
+    /// A view of the elements at odd indices of a slice, `slice[1], slice[3], ...`, without copying.
+    ///
+    /// See [EvenView] for an example.
+    OddView, 1, "odd"
+}
+
+/// Extension methods locating the elements of a given parity in a slice, implemented for every `[T]`.
+///
+/// The `first` methods scan from the front and the `last` methods from the back, stopping at the first match.
+/// Elements without a parity never match.
+///
+/// # Example
+/// ```
+/// use parity::slice::ParitySlice;
+///
+/// let fields = [4, 8, 15, 16, 23, 42];
+/// assert_eq!(fields.find_first_odd(), Some((2, &15)));
+/// assert_eq!(fields.find_last_odd(), Some((4, &23)));
+/// assert_eq!(fields[2..3].find_first_even(), None);
+/// ```
+pub trait ParitySlice<T> {
+    /// Returns the index of the first element of the given `parity`, and the element.
+    fn find_first_with_parity(&self, parity: ParityKind) -> Option<(usize, &T)>
+    where
+        T: Parity;
+
+    /// Returns the index of the last element of the given `parity`, and the element.
+    fn find_last_with_parity(&self, parity: ParityKind) -> Option<(usize, &T)>
+    where
+        T: Parity;
+
+    /// Returns statistics of the runs of consecutive elements with the same parity. See
+    /// [ParityIterator::parity_run_stats](crate::ParityIterator::parity_run_stats).
+    ///
+    /// # Example
+    /// ```
+    /// use parity::slice::ParitySlice;
+    ///
+    /// let stats = [2, 4, 1, 6, 8, 10].parity_run_stats();
+    /// assert_eq!((stats.runs, stats.longest_run, stats.same_parity_pairs), (3, 3, 3));
+    /// ```
+    fn parity_run_stats(&self) -> ParityRunStats
+    where
+        T: Parity;
+
+    /// Returns the index of the first even element, and the element.
+    #[inline]
+    fn find_first_even(&self) -> Option<(usize, &T)>
+    where
+        T: Parity,
+    {
+        self.find_first_with_parity(ParityKind::Even)
+    }
+
+    /// Returns the index of the first odd element, and the element.
+    #[inline]
+    fn find_first_odd(&self) -> Option<(usize, &T)>
+    where
+        T: Parity,
+    {
+        self.find_first_with_parity(ParityKind::Odd)
+    }
+
+    /// Returns the index of the last even element, and the element.
+    #[inline]
+    fn find_last_even(&self) -> Option<(usize, &T)>
+    where
+        T: Parity,
+    {
+        self.find_last_with_parity(ParityKind::Even)
+    }
+
+    /// Returns the index of the last odd element, and the element.
+    #[inline]
+    fn find_last_odd(&self) -> Option<(usize, &T)>
+    where
+        T: Parity,
+    {
+        self.find_last_with_parity(ParityKind::Odd)
+    }
+}
+
+impl<T> ParitySlice<T> for [T] {
+    #[inline]
+    fn find_first_with_parity(&self, parity: ParityKind) -> Option<(usize, &T)>
+    where
+        T: Parity,
+    {
+        self.iter()
+            .enumerate()
+            .find(|(_, value)| value.checked_parity() == Some(parity))
+    }
+
+    #[inline]
+    fn find_last_with_parity(&self, parity: ParityKind) -> Option<(usize, &T)>
+    where
+        T: Parity,
+    {
+        self.iter()
+            .enumerate()
+            .rfind(|(_, value)| value.checked_parity() == Some(parity))
+    }
+
+    #[inline]
+    fn parity_run_stats(&self) -> ParityRunStats
+    where
+        T: Parity,
+    {
+        ParityRunStats::of(self.iter().map(Parity::checked_parity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn construction() {
+        let data = [1, 2, 3, 4, 5];
+        assert_eq!(EvenSlice::try_new(&data).unwrap_err().slice_len(), 5);
+        assert_eq!(EvenSlice::new_truncated(&data).as_slice(), [1, 2, 3, 4]);
+        assert_eq!(EvenSlice::try_from(&data[1..]).unwrap().len(), 4);
+        assert!(EvenSlice::<u8>::default().is_empty());
+    }
+
+    #[test]
+    fn splitting() {
+        let data = [1, 2, 3, 4, 5, 6];
+        let even = EvenSlice::try_new(&data).unwrap();
+        let (head, tail) = even.split_at_pair(1);
+        assert_eq!(
+            (head.as_slice(), tail.as_slice()),
+            (&[1, 2][..], &[3, 4, 5, 6][..])
+        );
+        assert!(even.checked_split_at(3).is_none());
+        assert!(even.checked_split_at(8).is_none());
+        assert_eq!(even.checked_split_at(6).unwrap().1.len(), 0);
+
+        let (first, rest) = even.split_first_pair().unwrap();
+        assert_eq!((first, rest.as_slice()), (&[1, 2], &[3, 4, 5, 6][..]));
+        let (last, rest) = even.split_last_pair().unwrap();
+        assert_eq!((last, rest.as_slice()), (&[5, 6], &[1, 2, 3, 4][..]));
+        assert!(EvenSlice::<u8>::empty().split_first_pair().is_none());
+
+        assert_eq!(even.pairs().count(), even.pair_count());
+        assert_eq!(even.into_iter().sum::<i32>(), 21);
+    }
+
+    #[test]
+    fn medians() {
+        assert_eq!(median_by_parity::<u8>(&mut []), None);
+        assert_eq!(median_by_parity(&mut [3]), Some(Median::Middle(&3)));
+        assert_eq!(median_by_parity(&mut [3, 1]), Some(Median::Pair(&1, &3)));
+        assert_eq!(
+            median_by_parity(&mut [2, 2, 9, 2]),
+            Some(Median::Pair(&2, &2))
+        );
+        for len in 1..12 {
+            let mut values: std::vec::Vec<i32> = (0..len).map(|i| (i * 7) % 11).collect();
+            let mut sorted = values.clone();
+            sorted.sort();
+            let median = median_by_parity(&mut values).unwrap().map(|&x| x);
+            let mid = sorted.len() / 2;
+            let expected = if len % 2 == 1 {
+                Median::Middle(sorted[mid])
+            } else {
+                Median::Pair(sorted[mid - 1], sorted[mid])
+            };
+            assert_eq!(median, expected);
+        }
+        assert_eq!(Median::Middle(4).bounds(), (4, 4));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bitmaps() {
+        let values: std::vec::Vec<i64> = (-70..70).map(|i| i * i + i / 3).collect();
+        let bitmap = parity_bitmap(&values);
+        assert_eq!(bitmap.len(), 3);
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(bitmap[i / 64] >> (i % 64) & 1 == 1, value.is_even());
+        }
+        assert_eq!(bitmap[2] >> (values.len() % 64), 0);
+        assert!(parity_bitmap::<u8>(&[]).is_empty());
+        // floats without a parity are not even
+        assert_eq!(parity_bitmap(&[2.0, 0.5, f64::NAN, 3.0]), [0b0001]);
+
+        #[cfg(feature = "bitvec")]
+        {
+            let bits = parity_bitvec(&values);
+            assert_eq!(bits.len(), values.len());
+            assert!(bits
+                .iter()
+                .zip(&values)
+                .all(|(bit, value)| *bit == value.is_even()));
+        }
+    }
+
+    #[test]
+    fn partitions() {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        for len in 0..70 {
+            let mut values: std::vec::Vec<u16> = (0..len)
+                .map(|_| {
+                    seed ^= seed << 13;
+                    seed ^= seed >> 7;
+                    seed ^= seed << 17;
+                    seed as u16
+                })
+                .collect();
+            let mut sorted = values.clone();
+            let evens = partition_by_parity(&mut values);
+            assert_eq!(evens, sorted.iter().filter(|x| x.is_even()).count());
+            assert!(values[..evens].iter().all(u16::is_even));
+            assert!(values[evens..].iter().all(u16::is_odd));
+            // a permutation of the input
+            sorted.sort();
+            values.sort();
+            assert_eq!(values, sorted);
+        }
+        let mut floats = [0.5, 2.0, 1.0, f64::NAN, 4.0];
+        assert_eq!(partition_by_parity(&mut floats), 2);
+        assert!(floats[..2].iter().all(f64::is_even));
+    }
+
+    #[test]
+    fn index_views() {
+        for len in 0..7 {
+            let data: std::vec::Vec<usize> = (0..len).collect();
+            let (even, odd) = (EvenView::new(&data), OddView::new(&data));
+            assert_eq!(even.len() + odd.len(), len);
+            assert_eq!(even.len(), even.iter().count());
+            assert_eq!(odd.len(), odd.into_iter().count());
+            for i in 0..even.len() {
+                assert_eq!(even[i], 2 * i);
+            }
+            for i in 0..odd.len() {
+                assert_eq!(odd[i], 2 * i + 1);
+            }
+            assert_eq!(even.get(even.len()), None);
+            assert_eq!(odd.get(usize::MAX), None);
+        }
+        assert!(OddView::new(&[1]).is_empty());
+        assert_eq!(EvenView::new(&[1, 2]).as_slice(), [1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds for a view of length 1")]
+    fn view_out_of_bounds() {
+        let _ = OddView::new(&[1, 2, 3])[1];
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn deinterleaving() {
+        for len in 0..7 {
+            let values: Vec<usize> = (0..len).collect();
+            let (even, odd) = deinterleave(&values);
+            assert!(even.iter().all(|i| i % 2 == 0) && odd.iter().all(|i| i % 2 == 1));
+            assert_eq!(even.len() + odd.len(), len);
+
+            let (mut even_plane, mut odd_plane) =
+                (std::vec![0; even.len()], std::vec![0; odd.len()]);
+            deinterleave_into(&values, &mut even_plane, &mut odd_plane);
+            assert_eq!((even_plane, odd_plane), (even, odd));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "3 elements do not deinterleave into planes of 1 and 1 elements")]
+    fn deinterleave_into_checks_lengths() {
+        deinterleave_into(&[1, 2, 3], &mut [0], &mut [0]);
+    }
+
+    #[test]
+    fn find_by_parity() {
+        let values = [0.5, 3.0, 2.0, f64::NAN, 7.0, 4.0, 1.5];
+        assert_eq!(values.find_first_odd(), Some((1, &3.0)));
+        assert_eq!(values.find_first_even(), Some((2, &2.0)));
+        assert_eq!(values.find_last_odd(), Some((4, &7.0)));
+        assert_eq!(values.find_last_even(), Some((5, &4.0)));
+        assert_eq!(values[..1].find_first_with_parity(ParityKind::Even), None);
+        assert_eq!(values[6..].find_last_with_parity(ParityKind::Odd), None);
+        assert_eq!(<[u8]>::find_first_even(&[]), None);
+
+        let repeated = [1u8, 1, 1];
+        assert_eq!(repeated.find_first_odd().map(|(i, _)| i), Some(0));
+        assert_eq!(repeated.find_last_odd().map(|(i, _)| i), Some(2));
+    }
+
+    #[test]
+    fn slice_run_stats() {
+        use crate::ParityIterator;
+
+        let values = [3i16, 5, -2, 0, 4, 9];
+        assert_eq!(
+            values.parity_run_stats(),
+            values.into_iter().parity_run_stats()
+        );
+        assert_eq!(
+            values.parity_run_stats().longest_run_parity,
+            Some(ParityKind::Even)
+        );
+        assert_eq!(<[u8]>::parity_run_stats(&[]).runs, 0);
+    }
+}
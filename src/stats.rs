@@ -0,0 +1,230 @@
+//! Statistical tests for bias in streams of parities, for auditing random number generators and hash outputs.
+//!
+//! # Example
+//! ```
+//! use parity::stats::parity_balance_test;
+//! use parity::ParityCount;
+//!
+//! let fair = parity_balance_test(ParityCount { even: 5_040, odd: 4_960 });
+//! assert!(fair.is_balanced(0.05));
+//!
+//! let biased = parity_balance_test(ParityCount { even: 5_200, odd: 4_800 });
+//! assert!(!biased.is_balanced(0.05));
+//! ```
+
+use crate::{Parity, ParityCount, ParityKind};
+
+/// The result of [parity_balance_test].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct BalanceReport {
+    /// The observed counts.
+    pub counts: ParityCount,
+    /// The chi-square statistic of the counts against an even split, with one degree of freedom.
+    pub chi_square: f64,
+    /// The probability of a chi-square statistic at least this large if both parities are equally likely.
+    pub p_value: f64,
+}
+
+impl BalanceReport {
+    /// Returns `true` if the counts are consistent with an even split at significance level `alpha`, that is if the
+    /// p-value is at least `alpha`.
+    #[inline]
+    pub fn is_balanced(&self, alpha: f64) -> bool {
+        self.p_value >= alpha
+    }
+}
+
+/// Tests whether `counts` are consistent with even and odd values being equally likely, using Pearson's
+/// chi-square goodness-of-fit test.
+///
+/// The test only looks at the counts, so it cannot detect a stream that alternates too regularly or clusters. Like
+/// every chi-square test it is approximate for small samples, and should not be relied on for fewer than about ten
+/// values of each parity. No values at all are perfectly balanced, with a p-value of one.
+pub fn parity_balance_test(counts: ParityCount) -> BalanceReport {
+    let total = counts.total() as f64;
+    let chi_square = if counts.total() == 0 {
+        0.0
+    } else {
+        let difference = counts.even as f64 - counts.odd as f64;
+        difference * difference / total
+    };
+    BalanceReport {
+        counts,
+        chi_square,
+        p_value: chi_square_1_survival(chi_square),
+    }
+}
+
+/// The result of [runs_test].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RunsReport {
+    /// The number of even and odd values in the sequence.
+    pub counts: ParityCount,
+    /// The number of runs of consecutive values with the same parity.
+    pub runs: usize,
+    /// The number of standard deviations by which the number of runs differs from its expected value for
+    /// independent values, negative for too few runs and positive for too many.
+    ///
+    /// This is NaN if the sequence does not contain both parities, when the number of runs cannot vary.
+    pub z: f64,
+}
+
+impl RunsReport {
+    /// Returns the two-sided p-value of the test: the probability of a number of runs at least this far from the
+    /// expected one if the values are independent. This is NaN if [z](RunsReport::z) is.
+    #[inline]
+    pub fn p_value(&self) -> f64 {
+        libm::erfc(self.z.abs() / core::f64::consts::SQRT_2)
+    }
+
+    /// Returns `true` if the number of runs is consistent with independent values at significance level `alpha`,
+    /// that is if the p-value is at least `alpha`.
+    ///
+    /// A sequence without both parities never passes.
+    #[inline]
+    pub fn passes(&self, alpha: f64) -> bool {
+        self.p_value() >= alpha
+    }
+}
+
+/// Tests whether the parities in a sequence are independent of their neighbours, using the Wald-Wolfowitz runs
+/// test.
+///
+/// Too few runs mean that parities cluster, and too many that they alternate more often than chance allows, as in
+/// a generator whose low bit has a short period. The test is conditional on the counts of each parity, so it
+/// complements [parity_balance_test] instead of replacing it. It uses the normal approximation to the number of
+/// runs, which needs at least about ten values of each parity.
+///
+/// Use [runs_test_of_values] to test the parities of values directly.
+///
+/// # Example
+/// ```
+/// use parity::stats::runs_test;
+/// use parity::ParityKind;
+///
+/// // a strictly alternating stream is perfectly balanced but far from random
+/// let alternating = runs_test(ParityKind::iter().cycle().take(100));
+/// assert_eq!(alternating.runs, 100);
+/// assert!(alternating.z > 9.0);
+/// assert!(!alternating.passes(0.01));
+/// ```
+pub fn runs_test<I: IntoIterator<Item = ParityKind>>(parities: I) -> RunsReport {
+    let mut counts = ParityCount::default();
+    let mut runs = 0;
+    let mut previous = None;
+    for parity in parities {
+        counts.record(parity);
+        if previous != Some(parity) {
+            runs += 1;
+        }
+        previous = Some(parity);
+    }
+
+    let (even, odd) = (counts.even as f64, counts.odd as f64);
+    let n = even + odd;
+    let product = 2.0 * even * odd;
+    let mean = product / n + 1.0;
+    let variance = product * (product - n) / (n * n * (n - 1.0));
+    let z = if counts.even == 0 || counts.odd == 0 {
+        f64::NAN
+    } else {
+        (runs as f64 - mean) / libm::sqrt(variance)
+    };
+    RunsReport { counts, runs, z }
+}
+
+/// Runs the [runs_test] on the parities of `values`, skipping values without a parity.
+///
+/// # Example
+/// ```
+/// use parity::stats::runs_test_of_values;
+///
+/// // a linear congruential generator modulo a power of two alternates its low bit
+/// let mut state = 12345u32;
+/// let lcg = core::iter::repeat_with(|| {
+///     state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+///     state
+/// });
+/// assert!(!runs_test_of_values(lcg.take(1000)).passes(0.01));
+/// ```
+pub fn runs_test_of_values<I>(values: I) -> RunsReport
+where
+    I: IntoIterator,
+    I::Item: Parity,
+{
+    runs_test(
+        values
+            .into_iter()
+            .filter_map(|value| value.checked_parity()),
+    )
+}
+
+/// Returns the probability that a chi-square variable with one degree of freedom exceeds `x`.
+fn chi_square_1_survival(x: f64) -> f64 {
+    // a chi-square variable with one degree of freedom is the square of a standard normal one
+    libm::erfc(libm::sqrt(x / 2.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-6
+    }
+
+    #[test]
+    fn known_values() {
+        let report = parity_balance_test(ParityCount { even: 60, odd: 40 });
+        assert!(close(report.chi_square, 4.0));
+        assert!(close(report.p_value, 0.045_500_3));
+        assert!(!report.is_balanced(0.05));
+        assert!(report.is_balanced(0.01));
+
+        // the 5% critical value of the chi-square distribution with one degree of freedom
+        assert!(close(chi_square_1_survival(3.841_458_8), 0.05));
+        assert_eq!(chi_square_1_survival(0.0), 1.0);
+    }
+
+    #[test]
+    fn symmetric_and_degenerate() {
+        let a = parity_balance_test(ParityCount { even: 7, odd: 30 });
+        let b = parity_balance_test(ParityCount { even: 30, odd: 7 });
+        assert_eq!((a.chi_square, a.p_value), (b.chi_square, b.p_value));
+
+        let empty = parity_balance_test(ParityCount::default());
+        assert_eq!((empty.chi_square, empty.p_value), (0.0, 1.0));
+        let even = parity_balance_test(ParityCount {
+            even: 500,
+            odd: 500,
+        });
+        assert_eq!(even.p_value, 1.0);
+        assert!(parity_balance_test(ParityCount { even: 1000, odd: 0 }).p_value < 1e-100);
+    }
+
+    #[test]
+    fn runs() {
+        use ParityKind::{Even as E, Odd as O};
+
+        // 10 evens and 10 odds in 6 runs: mean 11 and variance 4.7368...
+        let report = runs_test([E, E, E, O, O, O, O, E, E, E, O, O, O, E, E, E, E, O, O, O]);
+        assert_eq!(report.counts, ParityCount { even: 10, odd: 10 });
+        assert_eq!(report.runs, 6);
+        assert!(close(report.z, -5.0 / (4.5 * 20.0 / 19.0f64).sqrt()));
+        assert!(!report.passes(0.05));
+        assert!(report.passes(0.01));
+
+        let one_parity = runs_test([O; 10]);
+        assert_eq!(one_parity.runs, 1);
+        assert!(one_parity.z.is_nan() && !one_parity.passes(0.0));
+        assert!(runs_test([]).z.is_nan());
+
+        let values = runs_test_of_values([1.0, 0.5, 3.0, 2.0, f64::NAN, 4.0, 5.0]);
+        assert_eq!(
+            (values.counts.even, values.counts.odd, values.runs),
+            (2, 3, 3)
+        );
+    }
+}
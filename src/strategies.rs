@@ -0,0 +1,163 @@
+//! `proptest` strategies that only generate integers of a given parity.
+//!
+//! # Example
+//! ```
+//! use parity::strategies::{even, odd_in};
+//! use parity::Parity;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(x in even::<i32>(), y in odd_in(1u8..100))| {
+//!     prop_assert!(x.is_even());
+//!     prop_assert!(y.is_odd() && y < 100);
+//!     prop_assert!((x as i64 + y as i64).is_odd());
+//! });
+//! ```
+
+use core::ops::RangeBounds;
+
+use proptest::prelude::*;
+
+use crate::range::ParityRange;
+use crate::ParityKind;
+
+/// An integer type with parity-constrained strategies.
+///
+/// Implemented for all primitive integer types.
+pub trait StrategyValue: Arbitrary + Copy + core::fmt::Debug + 'static {
+    /// Returns a strategy generating any value of the given `parity`, shrinking toward zero.
+    fn of_parity(parity: ParityKind) -> BoxedStrategy<Self>;
+
+    /// Returns a strategy generating values of the given `parity` uniformly from `range`, shrinking toward its
+    /// start, or `None` if the range contains no such value.
+    fn of_parity_in<B: RangeBounds<Self>>(
+        range: B,
+        parity: ParityKind,
+    ) -> Option<BoxedStrategy<Self>>;
+}
+
+macro_rules! impl_strategy_value {
+    ($($T:ty),*) => { $(
+        impl StrategyValue for $T {
+            fn of_parity(parity: ParityKind) -> BoxedStrategy<Self> {
+                match parity {
+                    ParityKind::Even => any::<$T>().prop_map(|x| x & !1).boxed(),
+                    ParityKind::Odd => any::<$T>().prop_map(|x| x | 1).boxed(),
+                }
+            }
+
+            fn of_parity_in<B: RangeBounds<Self>>(range: B, parity: ParityKind) -> Option<BoxedStrategy<Self>> {
+                let (first, steps) = <$T>::parity_range(&range, parity)?;
+                Some((0..=steps).prop_map(move |k| <$T>::nth_step(first, k)).boxed())
+            }
+        }
+    )* };
+}
+
+impl_strategy_value![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+/// Returns a strategy generating values of the given `parity`.
+#[inline]
+pub fn of_parity<T: StrategyValue>(parity: ParityKind) -> BoxedStrategy<T> {
+    T::of_parity(parity)
+}
+
+/// Returns a strategy generating even values.
+#[inline]
+pub fn even<T: StrategyValue>() -> BoxedStrategy<T> {
+    T::of_parity(ParityKind::Even)
+}
+
+/// Returns a strategy generating odd values.
+#[inline]
+pub fn odd<T: StrategyValue>() -> BoxedStrategy<T> {
+    T::of_parity(ParityKind::Odd)
+}
+
+/// Returns a strategy generating values of the given `parity` from `range`.
+///
+/// # Panics
+/// Panics if `range` contains no value of that parity.
+#[inline]
+#[track_caller]
+pub fn of_parity_in<T: StrategyValue, B: RangeBounds<T>>(
+    range: B,
+    parity: ParityKind,
+) -> BoxedStrategy<T> {
+    match T::of_parity_in(range, parity) {
+        Some(strategy) => strategy,
+        None => panic!("range contains no {parity} values"),
+    }
+}
+
+/// Returns a strategy generating even values from `range`.
+///
+/// # Panics
+/// Panics if `range` contains no even value.
+#[inline]
+#[track_caller]
+pub fn even_in<T: StrategyValue, B: RangeBounds<T>>(range: B) -> BoxedStrategy<T> {
+    of_parity_in(range, ParityKind::Even)
+}
+
+/// Returns a strategy generating odd values from `range`.
+///
+/// # Panics
+/// Panics if `range` contains no odd value.
+#[inline]
+#[track_caller]
+pub fn odd_in<T: StrategyValue, B: RangeBounds<T>>(range: B) -> BoxedStrategy<T> {
+    of_parity_in(range, ParityKind::Odd)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::strategy::ValueTree;
+    use proptest::test_runner::TestRunner;
+
+    use super::*;
+    use crate::Parity;
+
+    proptest! {
+        #[test]
+        fn generated_parity(
+            x in even::<i8>(),
+            y in odd::<u128>(),
+            p in prop_oneof![Just(ParityKind::Even), Just(ParityKind::Odd)],
+        ) {
+            prop_assert!(x.is_even());
+            prop_assert!(y.is_odd());
+            let z: i64 = of_parity(p).new_tree(&mut TestRunner::default()).unwrap().current();
+            prop_assert_eq!(z.checked_parity(), Some(p));
+        }
+
+        #[test]
+        fn generated_ranges(x in even_in(-7i32..=7), y in odd_in(u64::MAX - 4..)) {
+            prop_assert!(x.is_even() && (-6..=6).contains(&x));
+            prop_assert!(y.is_odd() && y >= u64::MAX - 4);
+        }
+    }
+
+    #[test]
+    fn shrinks_toward_range_start() {
+        let mut runner = TestRunner::deterministic();
+        let mut tree = even_in(10u16..1000).new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), 10);
+
+        let mut tree = odd::<i32>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), 1);
+    }
+
+    #[test]
+    fn empty_ranges() {
+        assert!(u8::of_parity_in(4..5, ParityKind::Odd).is_none());
+        assert!(i64::of_parity_in(.., ParityKind::Even).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "range contains no even values")]
+    fn empty_range_panics() {
+        let _ = even_in(1u32..=1);
+    }
+}
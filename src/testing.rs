@@ -0,0 +1,245 @@
+//! Checks for the invariants every [Parity] implementation should uphold, for crates implementing the traits for
+//! their own types.
+//!
+//! The checks panic with the offending value on the first violation, so they can be called directly from a test.
+//!
+//! # Example
+//! ```
+//! use parity::testing::{check_integer_parity_laws, check_parity_laws};
+//! use parity::Parity;
+//!
+//! #[derive(Debug)]
+//! struct Halves(i32);
+//!
+//! // only whole values have a parity
+//! impl Parity for Halves {
+//!     fn is_even(&self) -> bool {
+//!         self.0 % 4 == 0
+//!     }
+//!
+//!     fn is_odd(&self) -> bool {
+//!         self.0 % 4 == 2 || self.0 % 4 == -2
+//!     }
+//! }
+//!
+//! check_parity_laws((-9..=9).map(Halves));
+//! check_integer_parity_laws([0u8, 1, 2, 255]);
+//! ```
+
+use core::fmt::Debug;
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// Asserts that the [Parity] methods agree with each other on every value in `values`:
+///
+/// - no value is both even and odd;
+/// - [Parity::is_parity_defined] holds exactly for the values that are even or odd;
+/// - [Parity::checked_parity] returns the parity reported by [Parity::is_even] and [Parity::is_odd];
+/// - [Parity::same_parity], [Parity::opposite_parity] and [Parity::checked_same_parity] agree with the parities of
+///   each value and of the value before it, and of each value with itself.
+///
+/// Values without a parity are allowed, as for floating-point types.
+///
+/// # Panics
+/// Panics on the first value that breaks one of the laws.
+#[track_caller]
+pub fn check_parity_laws<T: Parity + Debug>(values: impl IntoIterator<Item = T>) {
+    let mut previous = None;
+    for value in values {
+        check_value(&value);
+        check_pair(&value, &value);
+        if let Some(previous) = &previous {
+            check_pair(previous, &value);
+        }
+        previous = Some(value);
+    }
+}
+
+/// Asserts the laws of [check_parity_laws] on every value in `values`, and that every value has a parity that
+/// agrees with [IntegerParity::parity], [IntegerParity::two_adic_valuation] and [IntegerParity::half_exact].
+///
+/// # Panics
+/// Panics on the first value that breaks one of the laws.
+#[track_caller]
+pub fn check_integer_parity_laws<T: IntegerParity + Debug>(values: impl IntoIterator<Item = T>) {
+    let mut previous = None;
+    for value in values {
+        check_value(&value);
+        check_pair(&value, &value);
+        if let Some(previous) = &previous {
+            check_pair(previous, &value);
+        }
+        assert!(
+            value.is_even() != value.is_odd(),
+            "{value:?} is neither even nor odd"
+        );
+        assert_eq!(
+            value.checked_parity(),
+            Some(value.parity()),
+            "parity() disagrees with is_even() and is_odd() for {value:?}"
+        );
+        assert_eq!(
+            value.two_adic_valuation() == Some(0),
+            value.is_odd(),
+            "{value:?} is odd but its 2-adic valuation is not zero, or the other way around"
+        );
+        assert_eq!(
+            value.half_exact().is_some(),
+            value.is_even(),
+            "half_exact() disagrees with is_even() for {value:?}"
+        );
+        previous = Some(value);
+    }
+}
+
+#[track_caller]
+fn check_value<T: Parity + Debug>(value: &T) {
+    let (even, odd) = (value.is_even(), value.is_odd());
+    assert!(!(even && odd), "{value:?} is both even and odd");
+    assert_eq!(
+        value.is_parity_defined(),
+        even || odd,
+        "is_parity_defined() disagrees with is_even() and is_odd() for {value:?}"
+    );
+    let expected = match (even, odd) {
+        (true, _) => Some(ParityKind::Even),
+        (_, true) => Some(ParityKind::Odd),
+        _ => None,
+    };
+    assert_eq!(
+        value.checked_parity(),
+        expected,
+        "checked_parity() disagrees with is_even() and is_odd() for {value:?}"
+    );
+}
+
+#[track_caller]
+fn check_pair<T: Parity + Debug>(a: &T, b: &T) {
+    let expected = a
+        .checked_parity()
+        .zip(b.checked_parity())
+        .map(|(a, b)| a == b);
+    assert_eq!(
+        a.checked_same_parity(b),
+        expected,
+        "checked_same_parity() disagrees with checked_parity() for {a:?} and {b:?}"
+    );
+    assert_eq!(
+        a.same_parity(b),
+        expected == Some(true),
+        "same_parity() disagrees with checked_parity() for {a:?} and {b:?}"
+    );
+    assert_eq!(
+        a.opposite_parity(b),
+        expected == Some(false),
+        "opposite_parity() disagrees with checked_parity() for {a:?} and {b:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RoundingMode;
+
+    #[test]
+    fn primitives_pass() {
+        check_integer_parity_laws(-300i16..300);
+        check_integer_parity_laws([0u128, 1, u128::MAX, u128::MAX - 1]);
+        check_integer_parity_laws([i64::MIN, -1, 0, i64::MAX]);
+        check_parity_laws([
+            0.0f64,
+            -0.0,
+            1.0,
+            -3.0,
+            0.5,
+            1e300,
+            f64::INFINITY,
+            f64::NAN,
+            f64::MIN_POSITIVE,
+        ]);
+        check_parity_laws([2.0f32, 3.0, 2.5, f32::NEG_INFINITY, f32::MAX]);
+    }
+
+    #[derive(Debug)]
+    struct Both(u8);
+
+    impl Parity for Both {
+        fn is_even(&self) -> bool {
+            true
+        }
+
+        fn is_odd(&self) -> bool {
+            self.0 == 3
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Both(3) is both even and odd")]
+    fn both_even_and_odd_fails() {
+        check_parity_laws([Both(1), Both(3)]);
+    }
+
+    #[derive(Debug)]
+    struct Count(u8);
+
+    impl Parity for Count {
+        fn is_even(&self) -> bool {
+            self.0.is_multiple_of(2)
+        }
+
+        fn is_odd(&self) -> bool {
+            self.0 % 2 == 1
+        }
+
+        // wrong for every pair
+        fn checked_same_parity(&self, other: &Self) -> Option<bool> {
+            Some(self.0 % 2 != other.0 % 2)
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "checked_same_parity() disagrees with checked_parity() for Count(4) and Count(4)"
+    )]
+    fn inconsistent_pairs_fail() {
+        check_parity_laws([Count(4)]);
+    }
+
+    #[derive(Debug)]
+    struct Wrong(u8);
+
+    impl Parity for Wrong {
+        fn is_even(&self) -> bool {
+            self.0.is_multiple_of(2)
+        }
+
+        fn is_odd(&self) -> bool {
+            self.0 % 2 == 1
+        }
+    }
+
+    impl IntegerParity for Wrong {
+        fn parity(&self) -> ParityKind {
+            ParityKind::Even
+        }
+
+        fn two_adic_valuation(&self) -> Option<u32> {
+            self.0.two_adic_valuation()
+        }
+
+        fn half_exact(&self) -> Option<Self> {
+            self.0.half_exact().map(Wrong)
+        }
+
+        fn div2(&self, mode: RoundingMode) -> Self {
+            Wrong(self.0.div2(mode))
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "parity() disagrees with is_even() and is_odd() for Wrong(7)")]
+    fn wrong_integer_parity_fails() {
+        check_parity_laws([Wrong(7)]);
+        check_integer_parity_laws([Wrong(2), Wrong(7)]);
+    }
+}
@@ -0,0 +1,127 @@
+//! The `tracing` events emitted by the validation helpers.
+//!
+//! Each event has the `parity` target. Failed checks are reported at the `DEBUG` level with the caller of the
+//! check, since they are often part of normal control flow, and rejected input while decoding at the `WARN` level.
+
+use core::any::type_name;
+use core::panic::Location;
+
+use crate::float::FloatParityError;
+use crate::ParityKind;
+
+/// Records a value of type `T` that did not have the `expected` parity.
+#[track_caller]
+pub(crate) fn wrong_parity<T: ?Sized>(expected: ParityKind) {
+    tracing::debug!(
+        target: "parity",
+        r#type = type_name::<T>(),
+        %expected,
+        caller = %Location::caller(),
+        "value does not have the expected parity",
+    );
+}
+
+/// Records a floating-point value that has no parity.
+#[track_caller]
+pub(crate) fn no_float_parity(value: f64, error: FloatParityError) {
+    tracing::debug!(
+        target: "parity",
+        value,
+        %error,
+        caller = %Location::caller(),
+        "floating-point value has no parity",
+    );
+}
+
+/// Records a decoded value of type `T` that was rejected for not having the `expected` parity.
+#[cfg(any(feature = "borsh", feature = "rkyv"))]
+pub(crate) fn rejected_wrong_parity<T: ?Sized>(expected: ParityKind, format: &'static str) {
+    tracing::warn!(
+        target: "parity",
+        r#type = type_name::<T>(),
+        %expected,
+        format,
+        "rejected a decoded value without the expected parity",
+    );
+}
+
+/// Records an integer that is not a valid encoding of a [ParityKind].
+#[cfg(any(feature = "borsh", feature = "diesel", feature = "sqlx"))]
+pub(crate) fn rejected_parity_encoding(value: i64, format: &'static str) {
+    tracing::warn!(target: "parity", value, format, "rejected an invalid parity encoding");
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::fmt::{Debug, Write};
+    use std::string::String;
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::float::FloatParity;
+    use crate::{Even, Parity};
+
+    /// Records each event as its fields, formatted as `name=value` pairs.
+    #[derive(Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    struct Fields(String);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            let _ = write!(self.0, "{}={:?} ", field.name(), value);
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields(String::new());
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.0);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn events() {
+        let recorder = Recorder::default();
+        let events = recorder.0.clone();
+        tracing::subscriber::with_default(recorder, || {
+            assert!(4u8.ensure_even().is_ok());
+            assert!(3u8.ensure_even().is_err());
+            assert!(Even::new(5i32).is_err());
+            assert!(2.5f32.try_is_odd().is_err());
+            assert!(f64::NAN.explain_parity().parity().is_err());
+            assert!(1e300.explain_parity().is_inexact());
+        });
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 4);
+        assert!(events[0].contains("type=\"u8\" expected=even caller=src/trace.rs:"));
+        assert!(events[1].contains("type=\"i32\""));
+        assert!(events[1].contains("caller=src/trace.rs:"));
+        assert!(events[2].starts_with("message=floating-point value has no parity value=2.5 "));
+        assert!(events[2].contains("error=value with a fractional part"));
+        assert!(events[2].contains("caller=src/trace.rs:"));
+        assert!(events[3].contains("value=NaN"));
+    }
+}
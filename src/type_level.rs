@@ -0,0 +1,91 @@
+//! Type-level parity for [typenum] numbers.
+//!
+//! # Example
+//! ```
+//! use parity::type_level::{TypeEven, TypeParity};
+//! use parity::ParityKind;
+//! use typenum::{Unsigned, U6, U7};
+//!
+//! // only accepts even dimensions
+//! fn halve<N: Unsigned + TypeEven>() -> usize {
+//!     N::USIZE / 2
+//! }
+//! assert_eq!(halve::<U6>(), 3);
+//! assert_eq!(U7::PARITY, ParityKind::Odd);
+//! ```
+
+use typenum::{Bit, NInt, NonZero, PInt, UInt, UTerm, Unsigned, B0, B1, Z0};
+
+use crate::ParityKind;
+
+/// The parity of a [typenum] integer, determined by its least significant bit.
+pub trait TypeParity {
+    /// [B1] for odd numbers and [B0] for even numbers.
+    type IsOdd: Bit;
+    /// The parity as a runtime value.
+    const PARITY: ParityKind;
+}
+
+/// Implemented by even [typenum] integers, so generic code can require an even type-level number.
+pub trait TypeEven: TypeParity<IsOdd = B0> {}
+
+/// Implemented by odd [typenum] integers, so generic code can require an odd type-level number.
+pub trait TypeOdd: TypeParity<IsOdd = B1> {}
+
+impl<T: TypeParity<IsOdd = B0>> TypeEven for T {}
+impl<T: TypeParity<IsOdd = B1>> TypeOdd for T {}
+
+impl TypeParity for UTerm {
+    type IsOdd = B0;
+    const PARITY: ParityKind = ParityKind::Even;
+}
+
+impl<U: Unsigned, B: Bit> TypeParity for UInt<U, B> {
+    type IsOdd = B;
+    const PARITY: ParityKind = ParityKind::from_odd(B::BOOL);
+}
+
+impl TypeParity for Z0 {
+    type IsOdd = B0;
+    const PARITY: ParityKind = ParityKind::Even;
+}
+
+impl<U: Unsigned + NonZero + TypeParity> TypeParity for PInt<U> {
+    type IsOdd = U::IsOdd;
+    const PARITY: ParityKind = U::PARITY;
+}
+
+impl<U: Unsigned + NonZero + TypeParity> TypeParity for NInt<U> {
+    type IsOdd = U::IsOdd;
+    const PARITY: ParityKind = U::PARITY;
+}
+
+/// Returns the parity of the [typenum] integer `N` as a runtime value.
+#[inline]
+pub const fn parity_of<N: TypeParity>() -> ParityKind {
+    N::PARITY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::IntegerParity;
+    use typenum::{N3, P4, U0, U1, U1024, U255};
+
+    fn even<N: TypeEven>() {}
+    fn odd<N: TypeOdd>() {}
+
+    #[test]
+    fn parities() {
+        even::<U0>();
+        odd::<U1>();
+        odd::<U255>();
+        even::<U1024>();
+        even::<P4>();
+        odd::<N3>();
+        even::<Z0>();
+
+        assert_eq!(parity_of::<U255>(), 255u32.parity());
+        assert_eq!(<N3 as TypeParity>::PARITY, ParityKind::Odd);
+    }
+}
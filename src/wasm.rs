@@ -0,0 +1,68 @@
+//! JavaScript bindings generated with `wasm-bindgen`.
+//!
+//! Numbers are checked with the float semantics of [Parity], so `isEven(2.5)` and `isOdd(NaN)` are both `false`.
+//! `BigInt`s are passed as `i64` and must fit in 64 bits. [ParityKind] is exported to JavaScript as `Parity`.
+//!
+//! ```js
+//! import { isEven, parity, parityBigInt, Parity } from "parity";
+//!
+//! isEven(4);                         // true
+//! parity(1.5);                       // undefined
+//! parityBigInt(9007199254740993n);   // Parity.Odd
+//! ```
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{IntegerParity, Parity, ParityKind};
+
+/// Returns `true` if the number `x` is even.
+#[wasm_bindgen(js_name = isEven)]
+pub fn is_even(x: f64) -> bool {
+    x.is_even()
+}
+
+/// Returns `true` if the number `x` is odd.
+#[wasm_bindgen(js_name = isOdd)]
+pub fn is_odd(x: f64) -> bool {
+    x.is_odd()
+}
+
+/// Returns the parity of the number `x`, or `undefined` if it is NaN, infinite or has a fractional part.
+#[wasm_bindgen(js_name = parity)]
+pub fn parity(x: f64) -> Option<ParityKind> {
+    x.checked_parity()
+}
+
+/// Returns `true` if the `BigInt` `x` is even.
+#[wasm_bindgen(js_name = isEvenBigInt)]
+pub fn is_even_bigint(x: i64) -> bool {
+    x.is_even()
+}
+
+/// Returns `true` if the `BigInt` `x` is odd.
+#[wasm_bindgen(js_name = isOddBigInt)]
+pub fn is_odd_bigint(x: i64) -> bool {
+    x.is_odd()
+}
+
+/// Returns the parity of the `BigInt` `x`.
+#[wasm_bindgen(js_name = parityBigInt)]
+pub fn parity_bigint(x: i64) -> ParityKind {
+    x.parity()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_semantics() {
+        assert!(is_even(-0.0));
+        assert!(!is_even(f64::NAN) && !is_odd(f64::NAN));
+        assert_eq!(parity(3.0), Some(ParityKind::Odd));
+        assert_eq!(parity(f64::INFINITY), None);
+        assert!(is_odd_bigint(9_007_199_254_740_993));
+        assert!(is_even_bigint(i64::MIN));
+        assert_eq!(parity_bigint(-1), ParityKind::Odd);
+    }
+}
@@ -0,0 +1,180 @@
+//! Parity statistics over a sliding window of recent values.
+
+use crate::iter::ParityCount;
+use crate::{IntegerParity, ParityKind};
+
+/// The even and odd counts of the last `N` values pushed, updated in constant time per value without allocating.
+///
+/// Only the parities are stored, in a ring buffer of `N` slots. `N` must be nonzero, which is checked at compile
+/// time when the window is created.
+///
+/// # Example
+/// Watching the balance of the last eight values of a stream:
+/// ```
+/// use parity::window::ParityWindow;
+/// use parity::{ParityCount, ParityKind};
+///
+/// let mut window = ParityWindow::<8>::new();
+/// for x in [1, 3, 5, 7, 9, 11, 2, 4, 6, 8, 10, 12] {
+///     window.push(x);
+/// }
+/// assert_eq!(window.counts(), ParityCount { even: 6, odd: 2 });
+/// assert_eq!(window.majority(), Some(ParityKind::Even));
+/// ```
+///
+/// ```compile_fail
+/// # use parity::window::ParityWindow;
+/// let window = ParityWindow::<0>::new(); // error: a parity window needs at least one slot
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ParityWindow<const N: usize> {
+    parities: [ParityKind; N],
+    // index of the oldest parity in the window
+    start: usize,
+    counts: ParityCount,
+}
+
+impl<const N: usize> ParityWindow<N> {
+    const NONZERO: () = assert!(N != 0, "a parity window needs at least one slot");
+
+    /// Returns an empty window.
+    #[inline]
+    pub const fn new() -> Self {
+        #[allow(clippy::let_unit_value)] // evaluating the constant is what performs the check
+        let () = Self::NONZERO;
+        ParityWindow {
+            parities: [ParityKind::Even; N],
+            start: 0,
+            counts: ParityCount { even: 0, odd: 0 },
+        }
+    }
+
+    /// Pushes the parity of `value`, returning the parity that left the window if it was full.
+    #[inline]
+    pub fn push<T: IntegerParity>(&mut self, value: T) -> Option<ParityKind> {
+        self.push_parity(value.parity())
+    }
+
+    /// Pushes `parity`, returning the parity that left the window if it was full.
+    pub fn push_parity(&mut self, parity: ParityKind) -> Option<ParityKind> {
+        let len = self.len();
+        self.counts.record(parity);
+        if len < N {
+            self.parities[(self.start + len) % N] = parity;
+            return None;
+        }
+        let evicted = core::mem::replace(&mut self.parities[self.start], parity);
+        match evicted {
+            ParityKind::Even => self.counts.even -= 1,
+            ParityKind::Odd => self.counts.odd -= 1,
+        }
+        self.start = (self.start + 1) % N;
+        Some(evicted)
+    }
+
+    /// Returns the even and odd counts of the values in the window.
+    #[inline]
+    pub const fn counts(&self) -> ParityCount {
+        self.counts
+    }
+
+    /// Returns the parity of more than half of the values in the window, or `None` if neither is.
+    #[inline]
+    pub fn majority(&self) -> Option<ParityKind> {
+        match self.counts.even.cmp(&self.counts.odd) {
+            core::cmp::Ordering::Greater => Some(ParityKind::Even),
+            core::cmp::Ordering::Less => Some(ParityKind::Odd),
+            core::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Returns the number of values in the window, at most `N`.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.counts.total()
+    }
+
+    /// Returns `true` if no value has been pushed since the window was created or cleared.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the window holds `N` values, so the next push evicts the oldest.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Returns an iterator over the parities in the window, from the oldest to the newest.
+    pub fn parities(&self) -> impl DoubleEndedIterator<Item = ParityKind> + ExactSizeIterator + '_ {
+        (0..self.len()).map(move |i| self.parities[(self.start + i) % N])
+    }
+
+    /// Removes every value from the window.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.start = 0;
+        self.counts = ParityCount::default();
+    }
+}
+
+impl<const N: usize> Default for ParityWindow<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::vec::Vec;
+
+    use super::*;
+    use crate::ParityIterator;
+
+    #[test]
+    fn matches_recount() {
+        let values: Vec<u32> = (0..200u32)
+            .map(|i| i.wrapping_mul(2_654_435_761) >> 7)
+            .collect();
+        let mut window = ParityWindow::<5>::new();
+        for (i, &value) in values.iter().enumerate() {
+            let evicted = window.push(value);
+            let recent = &values[i.saturating_sub(4)..=i];
+            assert_eq!(window.counts(), recent.iter().copied().count_parities());
+            assert!(window.parities().eq(recent.iter().map(|v| v.parity())));
+            assert_eq!(evicted, i.checked_sub(5).map(|j| values[j].parity()));
+            assert_eq!(window.is_full(), i >= 4);
+        }
+    }
+
+    #[test]
+    fn filling_and_clearing() {
+        let mut window = ParityWindow::<3>::default();
+        assert!(window.is_empty());
+        assert_eq!(window.majority(), None);
+        assert_eq!(window.push(1u8), None);
+        assert_eq!(window.push_parity(ParityKind::Even), None);
+        assert_eq!(window.majority(), None);
+        assert_eq!(window.push(-3i64), None);
+        assert_eq!(window.majority(), Some(ParityKind::Odd));
+        assert_eq!(window.push(4u8), Some(ParityKind::Odd));
+        assert!(window
+            .parities()
+            .rev()
+            .eq([ParityKind::Even, ParityKind::Odd, ParityKind::Even]));
+
+        window.clear();
+        assert_eq!(window.len(), 0);
+        assert!(window.parities().next().is_none());
+        assert_eq!(window.push(7u8), None);
+        assert_eq!(window.counts(), ParityCount { even: 0, odd: 1 });
+
+        let mut single = ParityWindow::<1>::new();
+        assert_eq!(single.push(2u8), None);
+        assert_eq!(single.push(3u8), Some(ParityKind::Even));
+        assert_eq!(single.majority(), Some(ParityKind::Odd));
+    }
+}
@@ -0,0 +1,574 @@
+use core::fmt;
+use core::num::NonZeroU32;
+use core::ops::{Neg, Shl, Shr};
+
+use crate::{Parity, ParityError, ParityKind};
+
+/// The type of the parity parameter of [Constrained]: [EVEN_PARAM] for even values and [ODD_PARAM] for odd ones.
+///
+/// Const parameters of enum types such as [ParityKind] are not stable yet, so the parameter is a `bool` that is
+/// `true` for odd values, as in [ParityKind::from_odd].
+pub type ParityParam = bool;
+
+/// The [ParityParam] of [Even] values.
+pub const EVEN_PARAM: ParityParam = false;
+
+/// The [ParityParam] of [Odd] values.
+pub const ODD_PARAM: ParityParam = true;
+
+/// A value that is known to have the parity selected by `P`, [EVEN_PARAM] or [ODD_PARAM].
+///
+/// This type is usually named through its aliases [Even] and [Odd]. The parity parameter lets generic code cover
+/// both wrappers at once, with [Constrained::PARITY] giving the parity as a value:
+/// ```
+/// use parity::{Constrained, Even, Odd, ParityParam, ODD_PARAM};
+///
+/// fn describe<const P: ParityParam>(value: Constrained<u32, P>) -> String {
+///     format!("{} is {}", value, Constrained::<u32, P>::PARITY)
+/// }
+///
+/// assert_eq!(describe(Even::new(4).unwrap()), "4 is even");
+/// assert_eq!(describe(Odd::new(7).unwrap()), "7 is odd");
+///
+/// let seven: Constrained<u32, ODD_PARAM> = Odd::new(7).unwrap();
+/// assert_eq!(describe(seven), "7 is odd");
+/// ```
+///
+/// `Constrained<T, P>` is `repr(transparent)`, so it has the same layout as `T`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(archived = ArchivedConstrained, bytecheck(verify))
+)]
+pub struct Constrained<T, const P: ParityParam>(pub(crate) T);
+
+/// A value that is known to be even.
+///
+/// `Even<T>` is `repr(transparent)`, so it has the same layout as `T`.
+///
+/// # Example
+/// ```
+/// use parity::Even;
+///
+/// let width = Even::new(640u32).unwrap();
+/// assert_eq!(width.into_inner() / 2, 320);
+///
+/// let err = Even::new(641u32).unwrap_err();
+/// assert_eq!(err.into_value(), 641);
+/// ```
+///
+/// # Arithmetic
+/// For the primitive integer types, checked, wrapping and overflowing arithmetic returns the wrapper of the
+/// parity the result is known to have, so the invariant carries through without re-validating. Wrapping around
+/// never changes the parity of the result.
+/// ```
+/// use parity::{Even, Odd};
+///
+/// let (two, three) = (Even::new(2u8).unwrap(), Odd::new(3u8).unwrap());
+/// let five: Odd<u8> = two.checked_add_odd(three).unwrap();
+/// let ten: Even<u8> = two.checked_mul(5).unwrap();
+/// assert_eq!((*five.get(), *ten.get()), (5, 10));
+/// assert_eq!(Even::new(250u8).unwrap().checked_add(ten), None);
+/// assert_eq!(Odd::new(255u8).unwrap().overflowing_add(three), (Even::new(2).unwrap(), true));
+/// ```
+pub type Even<T> = Constrained<T, EVEN_PARAM>;
+
+/// A value that is known to be odd.
+///
+/// `Odd<T>` is `repr(transparent)`, so it has the same layout as `T`.
+///
+/// # Example
+/// ```
+/// use parity::Odd;
+///
+/// let window = Odd::new(5usize).unwrap();
+/// assert_eq!(*window.get() / 2, 2);
+/// assert!(Odd::new(4.0).is_err());
+/// ```
+///
+/// Wrappers compare, order and hash like the values they wrap, so they can be used as keys of sorted and hashed
+/// collections:
+/// ```
+/// use std::collections::BTreeSet;
+/// use parity::Odd;
+///
+/// let set = BTreeSet::from([Odd::<i8>::MAX, Odd::new(-5).unwrap(), Odd::<i8>::ONE, Odd::<i8>::MIN]);
+/// assert_eq!(set.first(), Some(&Odd::<i8>::MIN));
+/// assert_eq!(*Odd::<i8>::MIN.get(), -127);
+/// ```
+pub type Odd<T> = Constrained<T, ODD_PARAM>;
+
+/// The archived form of an [Even] value.
+#[cfg(feature = "rkyv")]
+pub type ArchivedEven<T> = ArchivedConstrained<T, EVEN_PARAM>;
+
+/// The archived form of an [Odd] value.
+#[cfg(feature = "rkyv")]
+pub type ArchivedOdd<T> = ArchivedConstrained<T, ODD_PARAM>;
+
+impl<T, const P: ParityParam> Constrained<T, P> {
+    /// The parity of every value of this type.
+    pub const PARITY: ParityKind = ParityKind::from_odd(P);
+
+    /// Returns a reference to the wrapped value.
+    #[inline]
+    pub const fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Parity, const P: ParityParam> Constrained<T, P> {
+    /// Wraps `value`, failing with a [ParityError] carrying it if it does not have the parity [PARITY](Self::PARITY).
+    #[inline]
+    #[cfg_attr(feature = "tracing", track_caller)]
+    pub fn new(value: T) -> Result<Self, ParityError<T>> {
+        match Self::PARITY {
+            ParityKind::Even => value.ensure_even(),
+            ParityKind::Odd => value.ensure_odd(),
+        }
+        .map(Constrained)
+    }
+}
+
+impl<T: Shr<u32, Output = T> + Parity, const P: ParityParam> Constrained<T, P> {
+    /// Shifts the value right by `k` bits, failing with a [ParityError] carrying the result if it is not even.
+    #[inline]
+    pub fn shr_even(self, k: u32) -> Result<Even<T>, ParityError<T>> {
+        Even::new(self.0 >> k)
+    }
+
+    /// Shifts the value right by `k` bits, failing with a [ParityError] carrying the result if it is not odd.
+    #[inline]
+    pub fn shr_odd(self, k: u32) -> Result<Odd<T>, ParityError<T>> {
+        Odd::new(self.0 >> k)
+    }
+}
+
+/// Negation keeps the parity. As with the bare value, negating the minimum value of a signed type overflows.
+impl<T: Neg<Output = T>, const P: ParityParam> Neg for Constrained<T, P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Constrained(-self.0)
+    }
+}
+
+/// Shifting right can move any bit into the lowest place, so the result is the bare value. Use the
+/// [shr_even](Constrained::shr_even) and [shr_odd](Constrained::shr_odd) methods to check the parity of the result.
+impl<T: Shr<u32, Output = T>, const P: ParityParam> Shr<u32> for Constrained<T, P> {
+    type Output = T;
+
+    #[inline]
+    fn shr(self, k: u32) -> T {
+        self.0 >> k
+    }
+}
+
+impl<T: Parity, const P: ParityParam> Parity for Constrained<T, P> {
+    #[inline]
+    fn is_even(&self) -> bool {
+        self.0.is_even()
+    }
+
+    #[inline]
+    fn is_odd(&self) -> bool {
+        self.0.is_odd()
+    }
+
+    #[inline]
+    fn is_parity_defined(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn checked_parity(&self) -> Option<ParityKind> {
+        Some(Self::PARITY)
+    }
+}
+
+impl<T, const P: ParityParam> AsRef<T> for Constrained<T, P> {
+    #[inline]
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+/// Formats as `Even(value)` or `Odd(value)`, after the alias of the type.
+impl<T: fmt::Debug, const P: ParityParam> fmt::Debug for Constrained<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(match Self::PARITY {
+            ParityKind::Even => "Even",
+            ParityKind::Odd => "Odd",
+        })
+        .field(&self.0)
+        .finish()
+    }
+}
+
+impl<T: fmt::Display, const P: ParityParam> fmt::Display for Constrained<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<T: rkyv::Archive, const P: ParityParam> ArchivedConstrained<T, P> {
+    /// Returns a reference to the archived value.
+    #[inline]
+    pub fn get(&self) -> &T::Archived {
+        &self.0
+    }
+}
+
+macro_rules! wrapper_arithmetic {
+    ($Name:ident<$T:ty> { $(
+        $what:literal: $checked:ident, $wrapping:ident, $overflowing:ident
+            ($rhs:ident: $Rhs:ty => $value:expr) -> $Out:ident
+            via $inner_checked:ident, $inner_wrapping:ident, $inner_overflowing:ident;
+    )* }) => {
+        impl $Name<$T> { $(
+            #[doc = concat!("Returns ", $what, ", or `None` if it overflows.")]
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn $checked(self, $rhs: $Rhs) -> Option<$Out<$T>> {
+                self.0.$inner_checked($value).map(Constrained)
+            }
+
+            #[doc = concat!("Returns ", $what, ", wrapping around at the boundary of the type.")]
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn $wrapping(self, $rhs: $Rhs) -> $Out<$T> {
+                Constrained(self.0.$inner_wrapping($value))
+            }
+
+            #[doc = concat!("Returns ", $what, " wrapped around at the boundary of the type, and whether it")]
+            /// overflowed.
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub fn $overflowing(self, $rhs: $Rhs) -> ($Out<$T>, bool) {
+                let (value, overflowed) = self.0.$inner_overflowing($value);
+                (Constrained(value), overflowed)
+            }
+        )* }
+    };
+}
+
+// Wrapping around adds or removes a multiple of `2^BITS`, which is even, so the wrapping and overflowing results
+// keep the parity of the exact result.
+macro_rules! impl_wrapper_arithmetic {
+    ($($T:ty),*) => { $(
+        wrapper_arithmetic!(Even<$T> {
+            "the sum of `self` and an even `rhs`, which is even":
+                checked_add, wrapping_add, overflowing_add (rhs: Even<$T> => rhs.0) -> Even
+                via checked_add, wrapping_add, overflowing_add;
+            "the sum of `self` and an odd `rhs`, which is odd":
+                checked_add_odd, wrapping_add_odd, overflowing_add_odd (rhs: Odd<$T> => rhs.0) -> Odd
+                via checked_add, wrapping_add, overflowing_add;
+            "the difference of `self` and an even `rhs`, which is even":
+                checked_sub, wrapping_sub, overflowing_sub (rhs: Even<$T> => rhs.0) -> Even
+                via checked_sub, wrapping_sub, overflowing_sub;
+            "the difference of `self` and an odd `rhs`, which is odd":
+                checked_sub_odd, wrapping_sub_odd, overflowing_sub_odd (rhs: Odd<$T> => rhs.0) -> Odd
+                via checked_sub, wrapping_sub, overflowing_sub;
+            "the product of `self` and any `rhs`, which is even":
+                checked_mul, wrapping_mul, overflowing_mul (rhs: $T => rhs) -> Even
+                via checked_mul, wrapping_mul, overflowing_mul;
+        });
+
+        wrapper_arithmetic!(Odd<$T> {
+            "the sum of `self` and an odd `rhs`, which is even":
+                checked_add, wrapping_add, overflowing_add (rhs: Odd<$T> => rhs.0) -> Even
+                via checked_add, wrapping_add, overflowing_add;
+            "the sum of `self` and an even `rhs`, which is odd":
+                checked_add_even, wrapping_add_even, overflowing_add_even (rhs: Even<$T> => rhs.0) -> Odd
+                via checked_add, wrapping_add, overflowing_add;
+            "the difference of `self` and an odd `rhs`, which is even":
+                checked_sub, wrapping_sub, overflowing_sub (rhs: Odd<$T> => rhs.0) -> Even
+                via checked_sub, wrapping_sub, overflowing_sub;
+            "the difference of `self` and an even `rhs`, which is odd":
+                checked_sub_even, wrapping_sub_even, overflowing_sub_even (rhs: Even<$T> => rhs.0) -> Odd
+                via checked_sub, wrapping_sub, overflowing_sub;
+            "the product of `self` and an odd `rhs`, which is odd":
+                checked_mul, wrapping_mul, overflowing_mul (rhs: Odd<$T> => rhs.0) -> Odd
+                via checked_mul, wrapping_mul, overflowing_mul;
+            "the product of `self` and an even `rhs`, which is even":
+                checked_mul_even, wrapping_mul_even, overflowing_mul_even (rhs: Even<$T> => rhs.0) -> Even
+                via checked_mul, wrapping_mul, overflowing_mul;
+        });
+    )* };
+}
+
+impl_wrapper_arithmetic![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+macro_rules! impl_wrapper_consts {
+    ($($T:ty),*) => { $(
+        impl Even<$T> {
+            /// Zero.
+            pub const ZERO: Self = Constrained(0);
+            /// The smallest even value of the type.
+            pub const MIN: Self = Constrained(<$T>::MIN & !1);
+            /// The largest even value of the type.
+            pub const MAX: Self = Constrained(<$T>::MAX & !1);
+        }
+
+        impl Odd<$T> {
+            /// One.
+            pub const ONE: Self = Constrained(1);
+            /// The smallest odd value of the type.
+            pub const MIN: Self = Constrained(<$T>::MIN | 1);
+            /// The largest odd value of the type.
+            pub const MAX: Self = Constrained(<$T>::MAX | 1);
+        }
+    )* };
+}
+
+impl_wrapper_consts![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+macro_rules! impl_wrapper_shl {
+    ($($T:ty),*) => { $(
+        /// Shifting left by at least one bit clears the lowest bit, so the result is even. Shifting by at least the
+        /// width of the type shifts every bit out, leaving zero.
+        impl<const P: ParityParam> Shl<NonZeroU32> for Constrained<$T, P> {
+            type Output = Even<$T>;
+
+            #[inline]
+            fn shl(self, k: NonZeroU32) -> Even<$T> {
+                Constrained(self.0.checked_shl(k.get()).unwrap_or(0))
+            }
+        }
+    )* };
+}
+
+impl_wrapper_shl![u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize];
+
+/// Shifting an even value left by any amount keeps its lowest bit clear.
+impl<T: Shl<u32, Output = T>> Shl<u32> for Even<T> {
+    type Output = Self;
+
+    #[inline]
+    fn shl(self, k: u32) -> Self {
+        Constrained(self.0 << k)
+    }
+}
+
+macro_rules! impl_wrapper_abs {
+    ($($T:ty => $U:ty),*) => { $(
+        impl<const P: ParityParam> Constrained<$T, P> {
+            /// Returns the absolute value, which has the same parity.
+            ///
+            #[doc = concat!("As with [", stringify!($T), "::abs], the absolute value of the minimum value overflows,")]
+            /// and wraps around to it in release builds.
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn abs(self) -> Self {
+                Constrained(self.0.abs())
+            }
+
+            /// Returns the absolute value as the unsigned type of the same width, without overflowing.
+            #[inline]
+            #[must_use = "this returns the result of the operation, without modifying the original"]
+            pub const fn unsigned_abs(self) -> Constrained<$U, P> {
+                Constrained(self.0.unsigned_abs())
+            }
+        }
+    )* };
+}
+
+impl_wrapper_abs! { i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn even<T>(value: T) -> Even<T> {
+        Constrained(value)
+    }
+
+    fn odd<T>(value: T) -> Odd<T> {
+        Constrained(value)
+    }
+
+    #[test]
+    fn construction() {
+        assert_eq!(Even::new(-2i8).map(Even::into_inner), Ok(-2));
+        assert!(Even::new(f64::NAN).is_err());
+        assert!(Odd::new(f64::INFINITY).is_err());
+        assert_eq!(Odd::new(2u8).unwrap_err().expected(), ParityKind::Odd);
+        assert_eq!(
+            Odd::new(3.0).unwrap().checked_parity(),
+            Some(ParityKind::Odd)
+        );
+        assert!(Even::new(0u64).unwrap().same_parity(&Even::new(8).unwrap()));
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv_round_trip() {
+        use rkyv::rancor::Error;
+
+        let even = Even::new(-42i32).unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&even).unwrap();
+        let archived = rkyv::access::<ArchivedEven<i32>, Error>(&bytes).unwrap();
+        assert_eq!(*archived.get(), -42);
+        assert_eq!(
+            rkyv::deserialize::<Even<i32>, Error>(archived).unwrap(),
+            even
+        );
+
+        let odd = Odd::new(7u64).unwrap();
+        let bytes = rkyv::to_bytes::<Error>(&odd).unwrap();
+        assert_eq!(rkyv::from_bytes::<Odd<u64>, Error>(&bytes).unwrap(), odd);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv_rejects_wrong_parity() {
+        use rkyv::rancor::Error;
+
+        let bytes = rkyv::to_bytes::<Error>(&8u64).unwrap();
+        assert!(rkyv::from_bytes::<Odd<u64>, Error>(&bytes).is_err());
+        assert!(rkyv::access::<ArchivedOdd<u64>, Error>(&bytes).is_err());
+        assert_eq!(
+            rkyv::from_bytes::<Even<u64>, Error>(&bytes).unwrap(),
+            even(8)
+        );
+
+        let bytes = rkyv::to_bytes::<Error>(&2.5f64).unwrap();
+        assert!(rkyv::access::<ArchivedEven<f64>, Error>(&bytes).is_err());
+        assert!(rkyv::access::<ArchivedOdd<f64>, Error>(&bytes).is_err());
+    }
+
+    #[test]
+    fn arithmetic_keeps_parity() {
+        for a in (-128i16..128).step_by(3) {
+            for b in (-128i16..128).step_by(5) {
+                let (a8, b8) = (a as i8, b as i8);
+                let (even_a, even_b) = (even(a8 & !1), even(b8 & !1));
+                let (odd_a, odd_b) = (odd(a8 | 1), odd(b8 | 1));
+                assert_eq!(
+                    even_a.checked_add(even_b).map(Even::into_inner),
+                    even_a.0.checked_add(even_b.0)
+                );
+                assert_eq!(
+                    even_a.wrapping_sub_odd(odd_b).0,
+                    even_a.0.wrapping_sub(odd_b.0)
+                );
+                assert_eq!(
+                    even_a.overflowing_mul(b8),
+                    (
+                        even(even_a.0.wrapping_mul(b8)),
+                        even_a.0.overflowing_mul(b8).1
+                    )
+                );
+                assert_eq!(odd_a.wrapping_add(odd_b).0, odd_a.0.wrapping_add(odd_b.0));
+                assert_eq!(
+                    odd_a.checked_sub_even(even_b).map(Odd::into_inner),
+                    odd_a.0.checked_sub(even_b.0)
+                );
+                assert_eq!(
+                    odd_a.overflowing_mul(odd_b).1,
+                    odd_a.0.overflowing_mul(odd_b.0).1
+                );
+                for value in [
+                    even_a.wrapping_add_odd(odd_b).0,
+                    odd_a.wrapping_add_even(even_b).0,
+                    odd_a.wrapping_sub_even(even_b).0,
+                    odd_a.wrapping_mul(odd_b).0,
+                ] {
+                    assert!(value.is_odd());
+                }
+                for value in [
+                    even_a.wrapping_add(even_b).0,
+                    even_a.wrapping_sub(even_b).0,
+                    odd_a.wrapping_sub(odd_b).0,
+                    odd_a.wrapping_mul_even(even_b).0,
+                ] {
+                    assert!(value.is_even());
+                }
+            }
+        }
+        assert_eq!(odd(u64::MAX).checked_add_even(even(2)), None);
+        assert_eq!(even(0u32).checked_sub_odd(odd(1)), None);
+    }
+
+    #[test]
+    fn unary_operations() {
+        assert_eq!(-even(6i32), even(-6));
+        assert_eq!(-odd(-3.0f64), odd(3.0));
+        assert_eq!(odd(-7i16).abs(), odd(7));
+        assert_eq!(even(i8::MIN).unsigned_abs(), even(128u8));
+        assert_eq!(odd(i64::MIN + 1).unsigned_abs(), odd(u64::MAX >> 1));
+
+        let one = NonZeroU32::MIN;
+        assert_eq!(odd(5u32) << one, even(10));
+        assert_eq!(odd(-3i8) << NonZeroU32::new(2).unwrap(), even(-12));
+        assert_eq!(even(3u8 << 1) << 3, even(48));
+        assert_eq!(even(6u8) << one, even(12));
+        // shifting out every bit leaves zero rather than wrapping the shift amount
+        for k in [32, 33, 64, u32::MAX] {
+            let shifted = odd(5u32) << NonZeroU32::new(k).unwrap();
+            assert_eq!(shifted, Even::<u32>::ZERO);
+            assert!(shifted.is_even());
+        }
+        assert_eq!(odd(-1i8) << NonZeroU32::new(7).unwrap(), even(i8::MIN));
+        assert_eq!(odd(-1i8) << NonZeroU32::new(8).unwrap(), even(0));
+
+        assert_eq!(even(12u32) >> 2, 3);
+        assert_eq!(even(12u32).shr_odd(2), Ok(odd(3)));
+        assert_eq!(odd(13u32).shr_even(1), Ok(even(6)));
+        assert_eq!(odd(13u32).shr_odd(1).unwrap_err().into_value(), 6);
+    }
+
+    #[test]
+    fn constants_and_ordering() {
+        assert_eq!(Even::<u8>::MIN, even(0));
+        assert_eq!(Even::<u8>::MAX, even(254));
+        assert_eq!(Even::<i16>::MIN, even(i16::MIN));
+        assert_eq!(Even::<i16>::MAX, even(i16::MAX - 1));
+        assert_eq!(Odd::<u32>::MIN, Odd::<u32>::ONE);
+        assert_eq!(Odd::<u32>::MAX, odd(u32::MAX));
+        assert_eq!(Odd::<i64>::MIN, odd(i64::MIN + 1));
+        assert_eq!(Even::<usize>::ZERO.into_inner(), 0);
+        for value in [Even::<i128>::MIN, Even::<i128>::MAX, Even::<i128>::ZERO] {
+            assert!(value.0.is_even());
+        }
+        for value in [Odd::<isize>::MIN, Odd::<isize>::MAX, Odd::<isize>::ONE] {
+            assert!(value.0.is_odd());
+        }
+
+        let mut values = [even(4i32), even(-2), even(0)];
+        values.sort();
+        assert_eq!(values, [even(-2), even(0), even(4)]);
+        assert!(odd(1.5f64 * 2.0) > odd(1.0));
+        let set: std::collections::HashSet<_> = [odd(3u8), odd(3), odd(5)].into_iter().collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn generic_over_parity() {
+        fn halve_and_wrap<const P: ParityParam>(
+            value: Constrained<u32, P>,
+        ) -> Result<Constrained<u32, P>, u32> {
+            Constrained::new(value.into_inner() / 2).map_err(ParityError::into_value)
+        }
+
+        assert_eq!(Even::<u32>::PARITY, ParityKind::Even);
+        assert_eq!(Odd::<u32>::PARITY, ParityKind::Odd);
+        assert_eq!(halve_and_wrap(Even::new(8u32).unwrap()), Ok(Constrained(4)));
+        assert_eq!(halve_and_wrap(Odd::new(7u32).unwrap()), Ok(Constrained(3)));
+        assert_eq!(halve_and_wrap(Odd::new(5u32).unwrap()), Err(2));
+        assert_eq!(std::format!("{:?}", Even::new(2u8).unwrap()), "Even(2)");
+        assert_eq!(std::format!("{:?}", Odd::<i8>::ONE), "Odd(1)");
+        assert_eq!(Odd::<i16>::MIN.unsigned_abs(), Odd::new(32767u16).unwrap());
+    }
+}
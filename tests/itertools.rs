@@ -0,0 +1,76 @@
+//! The parity adapters and key functions used together with `itertools`.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use parity::iter::{checked_parity_key, parity_key};
+use parity::{Even, IntegerParity, Parity, ParityIterator, ParityKind};
+
+#[test]
+fn chunk_by_parity() {
+    let runs: Vec<(ParityKind, Vec<u32>)> = [2, 4, 1, 3, 5, 6]
+        .into_iter()
+        .chunk_by(parity_key)
+        .into_iter()
+        .map(|(parity, run)| (parity, run.collect()))
+        .collect();
+    assert_eq!(
+        runs,
+        [
+            (ParityKind::Even, vec![2, 4]),
+            (ParityKind::Odd, vec![1, 3, 5]),
+            (ParityKind::Even, vec![6]),
+        ]
+    );
+}
+
+#[test]
+fn group_map_by_parity() {
+    let groups: HashMap<ParityKind, Vec<i64>> = (-3..4).into_group_map_by(parity_key);
+    assert_eq!(groups[&ParityKind::Even], [-2, 0, 2]);
+    assert_eq!(groups[&ParityKind::Odd], [-3, -1, 1, 3]);
+
+    let counts = [0.5, 1.0, 2.0, f64::NAN]
+        .into_iter()
+        .counts_by(|x| checked_parity_key(&x));
+    assert_eq!(counts[&None], 2);
+    assert_eq!(counts[&Some(ParityKind::Odd)], 1);
+}
+
+#[test]
+fn adapters_compose() {
+    let pairs: Vec<(u8, u8)> = (0..10).evens().tuples().collect();
+    assert_eq!(pairs, [(0, 2), (4, 6)]);
+
+    let sums: Vec<i32> = (1..=9)
+        .odds()
+        .chunks(2)
+        .into_iter()
+        .map(|c| c.sum())
+        .collect();
+    assert_eq!(sums, [4, 12, 9]);
+
+    let steps: Vec<ParityKind> = [1, 2, 4, 7]
+        .into_iter()
+        .tuple_windows()
+        .map(|(a, b): (i32, i32)| (b - a).parity())
+        .collect();
+    assert_eq!(steps, [ParityKind::Odd, ParityKind::Even, ParityKind::Odd]);
+
+    let evens: Vec<Even<u16>> = (0..6u16)
+        .filter_map(|x| Even::new(x).ok())
+        .sorted_by(|a, b| b.cmp(a))
+        .collect();
+    assert_eq!(evens.iter().map(|e| *e.get()).join(","), "4,2,0");
+    assert!((0..20).evens().all_unique() && (0..20).odds().all(|x| x.is_odd()));
+
+    let (even, odd): (Vec<u32>, Vec<u32>) = (0..6).partition_map(|x: u32| {
+        if x.is_even() {
+            itertools::Either::Left(x)
+        } else {
+            itertools::Either::Right(x)
+        }
+    });
+    assert_eq!((even, odd), (vec![0, 2, 4], vec![1, 3, 5]));
+    assert_eq!((0..9).count_parities().even, 5);
+}